@@ -4,6 +4,10 @@
 use nalgebra_glm::IVec2;
 
 use raytracing::examples::cornell_box::cornell_box;
+use raytracing::misc::default_rand_gen::DefaultRandGen;
+use raytracing::misc::render_settings::RenderSettings;
+use raytracing::scene::scene_info::SceneInfo;
+use raytracing::scene::scene_reader::SceneReader;
 
 use crate::mainloop::default_mainloop::DefaultMainLoop;
 use crate::mainloop::default_mainloop_builder::DefaultMainLoopBuilder;
@@ -14,14 +18,41 @@ mod mainloop;
 
 pub fn main() {
     let resolution = IVec2::new(800, 600);
-    let tracer_resolution = resolution;
+    let settings = RenderSettings::new(resolution);
 
-    let scene_info = cornell_box(resolution);
+    // Scene file can be given as the first argument, Cornell box is rendered otherwise
+    let scene_info = match std::env::args().nth(1) {
+        Some(path) => {
+            let scene_info = SceneInfo::new(DefaultRandGen::new_rc);
+
+            match SceneReader::new(&path).and_then(|reader| reader.read(scene_info)) {
+                Ok(scene_info) => scene_info,
+                Err(err) => {
+                    println!("Error reading scene: {}", err);
+
+                    return;
+                }
+            }
+        }
+        None => cornell_box(resolution),
+    };
+
+    if let Err(err) = scene_info.validate() {
+        println!("Error in scene: {}", err);
+
+        return;
+    }
+
+    if let Err(err) = settings.validate() {
+        println!("Error in render settings: {}", err);
+
+        return;
+    }
 
     let mut mainloop = DefaultMainLoopBuilder::new()
         .state(RaytracingState::new(
             scene_info,
-            tracer_resolution,
+            settings,
             8,
             Option::Some("result.ppm"),
         ))
@@ -1,9 +1,8 @@
-use nalgebra_glm::IVec2;
-
 use raytracing::image::buffer_converter::BufferConverter;
 use raytracing::image::default_image_buffer::DefaultImageBuffer;
 use raytracing::image::image_buffer::ImageBuffer;
 use raytracing::image::ppm_converter::PpmConverter;
+use raytracing::misc::render_settings::RenderSettings;
 use raytracing::scene::scene_info::SceneInfo;
 use raytracing::thread_pool::pool::ThreadPool;
 
@@ -11,7 +10,7 @@ use crate::mainloop::states::mainloop_state::MainLoopState;
 
 pub struct RaytracingState {
     scene_info: SceneInfo,
-    resolution: IVec2,
+    settings: RenderSettings,
     total_samples: u64,
     pool: ThreadPool,
     buffer: DefaultImageBuffer,
@@ -22,7 +21,7 @@ pub struct RaytracingState {
 impl RaytracingState {
     pub fn new(
         scene: SceneInfo,
-        resolution: IVec2,
+        settings: RenderSettings,
         threads: usize,
         result_path: Option<&str>,
     ) -> Self {
@@ -36,10 +35,10 @@ impl RaytracingState {
 
         return RaytracingState {
             scene_info: scene,
-            resolution,
+            settings,
             total_samples: 0,
             pool: ThreadPool::new(threads),
-            buffer: DefaultImageBuffer::new(resolution).unwrap(),
+            buffer: DefaultImageBuffer::new(settings.get_resolution()).unwrap(),
             threads,
             result_path: path,
         };
@@ -49,7 +48,7 @@ impl RaytracingState {
 impl MainLoopState for RaytracingState {
     fn start(&mut self) {
         self.pool
-            .execute_scene(self.scene_info.clone(), self.threads);
+            .execute_scene(self.scene_info.clone(), self.settings, self.threads);
     }
 
     fn stop(&mut self) {
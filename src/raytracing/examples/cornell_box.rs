@@ -34,7 +34,7 @@ pub fn cornell_box(resolution: IVec2) -> SceneInfo {
             2.0 - 0.4,
         )
         .yz_square(
-            "lantern",
+            "lantern-red",
             "lantern-red",
             Vec3::new(-1.0 + 0.001, 0.0, 0.0),
             2.0 - 0.4,
@@ -1 +1,2 @@
 pub mod cornell_box;
+pub mod roulette_comparison;
@@ -0,0 +1,111 @@
+use std::time::{Duration, Instant};
+
+use crate::image::image_buffer::ImageBuffer;
+use crate::misc::render_settings::RenderSettings;
+use crate::misc::tracer::Tracer;
+use crate::scene::scene_error::SceneError;
+use crate::scene::scene_info::SceneInfo;
+
+/// Time and remaining noise of one render
+pub struct Convergence {
+    duration: Duration,
+    noise: f32,
+}
+
+impl Convergence {
+    /// Renders the scene twice with half of `samples` each, their difference estimates the noise
+    ///
+    /// # Arguments
+    ///
+    /// * `scene_info`:
+    /// * `settings`:
+    /// * `samples`: Samples per pixel of both renders together
+    ///
+    /// returns: SceneError if the scene can not be built
+    pub fn measure(
+        scene_info: &SceneInfo,
+        settings: RenderSettings,
+        samples: u64,
+    ) -> Result<Self, SceneError> {
+        let half = (samples / 2).max(1);
+        let mut first = Tracer::new(settings, scene_info.clone().try_build()?);
+        let mut second = Tracer::new(settings, scene_info.clone().try_build()?);
+        let start = Instant::now();
+
+        for _ in 0..half {
+            first.trace();
+            second.trace();
+        }
+
+        let duration = start.elapsed();
+        let first = first.get_buffer().get_buffer();
+        let second = second.get_buffer().get_buffer();
+        let squared_sum: f32 = first
+            .iter()
+            .zip(second)
+            .map(|(a, b)| ((a - b) / half as f32).magnitude_squared())
+            .sum();
+
+        // Average of both renders has a quarter of the variance of their difference
+        let noise = (squared_sum / first.len() as f32).sqrt() / 2.0;
+
+        return Result::Ok(Self { duration, noise });
+    }
+}
+
+impl Convergence {
+    pub fn get_duration(&self) -> Duration {
+        return self.duration;
+    }
+
+    /// Root mean square error estimate of the averaged image
+    pub fn get_noise(&self) -> f32 {
+        return self.noise;
+    }
+
+    /// Inverse of noise variance times time, higher converges faster
+    pub fn get_efficiency(&self) -> f32 {
+        return 1.0 / (self.noise * self.noise * self.duration.as_secs_f32());
+    }
+}
+
+/// Renders the scene with Russian roulette on and off and describes how fast each converges
+///
+/// # Arguments
+///
+/// * `scene_info`:
+/// * `settings`: Settings whose roulette depth is used for the enabled run
+/// * `samples`: Samples per pixel of every run
+///
+/// returns: SceneError if the scene can not be built or report otherwise
+pub fn roulette_comparison(
+    scene_info: &SceneInfo,
+    settings: RenderSettings,
+    samples: u64,
+) -> Result<String, SceneError> {
+    let depth = settings.get_russian_roulette_depth().unwrap_or(3);
+    let enabled = Convergence::measure(
+        scene_info,
+        settings.russian_roulette_depth(Option::Some(depth)),
+        samples,
+    )?;
+    let disabled = Convergence::measure(
+        scene_info,
+        settings.russian_roulette_depth(Option::None),
+        samples,
+    )?;
+
+    let mut report = String::new();
+
+    for (name, convergence) in [("roulette on", &enabled), ("roulette off", &disabled)] {
+        report += &format!(
+            "{:<12} time: {:>8.3}s noise: {:.5} efficiency: {:.1}\n",
+            name,
+            convergence.get_duration().as_secs_f32(),
+            convergence.get_noise(),
+            convergence.get_efficiency(),
+        );
+    }
+
+    return Result::Ok(report);
+}
@@ -4,6 +4,7 @@ use nalgebra_glm::Vec3;
 
 use crate::misc::ray::Ray;
 
+#[derive(Clone, Copy)]
 pub struct Aabb {
     minimum: Vec3,
     maximum: Vec3,
@@ -16,7 +17,41 @@ impl Aabb {
 }
 
 impl Aabb {
+    pub fn get_minimum(&self) -> &Vec3 {
+        return &self.minimum;
+    }
+
+    pub fn get_maximum(&self) -> &Vec3 {
+        return &self.maximum;
+    }
+
+    pub fn get_centroid(&self) -> Vec3 {
+        return (self.minimum + self.maximum) * 0.5;
+    }
+
+    pub fn surface_area(&self) -> f32 {
+        let extent = self.maximum - self.minimum;
+
+        return 2.0 * (extent.x * extent.y + extent.y * extent.z + extent.z * extent.x);
+    }
+
+    /// Index of the axis along which the box is the longest: 0 - x, 1 - y, 2 - z
+    pub fn longest_axis(&self) -> usize {
+        let extent = self.maximum - self.minimum;
+
+        return if extent.x > extent.y && extent.x > extent.z {
+            0
+        } else if extent.y > extent.z {
+            1
+        } else {
+            2
+        };
+    }
+
     pub fn hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> bool {
+        let mut t_min = t_min;
+        let mut t_max = t_max;
+
         for i in 0..3 {
             let inv_direction = 1.0 / ray.get_direction()[i];
             let mut t0 = (self.minimum[i] - ray.get_origin()[i]) * inv_direction;
@@ -26,10 +61,11 @@ impl Aabb {
                 swap(&mut t0, &mut t1);
             }
 
-            let t_min = if t0 > t_min { t0 } else { t_min };
-            let t_max = if t1 > t_max { t1 } else { t_max };
+            t_min = if t0 > t_min { t0 } else { t_min };
+            t_max = if t1 < t_max { t1 } else { t_max };
 
-            if t_max <= t_min {
+            // Flat boxes (e.g. around axis-aligned rects) have t0 == t1, so equality is a hit
+            if t_max < t_min {
                 return false;
             }
         }
@@ -0,0 +1,43 @@
+use crate::hittables::bvh::bvh_node::BvhNode;
+use crate::hittables::bvh::flat_bvh::FlatBvh;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::hittable_list::HittableList;
+
+/// Acceleration structure used for the scene hittables
+#[derive(Clone, Copy, Default)]
+pub enum Acceleration {
+    /// Every hittable is tested for every ray
+    Linear,
+    /// Median split hierarchy of boxed nodes
+    #[default]
+    Bvh,
+    /// Surface area heuristic hierarchy stored in a flat array
+    FlatBvh,
+}
+
+impl Acceleration {
+    pub fn build(&self, hittable_list: HittableList) -> Box<dyn Hittable> {
+        return match self {
+            Acceleration::Linear => Box::new(hittable_list),
+            Acceleration::Bvh => BvhNode::from_list(hittable_list),
+            Acceleration::FlatBvh => Box::new(FlatBvh::from_list(hittable_list)),
+        };
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            Acceleration::Linear => "linear",
+            Acceleration::Bvh => "bvh",
+            Acceleration::FlatBvh => "flat_bvh",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "linear" => Option::Some(Acceleration::Linear),
+            "bvh" => Option::Some(Acceleration::Bvh),
+            "flat_bvh" => Option::Some(Acceleration::FlatBvh),
+            _ => Option::None,
+        };
+    }
+}
@@ -1,4 +1,200 @@
-// pub struct BvhNode {
-//     left: Option<BvhNode>,
-//     right: Option<BvhNode>,
-// }
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::hittable_list::HittableList;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+/// Bounding volume hierarchy node.
+/// Leaves are the hittables themselves, so a node has one or two children.
+pub struct BvhNode {
+    left: Box<dyn Hittable>,
+    right: Option<Box<dyn Hittable>>,
+    bounding_box: Aabb,
+}
+
+impl BvhNode {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `hittables`: Non-empty list of hittables. Every one must have a bounding box
+    ///
+    /// returns: BvhNode Root of the hierarchy
+    pub fn new(mut hittables: Vec<Box<dyn Hittable>>) -> Self {
+        assert!(
+            !hittables.is_empty(),
+            "BvhNode requires at least one hittable"
+        );
+
+        let mut bounded: Vec<(Aabb, Box<dyn Hittable>)> = hittables
+            .drain(..)
+            .map(|hittable| {
+                let bounding_box = hittable
+                    .bounding_box()
+                    .expect("BvhNode requires hittables with bounding boxes");
+
+                (bounding_box, hittable)
+            })
+            .collect();
+
+        return Self::from_bounded(&mut bounded);
+    }
+
+    pub fn boxed(hittables: Vec<Box<dyn Hittable>>) -> Box<Self> {
+        return Box::new(Self::new(hittables));
+    }
+
+    /// Builds the hierarchy over every bounded hittable in the list.
+    /// Hittables without a bounding box are kept next to the hierarchy and tested linearly.
+    ///
+    /// # Arguments
+    ///
+    /// * `hittable_list`: Hittables to build the hierarchy for
+    ///
+    /// returns: Box<dyn Hittable> Root of the hierarchy or a list when there is nothing to build
+    pub fn from_list(hittable_list: HittableList) -> Box<dyn Hittable> {
+        let (bounded, mut unbounded): (Vec<_>, Vec<_>) = hittable_list
+            .into_hittables()
+            .into_iter()
+            .partition(|hittable| hittable.bounding_box().is_some());
+
+        if bounded.is_empty() {
+            return Box::new(HittableList::new(unbounded));
+        }
+
+        let node = Self::boxed(bounded);
+
+        if unbounded.is_empty() {
+            return node;
+        }
+
+        unbounded.push(node);
+
+        return Box::new(HittableList::new(unbounded));
+    }
+
+    fn from_bounded(bounded: &mut Vec<(Aabb, Box<dyn Hittable>)>) -> Self {
+        let bounding_box = bounded
+            .iter()
+            .skip(1)
+            .fold(bounded[0].0, |acc, (aabb, _)| acc.surrounding(*aabb));
+
+        if bounded.len() == 1 {
+            let (_, left) = bounded.pop().unwrap();
+
+            return Self {
+                left,
+                right: Option::None,
+                bounding_box,
+            };
+        }
+
+        if bounded.len() == 2 {
+            let (_, right) = bounded.pop().unwrap();
+            let (_, left) = bounded.pop().unwrap();
+
+            return Self {
+                left,
+                right: Option::Some(right),
+                bounding_box,
+            };
+        }
+
+        let axis = bounding_box.longest_axis();
+
+        bounded.sort_by(|(a, _), (b, _)| a.get_centroid()[axis].total_cmp(&b.get_centroid()[axis]));
+
+        let mut right_half = bounded.split_off(bounded.len() / 2);
+
+        return Self {
+            left: Box::new(Self::from_bounded(bounded)),
+            right: Option::Some(Box::new(Self::from_bounded(&mut right_half))),
+            bounding_box,
+        };
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        if !self.bounding_box.hit(ray, t_min, t_max) {
+            return false;
+        }
+
+        let hit_left = self.left.hit(ray, t_min, t_max, record);
+        let closest_so_far = if hit_left {
+            record.get_distance()
+        } else {
+            t_max
+        };
+
+        let hit_right = match &self.right {
+            Some(right) => right.hit(ray, t_min, closest_so_far, record),
+            None => false,
+        };
+
+        return hit_left || hit_right;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        return Option::Some(self.bounding_box);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra_glm::Vec3;
+
+    use super::*;
+    use crate::hittables::sphere::Sphere;
+    use crate::materials::lambertian::Lambertian;
+    use crate::misc::default_rand_gen::DefaultRandGen;
+    use crate::misc::rand_gen::RandGen;
+    use crate::misc::seeded_rand_gen::SeededRandGen;
+    use crate::textures::solid_color::SolidColor;
+
+    /// Same spheres for the same seed, scattered in a cube of size 20 around the origin
+    fn spheres(seed: u32) -> Vec<Box<dyn Hittable>> {
+        let mut rand = SeededRandGen::new(seed);
+        let material = Lambertian::new_rc(
+            SolidColor::new_rc(Vec3::from_element(0.5)),
+            DefaultRandGen::new_rc(),
+        );
+
+        return (0..300)
+            .map(|_| {
+                let center = Vec3::from_fn(|_, _| rand.uniform() * 20.0 - 10.0);
+                let radius = 0.1 + rand.uniform();
+
+                Sphere::boxed(center, radius, material.clone()) as Box<dyn Hittable>
+            })
+            .collect();
+    }
+
+    #[test]
+    fn hits_match_linear_list() {
+        let list = HittableList::new(spheres(1));
+        let bvh = BvhNode::new(spheres(1));
+        let mut rand = SeededRandGen::new(2);
+        let mut hits = 0;
+
+        for i in 0..2000 {
+            let origin = Vec3::from_fn(|_, _| rand.uniform() * 30.0 - 15.0);
+            let ray = Ray::new(origin, rand.unit_sphere());
+            // Every other ray stops short to check the upper bound is kept
+            let t_max = if i % 2 == 0 { f32::INFINITY } else { 10.0 };
+            let mut expected = HitRecord::default();
+            let mut actual = HitRecord::default();
+            let hit = list.hit(&ray, 0.001, t_max, &mut expected);
+
+            assert_eq!(bvh.hit(&ray, 0.001, t_max, &mut actual), hit);
+
+            if hit {
+                assert_eq!(actual.get_distance(), expected.get_distance());
+                assert_eq!(actual.get_point(), expected.get_point());
+                hits += 1;
+            }
+        }
+
+        assert!(hits > 200, "too few rays hit to compare, {}", hits);
+    }
+}
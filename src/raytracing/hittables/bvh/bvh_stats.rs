@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter};
+
+/// Statistics collected while building a hierarchy
+#[derive(Clone, Copy, Default)]
+pub struct BvhStats {
+    node_count: usize,
+    leaf_count: usize,
+    max_depth: usize,
+    min_leaf_size: usize,
+    max_leaf_size: usize,
+    primitive_count: usize,
+}
+
+impl BvhStats {
+    pub(super) fn add_interior(&mut self, depth: usize) {
+        self.node_count += 1;
+        self.max_depth = usize::max(self.max_depth, depth);
+    }
+
+    pub(super) fn add_leaf(&mut self, depth: usize, size: usize) {
+        self.min_leaf_size = if self.leaf_count == 0 {
+            size
+        } else {
+            usize::min(self.min_leaf_size, size)
+        };
+        self.max_leaf_size = usize::max(self.max_leaf_size, size);
+        self.node_count += 1;
+        self.leaf_count += 1;
+        self.primitive_count += size;
+        self.max_depth = usize::max(self.max_depth, depth);
+    }
+}
+
+impl BvhStats {
+    pub fn get_node_count(&self) -> usize {
+        return self.node_count;
+    }
+
+    pub fn get_leaf_count(&self) -> usize {
+        return self.leaf_count;
+    }
+
+    pub fn get_max_depth(&self) -> usize {
+        return self.max_depth;
+    }
+
+    pub fn get_min_leaf_size(&self) -> usize {
+        return self.min_leaf_size;
+    }
+
+    pub fn get_max_leaf_size(&self) -> usize {
+        return self.max_leaf_size;
+    }
+
+    pub fn get_primitive_count(&self) -> usize {
+        return self.primitive_count;
+    }
+
+    pub fn get_average_leaf_size(&self) -> f32 {
+        if self.leaf_count == 0 {
+            return 0.0;
+        }
+
+        return self.primitive_count as f32 / self.leaf_count as f32;
+    }
+}
+
+impl Display for BvhStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return write!(
+            f,
+            "nodes: {}, leaves: {}, depth: {}, primitives: {}, leaf size: {}..{} (avg {:.2})",
+            self.node_count,
+            self.leaf_count,
+            self.max_depth,
+            self.primitive_count,
+            self.min_leaf_size,
+            self.max_leaf_size,
+            self.get_average_leaf_size()
+        );
+    }
+}
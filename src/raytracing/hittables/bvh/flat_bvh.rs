@@ -0,0 +1,156 @@
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::bvh::bvh_stats::BvhStats;
+use crate::hittables::bvh::flat_bvh_node::FlatBvhNode;
+use crate::hittables::bvh::flat_bvh_tree::FlatBvhTree;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::hittable_list::HittableList;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+/// Bounding volume hierarchy built with the binned surface area heuristic.
+/// Nodes are stored depth-first in a single `Vec` and traversed without recursion.
+pub struct FlatBvh {
+    tree: FlatBvhTree,
+    primitives: Vec<Box<dyn Hittable>>,
+    unbounded: Vec<Box<dyn Hittable>>,
+}
+
+impl FlatBvh {
+    /// Hittables without a bounding box are kept outside the hierarchy and tested linearly
+    pub fn new(hittables: Vec<Box<dyn Hittable>>) -> Self {
+        let mut bounding_boxes = Vec::with_capacity(hittables.len());
+        let mut bounded = Vec::with_capacity(hittables.len());
+        let mut unbounded = Vec::new();
+
+        for hittable in hittables {
+            match hittable.bounding_box() {
+                Some(bounding_box) => {
+                    bounding_boxes.push(bounding_box);
+                    bounded.push(Option::Some(hittable));
+                }
+                None => unbounded.push(hittable),
+            }
+        }
+
+        let (tree, order) = FlatBvhTree::new(&bounding_boxes);
+        let primitives = order
+            .iter()
+            .map(|index| bounded[*index].take().unwrap())
+            .collect();
+
+        return Self {
+            tree,
+            primitives,
+            unbounded,
+        };
+    }
+
+    pub fn boxed(hittables: Vec<Box<dyn Hittable>>) -> Box<Self> {
+        return Box::new(Self::new(hittables));
+    }
+
+    pub fn from_list(hittable_list: HittableList) -> Self {
+        return Self::new(hittable_list.into_hittables());
+    }
+}
+
+impl FlatBvh {
+    pub fn get_stats(&self) -> &BvhStats {
+        return self.tree.get_stats();
+    }
+
+    pub fn get_nodes(&self) -> &[FlatBvhNode] {
+        return self.tree.get_nodes();
+    }
+}
+
+impl Hittable for FlatBvh {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let mut hit_anything = false;
+        let mut closest_so_far = t_max;
+
+        for object in &self.unbounded {
+            if object.hit(ray, t_min, closest_so_far, record) {
+                hit_anything = true;
+                closest_so_far = record.get_distance();
+            }
+        }
+
+        let hit_tree = self.tree.hit(ray, t_min, closest_so_far, |index, t_max| {
+            if self.primitives[index].hit(ray, t_min, t_max, record) {
+                Option::Some(record.get_distance())
+            } else {
+                Option::None
+            }
+        });
+
+        return hit_anything || hit_tree;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        if !self.unbounded.is_empty() {
+            return Option::None;
+        }
+
+        return self.tree.bounding_box();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra_glm::Vec3;
+
+    use super::*;
+    use crate::hittables::sphere::Sphere;
+    use crate::materials::lambertian::Lambertian;
+    use crate::misc::default_rand_gen::DefaultRandGen;
+    use crate::misc::rand_gen::RandGen;
+    use crate::misc::seeded_rand_gen::SeededRandGen;
+    use crate::textures::solid_color::SolidColor;
+
+    /// Same spheres for the same seed, scattered in a cube of size 20 around the origin
+    fn spheres(seed: u32) -> Vec<Box<dyn Hittable>> {
+        let mut rand = SeededRandGen::new(seed);
+        let material = Lambertian::new_rc(
+            SolidColor::new_rc(Vec3::from_element(0.5)),
+            DefaultRandGen::new_rc(),
+        );
+
+        return (0..300)
+            .map(|_| {
+                let center = Vec3::from_fn(|_, _| rand.uniform() * 20.0 - 10.0);
+                let radius = 0.1 + rand.uniform();
+
+                Sphere::boxed(center, radius, material.clone()) as Box<dyn Hittable>
+            })
+            .collect();
+    }
+
+    #[test]
+    fn hits_match_linear_list() {
+        let list = HittableList::new(spheres(1));
+        let bvh = FlatBvh::new(spheres(1));
+        let mut rand = SeededRandGen::new(2);
+        let mut hits = 0;
+
+        for i in 0..2000 {
+            let origin = Vec3::from_fn(|_, _| rand.uniform() * 30.0 - 15.0);
+            let ray = Ray::new(origin, rand.unit_sphere());
+            // Every other ray stops short to check the upper bound is kept
+            let t_max = if i % 2 == 0 { f32::INFINITY } else { 10.0 };
+            let mut expected = HitRecord::default();
+            let mut actual = HitRecord::default();
+            let hit = list.hit(&ray, 0.001, t_max, &mut expected);
+
+            assert_eq!(bvh.hit(&ray, 0.001, t_max, &mut actual), hit);
+
+            if hit {
+                assert_eq!(actual.get_distance(), expected.get_distance());
+                assert_eq!(actual.get_point(), expected.get_point());
+                hits += 1;
+            }
+        }
+
+        assert!(hits > 200, "too few rays hit to compare, {}", hits);
+    }
+}
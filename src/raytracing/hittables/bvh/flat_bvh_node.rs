@@ -0,0 +1,58 @@
+use crate::hittables::bounding::aabb::Aabb;
+
+/// Node of `FlatBvh`.
+/// Interior nodes store their first child right after themselves and the second one at `offset`.
+/// Leaves store `primitive_count` primitives starting from `offset`.
+#[derive(Clone, Copy)]
+pub struct FlatBvhNode {
+    bounding_box: Aabb,
+    offset: u32,
+    primitive_count: u32,
+    axis: u8,
+}
+
+impl FlatBvhNode {
+    pub fn leaf(bounding_box: Aabb, first_primitive: u32, primitive_count: u32) -> Self {
+        return Self {
+            bounding_box,
+            offset: first_primitive,
+            primitive_count,
+            axis: 0,
+        };
+    }
+
+    pub fn interior(bounding_box: Aabb, second_child: u32, axis: u8) -> Self {
+        return Self {
+            bounding_box,
+            offset: second_child,
+            primitive_count: 0,
+            axis,
+        };
+    }
+}
+
+impl FlatBvhNode {
+    pub fn get_bounding_box(&self) -> &Aabb {
+        return &self.bounding_box;
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        return self.primitive_count > 0;
+    }
+
+    pub fn get_offset(&self) -> usize {
+        return self.offset as usize;
+    }
+
+    pub fn get_primitive_count(&self) -> usize {
+        return self.primitive_count as usize;
+    }
+
+    pub fn get_axis(&self) -> usize {
+        return self.axis as usize;
+    }
+
+    pub(super) fn set_offset(&mut self, offset: u32) {
+        self.offset = offset;
+    }
+}
@@ -0,0 +1,337 @@
+use std::mem::swap;
+
+use nalgebra_glm::Vec3;
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::bvh::bvh_stats::BvhStats;
+use crate::hittables::bvh::flat_bvh_node::FlatBvhNode;
+use crate::misc::ray::Ray;
+
+const BIN_COUNT: usize = 12;
+const MAX_LEAF_SIZE: usize = 4;
+// Cost of visiting a node relative to the cost of one primitive intersection
+const TRAVERSAL_COST: f32 = 1.0;
+// Traversal pushes at most one node per level, so the depth is capped by the stack size
+const MAX_DEPTH: usize = 63;
+const STACK_SIZE: usize = MAX_DEPTH + 1;
+
+struct BuildPrimitive {
+    bounding_box: Aabb,
+    centroid: Vec3,
+    index: usize,
+}
+
+struct Split {
+    axis: usize,
+    bin: usize,
+    cost: f32,
+    centroid_min: f32,
+    bin_scale: f32,
+}
+
+impl Split {
+    fn goes_left(&self, primitive: &BuildPrimitive) -> bool {
+        return bin_index(
+            primitive.centroid[self.axis],
+            self.centroid_min,
+            self.bin_scale,
+        ) <= self.bin;
+    }
+}
+
+/// Surface area heuristic hierarchy over abstract primitives, known only by their bounding boxes.
+/// Nodes are stored depth-first in a single `Vec` and traversed without recursion.
+pub struct FlatBvhTree {
+    nodes: Vec<FlatBvhNode>,
+    stats: BvhStats,
+}
+
+impl FlatBvhTree {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `bounding_boxes`: Bounding box of every primitive
+    ///
+    /// returns: (FlatBvhTree, Vec<usize>) Tree and primitive order.
+    /// Leaves refer to primitives by their position in that order, not in `bounding_boxes`
+    pub fn new(bounding_boxes: &[Aabb]) -> (Self, Vec<usize>) {
+        let mut build_primitives: Vec<BuildPrimitive> = bounding_boxes
+            .iter()
+            .enumerate()
+            .map(|(index, bounding_box)| BuildPrimitive {
+                bounding_box: *bounding_box,
+                centroid: bounding_box.get_centroid(),
+                index,
+            })
+            .collect();
+
+        let mut nodes = Vec::with_capacity(build_primitives.len() * 2);
+        let mut stats = BvhStats::default();
+
+        if !build_primitives.is_empty() {
+            build(&mut nodes, &mut build_primitives, 0, 0, &mut stats);
+        }
+
+        let order = build_primitives
+            .iter()
+            .map(|primitive| primitive.index)
+            .collect();
+
+        return (Self { nodes, stats }, order);
+    }
+}
+
+impl FlatBvhTree {
+    pub fn get_stats(&self) -> &BvhStats {
+        return &self.stats;
+    }
+
+    pub fn get_nodes(&self) -> &[FlatBvhNode] {
+        return &self.nodes;
+    }
+
+    pub fn bounding_box(&self) -> Option<Aabb> {
+        return self.nodes.first().map(|node| *node.get_bounding_box());
+    }
+
+    /// Walks every leaf the ray can reach, nearest child first.
+    ///
+    /// # Arguments
+    ///
+    /// * `ray`:
+    /// * `t_min`:
+    /// * `t_max`:
+    /// * `hit_primitive`: Called with primitive position and the current closest distance.
+    ///   Returns distance to the primitive if it was hit closer than that
+    ///
+    /// returns: bool If true - hit, false - miss
+    pub fn hit<F>(&self, ray: &Ray, t_min: f32, t_max: f32, mut hit_primitive: F) -> bool
+    where
+        F: FnMut(usize, f32) -> Option<f32>,
+    {
+        if self.nodes.is_empty() {
+            return false;
+        }
+
+        let mut hit_anything = false;
+        let mut closest_so_far = t_max;
+        let mut stack = [0usize; STACK_SIZE];
+        let mut stack_size = 0;
+        let mut current = 0;
+
+        loop {
+            let node = &self.nodes[current];
+
+            if node.get_bounding_box().hit(ray, t_min, closest_so_far) {
+                if node.is_leaf() {
+                    let first = node.get_offset();
+
+                    for primitive in first..first + node.get_primitive_count() {
+                        if let Some(distance) = hit_primitive(primitive, closest_so_far) {
+                            hit_anything = true;
+                            closest_so_far = distance;
+                        }
+                    }
+                } else {
+                    // Visit the child closer to the ray origin first to shrink `closest_so_far` early
+                    let mut near = current + 1;
+                    let mut far = node.get_offset();
+
+                    if ray.get_direction()[node.get_axis()] < 0.0 {
+                        swap(&mut near, &mut far);
+                    }
+
+                    stack[stack_size] = far;
+                    stack_size += 1;
+                    current = near;
+
+                    continue;
+                }
+            }
+
+            if stack_size == 0 {
+                break;
+            }
+
+            stack_size -= 1;
+            current = stack[stack_size];
+        }
+
+        return hit_anything;
+    }
+}
+
+fn bin_index(centroid: f32, centroid_min: f32, bin_scale: f32) -> usize {
+    return usize::min(
+        ((centroid - centroid_min) * bin_scale) as usize,
+        BIN_COUNT - 1,
+    );
+}
+
+fn surround(aabb: Option<Aabb>, other: &Aabb) -> Option<Aabb> {
+    return match aabb {
+        Some(aabb) => Option::Some(aabb.surrounding(*other)),
+        None => Option::Some(*other),
+    };
+}
+
+fn find_split(primitives: &[BuildPrimitive], bounding_box: &Aabb) -> Option<Split> {
+    let mut centroid_min = primitives[0].centroid;
+    let mut centroid_max = primitives[0].centroid;
+
+    for primitive in primitives.iter().skip(1) {
+        centroid_min = centroid_min.inf(&primitive.centroid);
+        centroid_max = centroid_max.sup(&primitive.centroid);
+    }
+
+    let parent_area = bounding_box.surface_area();
+    let inv_parent_area = if parent_area > 0.0 {
+        1.0 / parent_area
+    } else {
+        0.0
+    };
+    let mut best: Option<Split> = Option::None;
+
+    for axis in 0..3 {
+        let extent = centroid_max[axis] - centroid_min[axis];
+
+        if extent <= 0.0 {
+            continue;
+        }
+
+        let bin_scale = BIN_COUNT as f32 / extent;
+        let mut bin_boxes: [Option<Aabb>; BIN_COUNT] = [Option::None; BIN_COUNT];
+        let mut bin_counts = [0usize; BIN_COUNT];
+
+        for primitive in primitives {
+            let bin = bin_index(primitive.centroid[axis], centroid_min[axis], bin_scale);
+
+            bin_boxes[bin] = surround(bin_boxes[bin], &primitive.bounding_box);
+            bin_counts[bin] += 1;
+        }
+
+        // Areas and counts of everything to the right of each split plane
+        let mut right_areas = [0.0f32; BIN_COUNT - 1];
+        let mut right_counts = [0usize; BIN_COUNT - 1];
+        let mut right_box: Option<Aabb> = Option::None;
+        let mut right_count = 0;
+
+        for split in (0..BIN_COUNT - 1).rev() {
+            if let Some(aabb) = &bin_boxes[split + 1] {
+                right_box = surround(right_box, aabb);
+            }
+
+            right_count += bin_counts[split + 1];
+            right_areas[split] = right_box.map_or(0.0, |aabb| aabb.surface_area());
+            right_counts[split] = right_count;
+        }
+
+        let mut left_box: Option<Aabb> = Option::None;
+        let mut left_count = 0;
+
+        for split in 0..BIN_COUNT - 1 {
+            if let Some(aabb) = &bin_boxes[split] {
+                left_box = surround(left_box, aabb);
+            }
+
+            left_count += bin_counts[split];
+
+            if left_count == 0 || right_counts[split] == 0 {
+                continue;
+            }
+
+            let left_area = left_box.map_or(0.0, |aabb| aabb.surface_area());
+            let cost = TRAVERSAL_COST
+                + (left_area * left_count as f32 + right_areas[split] * right_counts[split] as f32)
+                    * inv_parent_area;
+
+            if best.as_ref().is_none_or(|best| cost < best.cost) {
+                best = Option::Some(Split {
+                    axis,
+                    bin: split,
+                    cost,
+                    centroid_min: centroid_min[axis],
+                    bin_scale,
+                });
+            }
+        }
+    }
+
+    return best;
+}
+
+/// Moves primitives that go to the left child to the front, returns their count
+fn partition(primitives: &mut [BuildPrimitive], split: &Split) -> usize {
+    let mut left_count = 0;
+
+    for i in 0..primitives.len() {
+        if split.goes_left(&primitives[i]) {
+            primitives.swap(i, left_count);
+            left_count += 1;
+        }
+    }
+
+    return left_count;
+}
+
+fn build(
+    nodes: &mut Vec<FlatBvhNode>,
+    primitives: &mut [BuildPrimitive],
+    first_primitive: usize,
+    depth: usize,
+    stats: &mut BvhStats,
+) -> usize {
+    let bounding_box = primitives
+        .iter()
+        .skip(1)
+        .fold(primitives[0].bounding_box, |acc, primitive| {
+            acc.surrounding(primitive.bounding_box)
+        });
+    let node_index = nodes.len();
+    let leaf = FlatBvhNode::leaf(
+        bounding_box,
+        first_primitive as u32,
+        primitives.len() as u32,
+    );
+
+    if primitives.len() == 1 || depth >= MAX_DEPTH {
+        nodes.push(leaf);
+        stats.add_leaf(depth, primitives.len());
+
+        return node_index;
+    }
+
+    let split = match find_split(primitives, &bounding_box) {
+        Some(split) => split,
+        None => {
+            // Every centroid is in the same place, nothing to split
+            nodes.push(leaf);
+            stats.add_leaf(depth, primitives.len());
+
+            return node_index;
+        }
+    };
+
+    let leaf_cost = primitives.len() as f32;
+
+    if primitives.len() <= MAX_LEAF_SIZE && split.cost >= leaf_cost {
+        nodes.push(leaf);
+        stats.add_leaf(depth, primitives.len());
+
+        return node_index;
+    }
+
+    let left_count = partition(primitives, &split);
+    let (left, right) = primitives.split_at_mut(left_count);
+
+    nodes.push(FlatBvhNode::interior(bounding_box, 0, split.axis as u8));
+    stats.add_interior(depth);
+
+    build(nodes, left, first_primitive, depth + 1, stats);
+    let second_child = build(nodes, right, first_primitive + left_count, depth + 1, stats);
+
+    nodes[node_index].set_offset(second_child as u32);
+
+    return node_index;
+}
@@ -0,0 +1,70 @@
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::bvh::bvh_stats::BvhStats;
+use crate::hittables::bvh::flat_bvh_tree::FlatBvhTree;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::transformed::Transformed;
+use crate::hittables::triangle_mesh::TriangleMesh;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+/// Top level of a two-level hierarchy over instances of shared meshes.
+/// Rays reaching an instance continue through the bottom level hierarchy of its geometry,
+/// so every copy costs only its transform and material no matter how big the geometry is.
+pub struct InstanceBvh {
+    tree: FlatBvhTree,
+    instances: Vec<Transformed<TriangleMesh>>,
+}
+
+impl InstanceBvh {
+    /// Instances of meshes without triangles can not be hit, so they are left out
+    pub fn new(instances: Vec<Transformed<TriangleMesh>>) -> Self {
+        let mut bounding_boxes = Vec::with_capacity(instances.len());
+        let mut bounded = Vec::with_capacity(instances.len());
+
+        for instance in instances {
+            if let Some(bounding_box) = instance.bounding_box() {
+                bounding_boxes.push(bounding_box);
+                bounded.push(Option::Some(instance));
+            }
+        }
+
+        let (tree, order) = FlatBvhTree::new(&bounding_boxes);
+        let instances = order
+            .iter()
+            .map(|index| bounded[*index].take().unwrap())
+            .collect();
+
+        return Self { tree, instances };
+    }
+
+    pub fn boxed(instances: Vec<Transformed<TriangleMesh>>) -> Box<Self> {
+        return Box::new(Self::new(instances));
+    }
+}
+
+impl InstanceBvh {
+    pub fn get_stats(&self) -> &BvhStats {
+        return self.tree.get_stats();
+    }
+
+    /// Instances in hierarchy order, which may differ from the order they were given in
+    pub fn get_instances(&self) -> &[Transformed<TriangleMesh>] {
+        return &self.instances;
+    }
+}
+
+impl Hittable for InstanceBvh {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        return self.tree.hit(ray, t_min, t_max, |index, t_max| {
+            if self.instances[index].hit(ray, t_min, t_max, record) {
+                Option::Some(record.get_distance())
+            } else {
+                Option::None
+            }
+        });
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        return self.tree.bounding_box();
+    }
+}
@@ -1 +1,7 @@
+pub mod acceleration;
 pub mod bvh_node;
+pub mod bvh_stats;
+pub mod flat_bvh;
+pub mod flat_bvh_node;
+pub mod flat_bvh_tree;
+pub mod instance_bvh;
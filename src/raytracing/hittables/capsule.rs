@@ -0,0 +1,144 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::polynomial::solve_quadratic;
+use crate::misc::ray::Ray;
+
+/// Points within a distance from a segment: cylinder with hemispheres at both ends.
+/// Works in its own space where the segment goes along z from the origin
+pub struct Capsule {
+    point_one: Vec3,
+    height: f32,
+    radius: f32,
+    frame: Frame,
+    material: MaterialRc,
+}
+
+impl Capsule {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `point_one`: One end of the segment
+    /// * `point_two`: Other end of the segment, must differ from `point_one`
+    /// * `radius`:
+    /// * `material`:
+    pub fn new(point_one: Vec3, point_two: Vec3, radius: f32, material: MaterialRc) -> Self {
+        let axis = point_two - point_one;
+        let height = axis.magnitude();
+
+        return Self {
+            point_one,
+            height,
+            radius,
+            frame: Frame::new(&(axis / height)),
+            material,
+        };
+    }
+
+    pub fn boxed(point_one: Vec3, point_two: Vec3, radius: f32, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(point_one, point_two, radius, material));
+    }
+}
+
+impl Capsule {
+    /// Closest hit in own space
+    ///
+    /// returns: Option<(f32, Vec3)> Distance and outward normal in own space
+    fn intersect(
+        &self,
+        origin: &Vec3,
+        direction: &Vec3,
+        t_min: f32,
+        t_max: f32,
+    ) -> Option<(f32, Vec3)> {
+        let mut closest = Option::None;
+        let mut closest_so_far = t_max;
+
+        if let Some((near, far)) = solve_quadratic(
+            direction.x * direction.x + direction.y * direction.y,
+            2.0 * (origin.x * direction.x + origin.y * direction.y),
+            origin.x * origin.x + origin.y * origin.y - self.radius * self.radius,
+        ) {
+            for t in [near, far] {
+                let point = origin + direction * t;
+
+                if t >= t_min && t <= closest_so_far && (0.0..=self.height).contains(&point.z) {
+                    closest = Option::Some((t, Vec3::new(point.x, point.y, 0.0) / self.radius));
+                    closest_so_far = t;
+
+                    break;
+                }
+            }
+        }
+
+        // Only the outer half of every end sphere belongs to the surface
+        for (center_z, side) in [(0.0, -1.0), (self.height, 1.0)] {
+            let to_origin = origin - Vec3::new(0.0, 0.0, center_z);
+            let roots = solve_quadratic(
+                direction.magnitude_squared(),
+                2.0 * to_origin.dot(direction),
+                to_origin.magnitude_squared() - self.radius * self.radius,
+            );
+
+            if let Some((near, far)) = roots {
+                for t in [near, far] {
+                    let offset = to_origin + direction * t;
+
+                    if t >= t_min && t <= closest_so_far && offset.z * side >= 0.0 {
+                        closest = Option::Some((t, offset / self.radius));
+                        closest_so_far = t;
+
+                        break;
+                    }
+                }
+            }
+        }
+
+        return closest;
+    }
+}
+
+impl Hittable for Capsule {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let origin = self.frame.to_local(&(ray.get_origin() - self.point_one));
+        let direction = self.frame.to_local(ray.get_direction());
+        let (t, normal) = match self.intersect(&origin, &direction, t_min, t_max) {
+            Some(closest) => closest,
+            None => return false,
+        };
+
+        let point = origin + direction * t;
+
+        record.set_distance(t);
+        record.set_point(ray.get_at(t));
+        record.set_face_normal(ray, self.frame.to_world(&normal));
+        // Around the axis and along it from the bottom to the top of the end spheres
+        record.set_uv(Vec2::new(
+            (point.y.atan2(point.x) / (2.0 * PI)).rem_euclid(1.0),
+            ((point.z + self.radius) / (self.height + 2.0 * self.radius)).clamp(0.0, 1.0),
+        ));
+        record.set_material(self.material.clone());
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let point_two = self.point_one + self.frame.get_normal() * self.height;
+
+        return Option::Some(Aabb::new(
+            self.point_one.inf(&point_two).add_scalar(-self.radius),
+            self.point_one.sup(&point_two).add_scalar(self.radius),
+        ));
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+}
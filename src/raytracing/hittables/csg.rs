@@ -0,0 +1,203 @@
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::csg_operation::CsgOperation;
+use crate::hittables::hittable::Hittable;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+/// Solid made of two closed hittables by constructive solid geometry.
+/// Every part of its surface keeps the material of the hittable it comes from,
+/// so a difference is lined with the material of the second one
+pub struct Csg {
+    operation: CsgOperation,
+    first: Box<dyn Hittable>,
+    second: Box<dyn Hittable>,
+}
+
+impl Csg {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `operation`:
+    /// * `first`: Closed hittable
+    /// * `second`: Closed hittable
+    pub fn new(
+        operation: CsgOperation,
+        first: Box<dyn Hittable>,
+        second: Box<dyn Hittable>,
+    ) -> Self {
+        return Self {
+            operation,
+            first,
+            second,
+        };
+    }
+
+    pub fn boxed(
+        operation: CsgOperation,
+        first: Box<dyn Hittable>,
+        second: Box<dyn Hittable>,
+    ) -> Box<Self> {
+        return Box::new(Self::new(operation, first, second));
+    }
+}
+
+impl Csg {
+    pub fn get_operation(&self) -> CsgOperation {
+        return self.operation;
+    }
+}
+
+impl Hittable for Csg {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let intervals = match self.intervals(ray) {
+            Some(intervals) => intervals,
+            None => return false,
+        };
+
+        // Intervals are ordered, so the first boundary in range is the closest one
+        for (entry, exit) in intervals {
+            for boundary in [entry, exit] {
+                if boundary.get_distance() >= t_min && boundary.get_distance() <= t_max {
+                    *record = boundary;
+
+                    return true;
+                }
+            }
+        }
+
+        return false;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let first = self.first.bounding_box()?;
+
+        return match self.operation {
+            CsgOperation::Union => Option::Some(first.surrounding(self.second.bounding_box()?)),
+            CsgOperation::Intersection => {
+                let second = self.second.bounding_box()?;
+                let minimum = first.get_minimum().sup(second.get_minimum());
+                let maximum = first.get_maximum().inf(second.get_maximum());
+
+                // Solids not overlapping leave nothing, which still needs a valid box
+                Option::Some(Aabb::new(minimum, maximum.sup(&minimum)))
+            }
+            CsgOperation::Difference => Option::Some(first),
+        };
+    }
+
+    fn is_closed(&self) -> bool {
+        return self.first.is_closed() && self.second.is_closed();
+    }
+
+    fn intervals(&self, ray: &Ray) -> Option<Vec<(HitRecord, HitRecord)>> {
+        // Boundaries of both solids by distance, each with its solid and whether it enters it
+        let mut boundaries = Vec::new();
+
+        for (is_first, hittable) in [(true, &self.first), (false, &self.second)] {
+            for (entry, exit) in hittable.intervals(ray)? {
+                boundaries.push((entry, is_first, true));
+                boundaries.push((exit, is_first, false));
+            }
+        }
+
+        boundaries.sort_by(|a, b| a.0.get_distance().total_cmp(&b.0.get_distance()));
+
+        let mut intervals = Vec::new();
+        let mut entry = Option::None;
+        let mut in_first = false;
+        let mut in_second = false;
+
+        for (mut record, is_first, is_entry) in boundaries {
+            let was_inside = self.operation.contains(in_first, in_second);
+
+            if is_first {
+                in_first = is_entry;
+            } else {
+                in_second = is_entry;
+            }
+
+            let is_inside = self.operation.contains(in_first, in_second);
+
+            // Normal keeps facing against the ray, only the side of the result changes
+            if is_inside && !was_inside {
+                record.set_is_front_face(true);
+                entry = Option::Some(record);
+            } else if was_inside && !is_inside {
+                record.set_is_front_face(false);
+
+                if let Some(start) = entry.take() {
+                    intervals.push((start, record));
+                }
+            }
+        }
+
+        return Option::Some(intervals);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra_glm::Vec3;
+
+    use super::*;
+    use crate::hittables::cuboid::Cuboid;
+    use crate::hittables::sphere::Sphere;
+    use crate::materials::lambertian::Lambertian;
+    use crate::materials::material::MaterialRc;
+    use crate::misc::default_rand_gen::DefaultRandGen;
+    use crate::textures::solid_color::SolidColor;
+
+    const ENTRY: f32 = 3.3759;
+    const EXIT: f32 = 3.3761;
+
+    fn material() -> MaterialRc {
+        return Lambertian::new_rc(
+            SolidColor::new_rc(Vec3::from_element(0.5)),
+            DefaultRandGen::new_rc(),
+        );
+    }
+
+    fn cuboid() -> Box<Cuboid> {
+        return Cuboid::boxed(
+            Vec3::new(-0.5, -0.3, -0.6),
+            Vec3::new(0.4, 0.6, 0.2),
+            material(),
+        );
+    }
+
+    /// Enters the top of the cuboid next to its edge and leaves through the side right after
+    fn corner_ray() -> Ray {
+        return Ray::new(
+            Vec3::new(-0.030115, 2.204909, 2.594523),
+            Vec3::new(0.1274, -0.6933, -0.7093),
+        );
+    }
+
+    #[test]
+    fn intervals_keep_exit_close_to_entry() {
+        let intervals = cuboid().intervals(&corner_ray()).unwrap();
+
+        assert_eq!(intervals.len(), 1);
+
+        let (entry, exit) = &intervals[0];
+
+        assert!((entry.get_distance() - ENTRY).abs() < 1e-5);
+        assert!((exit.get_distance() - EXIT).abs() < 1e-5);
+        assert!(entry.get_is_front_face());
+        assert!(!exit.get_is_front_face());
+    }
+
+    #[test]
+    fn intersection_hits_thin_overlap() {
+        let csg = Csg::new(
+            CsgOperation::Intersection,
+            Sphere::boxed(Vec3::zeros(), 0.8, material()),
+            cuboid(),
+        );
+        let mut record = HitRecord::default();
+
+        assert!(csg.hit(&corner_ray(), 0.0001, f32::INFINITY, &mut record));
+        assert!((record.get_distance() - ENTRY).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,43 @@
+/// How a solid is made of two others
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CsgOperation {
+    /// Inside either of them
+    Union,
+    /// Inside both of them
+    Intersection,
+    /// Inside the first one but not the second one
+    Difference,
+}
+
+impl CsgOperation {
+    /// Whether a point is inside the result
+    ///
+    /// # Arguments
+    ///
+    /// * `in_first`: Whether the point is inside the first solid
+    /// * `in_second`: Whether the point is inside the second solid
+    pub fn contains(&self, in_first: bool, in_second: bool) -> bool {
+        return match self {
+            CsgOperation::Union => in_first || in_second,
+            CsgOperation::Intersection => in_first && in_second,
+            CsgOperation::Difference => in_first && !in_second,
+        };
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            CsgOperation::Union => "union",
+            CsgOperation::Intersection => "intersection",
+            CsgOperation::Difference => "difference",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "union" => Option::Some(CsgOperation::Union),
+            "intersection" => Option::Some(CsgOperation::Intersection),
+            "difference" => Option::Some(CsgOperation::Difference),
+            _ => Option::None,
+        };
+    }
+}
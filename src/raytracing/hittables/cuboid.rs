@@ -0,0 +1,151 @@
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::quad::Quad;
+use crate::materials::material::MaterialRc;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+use crate::misc::surface_sample::SurfaceSample;
+
+/// Axis-aligned box made of six quads facing outwards, transform it to rotate
+pub struct Cuboid {
+    minimum: Vec3,
+    maximum: Vec3,
+    faces: Vec<Quad>,
+    area: f32,
+}
+
+impl Cuboid {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `minimum`: Corner with the smallest coordinates
+    /// * `maximum`: Opposite corner, larger on every axis
+    /// * `material`:
+    pub fn new(minimum: Vec3, maximum: Vec3, material: MaterialRc) -> Self {
+        let size = maximum - minimum;
+        let dx = Vec3::new(size.x, 0.0, 0.0);
+        let dy = Vec3::new(0.0, size.y, 0.0);
+        let dz = Vec3::new(0.0, 0.0, size.z);
+        let faces = vec![
+            Quad::new(
+                Vec3::new(minimum.x, minimum.y, maximum.z),
+                dx,
+                dy,
+                material.clone(),
+            ),
+            Quad::new(
+                Vec3::new(maximum.x, minimum.y, minimum.z),
+                -dx,
+                dy,
+                material.clone(),
+            ),
+            Quad::new(
+                Vec3::new(maximum.x, minimum.y, maximum.z),
+                -dz,
+                dy,
+                material.clone(),
+            ),
+            Quad::new(minimum, dz, dy, material.clone()),
+            Quad::new(
+                Vec3::new(minimum.x, maximum.y, maximum.z),
+                dx,
+                -dz,
+                material.clone(),
+            ),
+            Quad::new(minimum, dx, dz, material),
+        ];
+        let area = faces.iter().map(|x| x.get_area()).sum();
+
+        return Self {
+            minimum,
+            maximum,
+            faces,
+            area,
+        };
+    }
+
+    pub fn boxed(minimum: Vec3, maximum: Vec3, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(minimum, maximum, material));
+    }
+}
+
+impl Cuboid {
+    pub fn get_area(&self) -> f32 {
+        return self.area;
+    }
+
+    pub fn get_faces(&self) -> &[Quad] {
+        return &self.faces;
+    }
+}
+
+impl Hittable for Cuboid {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let mut hit_anything = false;
+        let mut closest_so_far = t_max;
+
+        for face in &self.faces {
+            if face.hit(ray, t_min, closest_so_far, record) {
+                hit_anything = true;
+                closest_so_far = record.get_distance();
+            }
+        }
+
+        return hit_anything;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        return Option::Some(Aabb::new(self.minimum, self.maximum));
+    }
+
+    /// Picks a face by its area, then a point on it, so points are uniform over the whole box.
+    /// Points on the far side are hidden by the box itself, but keep the density right
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let mut target = u.x * self.area;
+
+        for (i, face) in self.faces.iter().enumerate() {
+            if target >= face.get_area() && i + 1 < self.faces.len() {
+                target -= face.get_area();
+
+                continue;
+            }
+
+            // Reuse the part of `u.x` left after choosing the face
+            let remapped = Vec2::new((target / face.get_area()).clamp(0.0, 1.0), u.y);
+            let point = face.get_point(&remapped);
+            let to_point = point - origin;
+            let distance = to_point.magnitude();
+
+            if distance <= 0.0 {
+                return Option::None;
+            }
+
+            // The direction also passes through the opposite face, which could have been picked
+            let pdf = self.pdf(origin, &(to_point / distance));
+
+            if pdf <= 0.0 {
+                return Option::None;
+            }
+
+            return Option::Some(SurfaceSample::new(point, *face.get_normal(), pdf));
+        }
+
+        return Option::None;
+    }
+
+    /// Every face the direction passes through could have been sampled
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return self
+            .faces
+            .iter()
+            .map(|x| x.pdf(origin, direction) * x.get_area() / self.area)
+            .sum();
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+}
@@ -0,0 +1,157 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::polynomial::solve_quadratic;
+use crate::misc::ray::Ray;
+
+/// Circular cylinder between two points, open or closed by disks at both ends.
+/// Works in its own space where the axis is z and the base is at the origin
+pub struct Cylinder {
+    base: Vec3,
+    height: f32,
+    radius: f32,
+    capped: bool,
+    frame: Frame,
+    material: MaterialRc,
+}
+
+impl Cylinder {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: Center of the bottom end
+    /// * `top`: Center of the top end, must differ from `base`
+    /// * `radius`:
+    /// * `capped`: Whether the ends are closed by disks
+    /// * `material`:
+    pub fn new(base: Vec3, top: Vec3, radius: f32, capped: bool, material: MaterialRc) -> Self {
+        let axis = top - base;
+        let height = axis.magnitude();
+
+        return Self {
+            base,
+            height,
+            radius,
+            capped,
+            frame: Frame::new(&(axis / height)),
+            material,
+        };
+    }
+
+    pub fn boxed(
+        base: Vec3,
+        top: Vec3,
+        radius: f32,
+        capped: bool,
+        material: MaterialRc,
+    ) -> Box<Self> {
+        return Box::new(Self::new(base, top, radius, capped, material));
+    }
+}
+
+impl Cylinder {
+    /// Closest hit in own space
+    ///
+    /// returns: Option<(f32, Vec3, Vec2)> Distance, outward normal in own space and UV
+    fn intersect(
+        &self,
+        origin: &Vec3,
+        direction: &Vec3,
+        t_min: f32,
+        t_max: f32,
+    ) -> Option<(f32, Vec3, Vec2)> {
+        let mut closest = Option::None;
+        let mut closest_so_far = t_max;
+
+        if let Some((near, far)) = solve_quadratic(
+            direction.x * direction.x + direction.y * direction.y,
+            2.0 * (origin.x * direction.x + origin.y * direction.y),
+            origin.x * origin.x + origin.y * origin.y - self.radius * self.radius,
+        ) {
+            for t in [near, far] {
+                let point = origin + direction * t;
+
+                if t >= t_min && t <= closest_so_far && (0.0..=self.height).contains(&point.z) {
+                    let u = (point.y.atan2(point.x) / (2.0 * PI)).rem_euclid(1.0);
+
+                    closest = Option::Some((
+                        t,
+                        Vec3::new(point.x, point.y, 0.0) / self.radius,
+                        Vec2::new(u, point.z / self.height),
+                    ));
+                    closest_so_far = t;
+
+                    break;
+                }
+            }
+        }
+
+        if self.capped && direction.z != 0.0 {
+            for (z, normal_z) in [(0.0, -1.0), (self.height, 1.0)] {
+                let t = (z - origin.z) / direction.z;
+                let point = origin + direction * t;
+
+                if t >= t_min
+                    && t <= closest_so_far
+                    && point.x * point.x + point.y * point.y <= self.radius * self.radius
+                {
+                    closest = Option::Some((
+                        t,
+                        Vec3::new(0.0, 0.0, normal_z),
+                        Vec2::new(
+                            0.5 + point.x / (2.0 * self.radius),
+                            0.5 + point.y / (2.0 * self.radius),
+                        ),
+                    ));
+                    closest_so_far = t;
+                }
+            }
+        }
+
+        return closest;
+    }
+}
+
+impl Hittable for Cylinder {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let origin = self.frame.to_local(&(ray.get_origin() - self.base));
+        let direction = self.frame.to_local(ray.get_direction());
+        let (t, normal, uv) = match self.intersect(&origin, &direction, t_min, t_max) {
+            Some(closest) => closest,
+            None => return false,
+        };
+
+        record.set_distance(t);
+        record.set_point(ray.get_at(t));
+        record.set_face_normal(ray, self.frame.to_world(&normal));
+        record.set_uv(uv);
+        record.set_material(self.material.clone());
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let top = self.base + self.frame.get_normal() * self.height;
+        // Extent of the end disks along every axis
+        let extent = self
+            .frame
+            .get_normal()
+            .map(|x| self.radius * (1.0 - x * x).max(0.0).sqrt());
+
+        return Option::Some(Aabb::new(
+            self.base.inf(&top) - extent,
+            self.base.sup(&top) + extent,
+        ));
+    }
+
+    fn is_closed(&self) -> bool {
+        return self.capped;
+    }
+}
@@ -0,0 +1,113 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+use crate::misc::surface_sample::{area_pdf, SurfaceSample};
+
+const PARALLEL_EPSILON: f32 = 1e-8;
+
+/// Flat circle facing along its normal
+pub struct Disk {
+    center: Vec3,
+    radius: f32,
+    frame: Frame,
+    material: MaterialRc,
+}
+
+impl Disk {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `center`:
+    /// * `normal`: Direction the disk faces, normalized here
+    /// * `radius`:
+    /// * `material`:
+    pub fn new(center: Vec3, normal: Vec3, radius: f32, material: MaterialRc) -> Self {
+        return Self {
+            center,
+            radius,
+            frame: Frame::new(&normal.normalize()),
+            material,
+        };
+    }
+
+    pub fn boxed(center: Vec3, normal: Vec3, radius: f32, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(center, normal, radius, material));
+    }
+}
+
+impl Disk {
+    pub fn get_area(&self) -> f32 {
+        return PI * self.radius * self.radius;
+    }
+}
+
+impl Hittable for Disk {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let normal = self.frame.get_normal();
+        let denominator = normal.dot(ray.get_direction());
+
+        if denominator.abs() < PARALLEL_EPSILON {
+            return false;
+        }
+
+        let t = normal.dot(&(self.center - ray.get_origin())) / denominator;
+
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        let point = ray.get_at(t);
+        let offset = point - self.center;
+
+        if offset.magnitude_squared() > self.radius * self.radius {
+            return false;
+        }
+
+        let local = self.frame.to_local(&offset);
+
+        record.set_face_normal(ray, *normal);
+
+        record.set_distance(t);
+        record.set_material(self.material.clone());
+        record.set_point(point);
+        // Planar projection, so that the square [0, 1]² just covers the disk
+        record.set_uv(Vec2::new(
+            0.5 + local.x / (2.0 * self.radius),
+            0.5 + local.y / (2.0 * self.radius),
+        ));
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let normal = self.frame.get_normal();
+        let extent = normal.map(|x| self.radius * (1.0 - x * x).max(0.0).sqrt());
+
+        return Option::Some(Aabb::new(self.center - extent, self.center + extent));
+    }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let distance = self.radius * u.x.sqrt();
+        let phi = 2.0 * PI * u.y;
+        let local = Vec3::new(distance * phi.cos(), distance * phi.sin(), 0.0);
+
+        return SurfaceSample::from_area(
+            origin,
+            self.center + self.frame.to_world(&local),
+            *self.frame.get_normal(),
+            self.get_area(),
+        );
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return area_pdf(self, self.get_area(), origin, direction);
+    }
+}
@@ -1,6 +1,9 @@
+use nalgebra_glm::{Vec2, Vec3};
+
 use crate::hittables::bounding::aabb::Aabb;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
+use crate::misc::surface_sample::SurfaceSample;
 
 pub trait Hittable {
     ///
@@ -16,4 +19,112 @@ pub trait Hittable {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool;
 
     fn bounding_box(&self) -> Option<Aabb>;
+
+    /// Samples a point on the surface, used to light the scene with emissive hittables
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Point the surface is seen from
+    /// * `u`: Two uniform random numbers in [0, 1)
+    ///
+    /// returns: None if the hittable can not be sampled or the sample is useless
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        return Option::None;
+    }
+
+    /// Solid angle density with which `sample` picks `direction` from `origin`
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Point the surface is seen from
+    /// * `direction`: Unit direction from `origin`
+    ///
+    /// returns: f32 Zero if `direction` misses the hittable or it can not be sampled
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return 0.0;
+    }
+
+    /// Whether the surface encloses a volume, which solid operations need
+    fn is_closed(&self) -> bool {
+        return false;
+    }
+
+    /// Parts of the whole line of the ray, behind its origin too, that are inside the hittable.
+    /// Walks the hits along the line by default
+    ///
+    /// # Arguments
+    ///
+    /// * `ray`:
+    ///
+    /// returns: Option<Vec<(HitRecord, HitRecord)>> Entry and exit hits ordered by distance,
+    /// None if the hittable is not closed
+    fn intervals(&self, ray: &Ray) -> Option<Vec<(HitRecord, HitRecord)>> {
+        if !self.is_closed() {
+            return Option::None;
+        }
+
+        let mut intervals = Vec::new();
+        let mut entry: Option<HitRecord> = Option::None;
+        let mut last: Option<HitRecord> = Option::None;
+        let mut record = HitRecord::default();
+        let mut t_min = f32::NEG_INFINITY;
+
+        while self.hit(ray, t_min, f32::INFINITY, &mut record) {
+            let distance = record.get_distance();
+
+            if !distance.is_finite() {
+                break;
+            }
+
+            // Any longer step could skip the exit of a thin part, the same hit is not found twice
+            t_min = distance.next_up();
+            last = Option::Some(record.clone());
+
+            // Grazing hits may repeat a side, those do not change whether the line is inside
+            match entry.take() {
+                Some(start) if !record.get_is_front_face() => {
+                    intervals.push((start, record.clone()));
+                }
+                Some(start) => entry = Option::Some(start),
+                None if record.get_is_front_face() => entry = Option::Some(record.clone()),
+                None => {}
+            }
+        }
+
+        // Exit lost to rounding, the last hit closes the interval, at worst the entry itself
+        if let Some(start) = entry {
+            let mut end = last.unwrap_or_else(|| start.clone());
+
+            end.set_is_front_face(false);
+            intervals.push((start, end));
+        }
+
+        return Option::Some(intervals);
+    }
+}
+
+impl<T: Hittable + ?Sized> Hittable for Box<T> {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        return self.as_ref().hit(ray, t_min, t_max, record);
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        return self.as_ref().bounding_box();
+    }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        return self.as_ref().sample(origin, u);
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return self.as_ref().pdf(origin, direction);
+    }
+
+    fn is_closed(&self) -> bool {
+        return self.as_ref().is_closed();
+    }
+
+    fn intervals(&self, ray: &Ray) -> Option<Vec<(HitRecord, HitRecord)>> {
+        return self.as_ref().intervals(ray);
+    }
 }
@@ -13,6 +13,20 @@ impl HittableList {
     }
 }
 
+impl HittableList {
+    pub fn len(&self) -> usize {
+        return self.hittables.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.hittables.is_empty();
+    }
+
+    pub fn into_hittables(self) -> Vec<Box<dyn Hittable>> {
+        return self.hittables;
+    }
+}
+
 impl Hittable for HittableList {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
         let mut temp_rec: HitRecord = HitRecord::default();
@@ -35,6 +49,17 @@ impl Hittable for HittableList {
     }
 
     fn bounding_box(&self) -> Option<Aabb> {
-        todo!();
+        let mut result: Option<Aabb> = Option::None;
+
+        for object in &self.hittables {
+            let bounding_box = object.bounding_box()?;
+
+            result = match result {
+                Some(aabb) => Option::Some(aabb.surrounding(bounding_box)),
+                None => Option::Some(bounding_box),
+            };
+        }
+
+        return result;
     }
 }
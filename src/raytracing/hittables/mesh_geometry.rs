@@ -0,0 +1,283 @@
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::bvh::flat_bvh_tree::FlatBvhTree;
+use crate::misc::ray::Ray;
+
+const DETERMINANT_EPSILON: f32 = 1e-8;
+
+/// Triangle geometry with shared vertex buffers and its own hierarchy.
+/// Contains no materials, so it can be shared between threads and meshes.
+pub struct MeshGeometry {
+    positions: Vec<Vec3>,
+    normals: Option<Vec<Vec3>>,
+    uvs: Option<Vec<Vec2>>,
+    triangles: Vec<[u32; 3]>,
+    tree: FlatBvhTree,
+    area_cdf: Vec<f32>,
+}
+
+impl MeshGeometry {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `positions`: Vertex positions
+    /// * `normals`: Per-vertex normals, same length as `positions`
+    /// * `uvs`: Per-vertex texture coordinates, same length as `positions`
+    /// * `triangles`: Vertex indices of every triangle
+    ///
+    /// returns: String with text what went wrong or geometry otherwise
+    pub fn new(
+        positions: Vec<Vec3>,
+        normals: Option<Vec<Vec3>>,
+        uvs: Option<Vec<Vec2>>,
+        triangles: Vec<[u32; 3]>,
+    ) -> Result<Self, String> {
+        if let Some(normals) = &normals {
+            if normals.len() != positions.len() {
+                return Result::Err(format!(
+                    "Normal count does not match position count: {} != {}",
+                    normals.len(),
+                    positions.len()
+                ));
+            }
+        }
+
+        if let Some(uvs) = &uvs {
+            if uvs.len() != positions.len() {
+                return Result::Err(format!(
+                    "UV count does not match position count: {} != {}",
+                    uvs.len(),
+                    positions.len()
+                ));
+            }
+        }
+
+        for (i, triangle) in triangles.iter().enumerate() {
+            for index in triangle {
+                if *index as usize >= positions.len() {
+                    return Result::Err(format!(
+                        "Triangle {} refers to vertex {} out of {}",
+                        i,
+                        index,
+                        positions.len()
+                    ));
+                }
+            }
+        }
+
+        let bounding_boxes: Vec<Aabb> = triangles
+            .iter()
+            .map(|triangle| {
+                let a = positions[triangle[0] as usize];
+                let b = positions[triangle[1] as usize];
+                let c = positions[triangle[2] as usize];
+
+                Aabb::new(a.inf(&b).inf(&c), a.sup(&b).sup(&c))
+            })
+            .collect();
+        let (tree, order) = FlatBvhTree::new(&bounding_boxes);
+        let triangles: Vec<[u32; 3]> = order.iter().map(|index| triangles[*index]).collect();
+        let area_cdf = triangles
+            .iter()
+            .scan(0.0, |total, [a, b, c]| {
+                let a = positions[*a as usize];
+                let b = positions[*b as usize];
+                let c = positions[*c as usize];
+
+                *total += 0.5 * (b - a).cross(&(c - a)).magnitude();
+
+                Option::Some(*total)
+            })
+            .collect();
+
+        return Result::Ok(Self {
+            positions,
+            normals,
+            uvs,
+            triangles,
+            tree,
+            area_cdf,
+        });
+    }
+}
+
+impl MeshGeometry {
+    pub fn get_positions(&self) -> &[Vec3] {
+        return &self.positions;
+    }
+
+    pub fn get_normals(&self) -> Option<&[Vec3]> {
+        return self.normals.as_deref();
+    }
+
+    pub fn get_uvs(&self) -> Option<&[Vec2]> {
+        return self.uvs.as_deref();
+    }
+
+    /// Triangles in hierarchy order, which may differ from the order they were given in
+    pub fn get_triangles(&self) -> &[[u32; 3]] {
+        return &self.triangles;
+    }
+
+    pub fn get_tree(&self) -> &FlatBvhTree {
+        return &self.tree;
+    }
+
+    /// Total surface area of all triangles
+    pub fn get_area(&self) -> f32 {
+        return self.area_cdf.last().copied().unwrap_or(0.0);
+    }
+
+    pub fn get_vertices(&self, triangle: usize) -> [Vec3; 3] {
+        let [a, b, c] = self.triangles[triangle];
+
+        return [
+            self.positions[a as usize],
+            self.positions[b as usize],
+            self.positions[c as usize],
+        ];
+    }
+
+    /// Unit normal of the triangle plane, following counter-clockwise winding
+    pub fn get_geometric_normal(&self, triangle: usize) -> Vec3 {
+        let [a, b, c] = self.get_vertices(triangle);
+
+        return (b - a).cross(&(c - a)).normalize();
+    }
+
+    /// Interpolated vertex normal or geometric normal if there are no vertex normals
+    pub fn get_shading_normal(&self, triangle: usize, u: f32, v: f32) -> Vec3 {
+        return match &self.normals {
+            Some(normals) => {
+                let [a, b, c] = self.triangles[triangle];
+
+                ((1.0 - u - v) * normals[a as usize]
+                    + u * normals[b as usize]
+                    + v * normals[c as usize])
+                    .normalize()
+            }
+            None => self.get_geometric_normal(triangle),
+        };
+    }
+
+    /// Interpolated texture coordinates or barycentric coordinates if there are no UVs
+    pub fn get_uv(&self, triangle: usize, u: f32, v: f32) -> Vec2 {
+        return match &self.uvs {
+            Some(uvs) => {
+                let [a, b, c] = self.triangles[triangle];
+
+                (1.0 - u - v) * uvs[a as usize] + u * uvs[b as usize] + v * uvs[c as usize]
+            }
+            None => Vec2::new(u, v),
+        };
+    }
+
+    /// Möller-Trumbore ray-triangle intersection
+    ///
+    /// # Arguments
+    ///
+    /// * `triangle`: Triangle position in `get_triangles`
+    /// * `ray`:
+    /// * `t_min`:
+    /// * `t_max`:
+    ///
+    /// returns: Option<(f32, f32, f32)> Distance and barycentric coordinates u, v of the hit
+    pub fn intersect(
+        &self,
+        triangle: usize,
+        ray: &Ray,
+        t_min: f32,
+        t_max: f32,
+    ) -> Option<(f32, f32, f32)> {
+        let [a, b, c] = self.get_vertices(triangle);
+        let edge_one = b - a;
+        let edge_two = c - a;
+        let p = ray.get_direction().cross(&edge_two);
+        let determinant = edge_one.dot(&p);
+
+        if determinant.abs() < DETERMINANT_EPSILON {
+            return Option::None;
+        }
+
+        let inv_determinant = 1.0 / determinant;
+        let to_origin = ray.get_origin() - a;
+        let u = to_origin.dot(&p) * inv_determinant;
+
+        if !(0.0..=1.0).contains(&u) {
+            return Option::None;
+        }
+
+        let q = to_origin.cross(&edge_one);
+        let v = ray.get_direction().dot(&q) * inv_determinant;
+
+        if v < 0.0 || u + v > 1.0 {
+            return Option::None;
+        }
+
+        let t = edge_two.dot(&q) * inv_determinant;
+
+        if t < t_min || t > t_max {
+            return Option::None;
+        }
+
+        return Option::Some((t, u, v));
+    }
+
+    /// Closest triangle hit by `ray`, found through the hierarchy
+    ///
+    /// returns: Option<(usize, f32, f32, f32)> Triangle, distance and barycentric coordinates u, v
+    pub fn closest_hit(&self, ray: &Ray, t_min: f32, t_max: f32) -> Option<(usize, f32, f32, f32)> {
+        let mut closest = Option::None;
+
+        self.tree
+            .hit(ray, t_min, t_max, |triangle, closest_so_far| {
+                let (t, u, v) = self.intersect(triangle, ray, t_min, closest_so_far)?;
+
+                closest = Option::Some((triangle, t, u, v));
+
+                Option::Some(t)
+            });
+
+        return closest;
+    }
+
+    /// Picks a point uniformly by area over all triangles
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: Two uniform random numbers in [0, 1)
+    ///
+    /// returns: Option<(Vec3, Vec3)> Point and geometric normal, None if the mesh has no area
+    pub fn sample_point(&self, u: &Vec2) -> Option<(Vec3, Vec3)> {
+        let area = self.get_area();
+
+        if area <= 0.0 {
+            return Option::None;
+        }
+
+        let target = u.x * area;
+        let triangle = self
+            .area_cdf
+            .partition_point(|x| *x <= target)
+            .min(self.triangles.len() - 1);
+        let start = if triangle > 0 {
+            self.area_cdf[triangle - 1]
+        } else {
+            0.0
+        };
+        let triangle_area = self.area_cdf[triangle] - start;
+        // Reuse the part of `u.x` left after choosing the triangle
+        let remapped = ((target - start) / triangle_area).clamp(0.0, 1.0);
+        let sqrt_u = remapped.sqrt();
+        let b1 = 1.0 - sqrt_u;
+        let b2 = u.y * sqrt_u;
+        let [a, b, c] = self.get_vertices(triangle);
+
+        return Option::Some((
+            (1.0 - b1 - b2) * a + b1 * b + b2 * c,
+            self.get_geometric_normal(triangle),
+        ));
+    }
+}
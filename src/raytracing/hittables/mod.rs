@@ -1,9 +1,21 @@
 pub mod bounding;
 pub mod bvh;
+pub mod capsule;
+pub mod cone;
+pub mod csg;
+pub mod csg_operation;
+pub mod cuboid;
+pub mod cylinder;
+pub mod disk;
 pub mod hittable;
 pub mod hittable_list;
+pub mod mesh_geometry;
+pub mod quad;
 pub mod sphere;
+pub mod torus;
+pub mod transformed;
 pub mod triangle;
+pub mod triangle_mesh;
 pub mod xy_rect;
 pub mod xz_rect;
 pub mod yz_rect;
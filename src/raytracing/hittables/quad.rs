@@ -0,0 +1,125 @@
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+use crate::misc::surface_sample::{area_pdf, SurfaceSample};
+
+const PARALLEL_EPSILON: f32 = 1e-8;
+
+/// Parallelogram spanned by two edges from one corner, facing along `edge_one × edge_two`
+pub struct Quad {
+    origin: Vec3,
+    edge_one: Vec3,
+    edge_two: Vec3,
+    normal: Vec3,
+    // Normal divided by the doubled area, turns cross products with the edges into
+    // coordinates along them
+    planar: Vec3,
+    area: f32,
+    material: MaterialRc,
+}
+
+impl Quad {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Corner both edges start from, has UV of (0, 0)
+    /// * `edge_one`: Edge along which u grows
+    /// * `edge_two`: Edge along which v grows, must not be parallel to `edge_one`
+    /// * `material`:
+    pub fn new(origin: Vec3, edge_one: Vec3, edge_two: Vec3, material: MaterialRc) -> Self {
+        let cross = edge_one.cross(&edge_two);
+        let area = cross.magnitude();
+
+        return Self {
+            origin,
+            edge_one,
+            edge_two,
+            normal: cross / area,
+            planar: cross / cross.magnitude_squared(),
+            area,
+            material,
+        };
+    }
+
+    pub fn boxed(origin: Vec3, edge_one: Vec3, edge_two: Vec3, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(origin, edge_one, edge_two, material));
+    }
+}
+
+impl Quad {
+    pub fn get_area(&self) -> f32 {
+        return self.area;
+    }
+
+    pub fn get_normal(&self) -> &Vec3 {
+        return &self.normal;
+    }
+
+    /// Point with coordinates `uv` along the edges, each from 0 to 1
+    pub fn get_point(&self, uv: &Vec2) -> Vec3 {
+        return self.origin + self.edge_one * uv.x + self.edge_two * uv.y;
+    }
+}
+
+impl Hittable for Quad {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let denominator = self.normal.dot(ray.get_direction());
+
+        if denominator.abs() < PARALLEL_EPSILON {
+            return false;
+        }
+
+        let t = self.normal.dot(&(self.origin - ray.get_origin())) / denominator;
+
+        if t < t_min || t > t_max {
+            return false;
+        }
+
+        let point = ray.get_at(t);
+        let to_point = point - self.origin;
+        let u = self.planar.dot(&to_point.cross(&self.edge_two));
+        let v = self.planar.dot(&self.edge_one.cross(&to_point));
+
+        if !(0.0..=1.0).contains(&u) || !(0.0..=1.0).contains(&v) {
+            return false;
+        }
+
+        record.set_face_normal(ray, self.normal);
+
+        record.set_distance(t);
+        record.set_material(self.material.clone());
+        record.set_point(point);
+        record.set_uv(Vec2::new(u, v));
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let far = self.origin + self.edge_one + self.edge_two;
+        let minimum = self
+            .origin
+            .inf(&far)
+            .inf(&(self.origin + self.edge_one))
+            .inf(&(self.origin + self.edge_two));
+        let maximum = self
+            .origin
+            .sup(&far)
+            .sup(&(self.origin + self.edge_one))
+            .sup(&(self.origin + self.edge_two));
+
+        return Option::Some(Aabb::new(minimum, maximum));
+    }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        return SurfaceSample::from_area(origin, self.get_point(u), self.normal, self.area);
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return area_pdf(self, self.area, origin, direction);
+    }
+}
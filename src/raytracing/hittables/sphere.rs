@@ -1,12 +1,15 @@
+use std::f32::consts::PI;
 use std::ops::Div;
 
-use nalgebra_glm::Vec3;
+use nalgebra_glm::{Vec2, Vec3};
 
 use crate::hittables::bounding::aabb::Aabb;
 use crate::hittables::hittable::Hittable;
 use crate::materials::material::MaterialRc;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
+use crate::misc::surface_sample::{area_pdf, SurfaceSample};
+use crate::misc::utils::orthonormal_basis;
 
 pub struct Sphere {
     center: Vec3,
@@ -28,6 +31,34 @@ impl Sphere {
     }
 }
 
+impl Sphere {
+    pub fn get_area(&self) -> f32 {
+        return 4.0 * PI * self.radius * self.radius;
+    }
+
+    /// Longitude around z axis and latitude from the bottom pole, both in [0, 1]
+    fn get_uv(outward_normal: &Vec3) -> Vec2 {
+        let phi = outward_normal.y.atan2(outward_normal.x);
+        let theta = outward_normal.z.clamp(-1.0, 1.0).acos();
+
+        return Vec2::new((phi / (2.0 * PI)).rem_euclid(1.0), 1.0 - theta / PI);
+    }
+
+    /// Cosine of the half angle of the cone the sphere occupies when seen from `origin`
+    ///
+    /// returns: None if `origin` is inside the sphere
+    fn cone_cos_theta_max(&self, origin: &Vec3) -> Option<f32> {
+        let distance_squared = (self.center - origin).magnitude_squared();
+        let radius_squared = self.radius * self.radius;
+
+        if distance_squared <= radius_squared {
+            return Option::None;
+        }
+
+        return Option::Some((1.0 - radius_squared / distance_squared).max(0.0).sqrt());
+    }
+}
+
 impl Hittable for Sphere {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
         let oc: Vec3 = ray.get_origin() - self.center;
@@ -53,19 +84,14 @@ impl Hittable for Sphere {
                 return false;
             }
         }
-        let normal = (record.get_point() - self.center).div(self.radius);
-        let is_front = normal.dot(record.get_normal()) > 0.0;
-        let normal = if is_front {
-            -normal
-        } else {
-            normal
-        };
 
         record.set_distance(root);
-        record.set_point(ray.get_at(record.get_distance()));
-        record.set_normal(normal);
+        record.set_point(ray.get_at(root));
+        let outward_normal = (record.get_point() - self.center).div(self.radius);
+
+        record.set_face_normal(ray, outward_normal);
+        record.set_uv(Self::get_uv(&outward_normal));
         record.set_material(self.material.clone());
-        record.set_is_front_face(is_front);
 
         return true;
     }
@@ -76,4 +102,73 @@ impl Hittable for Sphere {
             self.center.add_scalar(self.radius),
         ));
     }
+
+    /// Samples the visible cone from outside and the whole surface from inside
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let cos_theta_max = match self.cone_cos_theta_max(origin) {
+            Some(cos_theta_max) => cos_theta_max,
+            None => {
+                let z = 1.0 - 2.0 * u.y;
+                let r = (1.0 - z * z).max(0.0).sqrt();
+                let phi = 2.0 * PI * u.x;
+                let normal = Vec3::new(r * phi.cos(), r * phi.sin(), z);
+
+                return SurfaceSample::from_area(
+                    origin,
+                    self.center + normal * self.radius,
+                    normal,
+                    self.get_area(),
+                );
+            }
+        };
+
+        let axis = (self.center - origin).normalize();
+        let (tangent, bitangent) = orthonormal_basis(&axis);
+        let cos_theta = 1.0 + u.y * (cos_theta_max - 1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let phi = 2.0 * PI * u.x;
+        let direction =
+            (tangent * phi.cos() + bitangent * phi.sin()) * sin_theta + axis * cos_theta;
+
+        let mut record = HitRecord::default();
+
+        if !self.hit(
+            &Ray::new(*origin, direction),
+            0.0,
+            f32::INFINITY,
+            &mut record,
+        ) {
+            return Option::None;
+        }
+
+        return Option::Some(SurfaceSample::new(
+            *record.get_point(),
+            (record.get_point() - self.center).div(self.radius),
+            1.0 / (2.0 * PI * (1.0 - cos_theta_max)),
+        ));
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return match self.cone_cos_theta_max(origin) {
+            Some(cos_theta_max) => {
+                let mut record = HitRecord::default();
+
+                if !self.hit(
+                    &Ray::new(*origin, *direction),
+                    0.0,
+                    f32::INFINITY,
+                    &mut record,
+                ) {
+                    return 0.0;
+                }
+
+                1.0 / (2.0 * PI * (1.0 - cos_theta_max))
+            }
+            None => area_pdf(self, self.get_area(), origin, direction),
+        };
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
 }
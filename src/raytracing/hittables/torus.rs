@@ -0,0 +1,150 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::polynomial::solve_quadratic;
+use crate::misc::polynomial::solve_quartic;
+use crate::misc::ray::Ray;
+
+/// Ring of circular cross-section around an axis.
+/// Works in its own space where the axis is z and the center is at the origin
+pub struct Torus {
+    center: Vec3,
+    major_radius: f32,
+    minor_radius: f32,
+    frame: Frame,
+    material: MaterialRc,
+}
+
+impl Torus {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `center`:
+    /// * `axis`: Direction the ring goes around, normalized here
+    /// * `major_radius`: From the center to the middle of the tube
+    /// * `minor_radius`: Of the tube, smaller than `major_radius`
+    /// * `material`:
+    pub fn new(
+        center: Vec3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+        material: MaterialRc,
+    ) -> Self {
+        return Self {
+            center,
+            major_radius,
+            minor_radius,
+            frame: Frame::new(&axis.normalize()),
+            material,
+        };
+    }
+
+    pub fn boxed(
+        center: Vec3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+        material: MaterialRc,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+            material,
+        ));
+    }
+}
+
+impl Torus {
+    /// Closest hit distance in own space
+    fn intersect(&self, origin: &Vec3, direction: &Vec3, t_min: f32, t_max: f32) -> Option<f32> {
+        let outer_radius = self.major_radius + self.minor_radius;
+
+        // Rays missing the bounding sphere can skip the quartic
+        solve_quadratic(
+            direction.magnitude_squared(),
+            2.0 * origin.dot(direction),
+            origin.magnitude_squared() - outer_radius * outer_radius,
+        )?;
+
+        // Solved in double precision along a unit direction from the point closest to
+        // the center, which keeps the coefficients small
+        let length = direction.magnitude() as f64;
+        let unit = direction.map(|x| x as f64) / length;
+        let shift = -origin.map(|x| x as f64).dot(&unit);
+        let start = origin.map(|x| x as f64) + unit * shift;
+        let major2 = (self.major_radius as f64).powi(2);
+        let k = start.dot(&unit);
+        let q = start.magnitude_squared() + major2 - (self.minor_radius as f64).powi(2);
+        let planar_direction = unit.x * unit.x + unit.y * unit.y;
+        let planar_dot = start.x * unit.x + start.y * unit.y;
+        let planar_start = start.x * start.x + start.y * start.y;
+        let (roots, count) = solve_quartic(
+            4.0 * k,
+            4.0 * k * k + 2.0 * q - 4.0 * major2 * planar_direction,
+            4.0 * k * q - 8.0 * major2 * planar_dot,
+            q * q - 4.0 * major2 * planar_start,
+        );
+
+        return roots[..count]
+            .iter()
+            .map(|x| ((x + shift) / length) as f32)
+            .filter(|t| *t >= t_min && *t <= t_max)
+            .reduce(f32::min);
+    }
+}
+
+impl Hittable for Torus {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let origin = self.frame.to_local(&(ray.get_origin() - self.center));
+        let direction = self.frame.to_local(ray.get_direction());
+        let t = match self.intersect(&origin, &direction, t_min, t_max) {
+            Some(t) => t,
+            None => return false,
+        };
+
+        let point = origin + direction * t;
+        let planar_distance = (point.x * point.x + point.y * point.y).sqrt();
+        // Middle of the tube closest to the point
+        let tube_center = if planar_distance > 0.0 {
+            Vec3::new(point.x, point.y, 0.0) * (self.major_radius / planar_distance)
+        } else {
+            Vec3::new(self.major_radius, 0.0, 0.0)
+        };
+        let normal = (point - tube_center).normalize();
+
+        record.set_distance(t);
+        record.set_point(ray.get_at(t));
+        record.set_face_normal(ray, self.frame.to_world(&normal));
+        record.set_uv(Vec2::new(
+            (point.y.atan2(point.x) / (2.0 * PI)).rem_euclid(1.0),
+            (point.z.atan2(planar_distance - self.major_radius) / (2.0 * PI)).rem_euclid(1.0),
+        ));
+        record.set_material(self.material.clone());
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        // Ring of the tube middle along every axis, widened by the tube
+        let extent = self
+            .frame
+            .get_normal()
+            .map(|x| self.major_radius * (1.0 - x * x).max(0.0).sqrt() + self.minor_radius);
+
+        return Option::Some(Aabb::new(self.center - extent, self.center + extent));
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+}
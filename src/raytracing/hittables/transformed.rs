@@ -0,0 +1,247 @@
+use nalgebra_glm::{mat4_to_mat3, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+use crate::misc::surface_sample::SurfaceSample;
+
+const MIN_DETERMINANT: f32 = 1e-12;
+const MIN_COSINE: f32 = 1e-6;
+const PDF_T_MIN: f32 = 0.0001;
+
+/// Hittable moved, rotated or scaled by an affine transform of its own coordinates.
+/// Shared geometry wrapped directly, e.g. `Transformed<TriangleMesh>`, makes a cheap instance
+pub struct Transformed<T: Hittable = Box<dyn Hittable>> {
+    hittable: T,
+    matrix: Mat4,
+    inverse: Mat4,
+    // Inverse transpose of the linear part, keeps normals perpendicular to the surface
+    normal_matrix: Mat3,
+    determinant: f32,
+}
+
+impl<T: Hittable> Transformed<T> {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `hittable`: Hittable in its own coordinates
+    /// * `matrix`: From its own coordinates to the world ones, must pass `validate_transform`
+    pub fn new(hittable: T, matrix: Mat4) -> Self {
+        let linear = mat4_to_mat3(&matrix);
+
+        return Self {
+            hittable,
+            matrix,
+            inverse: matrix.try_inverse().expect("Transform must be invertible"),
+            normal_matrix: linear
+                .try_inverse()
+                .expect("Transform must be invertible")
+                .transpose(),
+            determinant: linear.determinant(),
+        };
+    }
+
+    pub fn boxed(hittable: T, matrix: Mat4) -> Box<Self> {
+        return Box::new(Self::new(hittable, matrix));
+    }
+}
+
+impl<T: Hittable> Transformed<T> {
+    pub fn get_hittable(&self) -> &T {
+        return &self.hittable;
+    }
+
+    pub fn get_matrix(&self) -> &Mat4 {
+        return &self.matrix;
+    }
+
+    fn transform_point(matrix: &Mat4, point: &Vec3) -> Vec3 {
+        return (matrix * Vec4::new(point.x, point.y, point.z, 1.0)).xyz();
+    }
+
+    fn transform_vector(matrix: &Mat4, vector: &Vec3) -> Vec3 {
+        return (matrix * Vec4::new(vector.x, vector.y, vector.z, 0.0)).xyz();
+    }
+
+    /// Ray in own coordinates, its direction is not normalized so that distances along it
+    /// stay the same as in the world
+    fn to_local_ray(&self, ray: &Ray) -> Ray {
+        return Ray::no_norm(
+            Self::transform_point(&self.inverse, ray.get_origin()),
+            Self::transform_vector(&self.inverse, ray.get_direction()),
+        );
+    }
+
+    /// Moves a hit in own coordinates to the world, distances along the ray stay the same
+    fn to_world_record(&self, ray: &Ray, record: &mut HitRecord) {
+        // Which side was hit does not change, the normal keeps facing against the ray
+        record.set_point(ray.get_at(record.get_distance()));
+        record.set_normal((self.normal_matrix * record.get_normal()).normalize());
+    }
+
+    /// Converts solid angle density of a point seen from `origin` from own coordinates to
+    /// the world ones
+    ///
+    /// # Arguments
+    ///
+    /// * `pdf`: Density in own coordinates
+    /// * `origin`: Point the surface is seen from, in the world
+    /// * `local_point`: Point on the surface in own coordinates
+    /// * `local_normal`: Unit surface normal at `local_point` in own coordinates
+    ///
+    /// returns: f32 Zero if the surface is seen edge-on
+    fn to_world_pdf(
+        &self,
+        pdf: f32,
+        origin: &Vec3,
+        local_point: &Vec3,
+        local_normal: &Vec3,
+    ) -> f32 {
+        let local_origin = Self::transform_point(&self.inverse, origin);
+        let to_local_point = local_point - local_origin;
+        let local_distance = to_local_point.magnitude();
+        let to_point = Self::transform_point(&self.matrix, local_point) - origin;
+        let distance = to_point.magnitude();
+
+        if distance <= 0.0 || local_distance <= 0.0 {
+            return 0.0;
+        }
+
+        let normal = self.normal_matrix * local_normal;
+        // Area of the surface grows by the determinant, but only across the normal
+        let area_scale = self.determinant.abs() * normal.magnitude();
+        let cosine = to_point.dot(&normal).abs() / (distance * normal.magnitude());
+
+        if cosine < MIN_COSINE {
+            return 0.0;
+        }
+
+        let local_cosine = to_local_point.dot(local_normal).abs() / local_distance;
+        let area_pdf = pdf * local_cosine / (local_distance * local_distance);
+
+        return area_pdf / area_scale * distance * distance / cosine;
+    }
+}
+
+impl<T: Hittable> Hittable for Transformed<T> {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        if !self
+            .hittable
+            .hit(&self.to_local_ray(ray), t_min, t_max, record)
+        {
+            return false;
+        }
+
+        self.to_world_record(ray, record);
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let aabb = self.hittable.bounding_box()?;
+        let corners = [aabb.get_minimum(), aabb.get_maximum()];
+        let mut minimum = Vec3::from_element(f32::INFINITY);
+        let mut maximum = Vec3::from_element(f32::NEG_INFINITY);
+
+        for i in 0..8 {
+            let corner = Vec3::new(
+                corners[i & 1].x,
+                corners[(i >> 1) & 1].y,
+                corners[(i >> 2) & 1].z,
+            );
+            let point = Self::transform_point(&self.matrix, &corner);
+
+            minimum = minimum.inf(&point);
+            maximum = maximum.sup(&point);
+        }
+
+        return Option::Some(Aabb::new(minimum, maximum));
+    }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let local_origin = Self::transform_point(&self.inverse, origin);
+        let sample = self.hittable.sample(&local_origin, u)?;
+        let pdf = self.to_world_pdf(
+            sample.get_pdf(),
+            origin,
+            sample.get_point(),
+            sample.get_normal(),
+        );
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(SurfaceSample::new(
+            Self::transform_point(&self.matrix, sample.get_point()),
+            (self.normal_matrix * sample.get_normal()).normalize(),
+            pdf,
+        ));
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        let local_ray = Ray::new(
+            Self::transform_point(&self.inverse, origin),
+            Self::transform_vector(&self.inverse, direction),
+        );
+        let pdf = self
+            .hittable
+            .pdf(local_ray.get_origin(), local_ray.get_direction());
+
+        if pdf <= 0.0 {
+            return 0.0;
+        }
+
+        let mut record = HitRecord::default();
+
+        if !self
+            .hittable
+            .hit(&local_ray, PDF_T_MIN, f32::INFINITY, &mut record)
+        {
+            return 0.0;
+        }
+
+        return self.to_world_pdf(pdf, origin, record.get_point(), record.get_normal());
+    }
+
+    fn is_closed(&self) -> bool {
+        return self.hittable.is_closed();
+    }
+
+    fn intervals(&self, ray: &Ray) -> Option<Vec<(HitRecord, HitRecord)>> {
+        let mut intervals = self.hittable.intervals(&self.to_local_ray(ray))?;
+
+        for (entry, exit) in intervals.iter_mut() {
+            self.to_world_record(ray, entry);
+            self.to_world_record(ray, exit);
+        }
+
+        return Option::Some(intervals);
+    }
+}
+
+/// Checks `matrix` can be used to transform a hittable
+///
+/// returns: String with the reason it can not or nothing otherwise
+pub fn validate_transform(matrix: &Mat4) -> Result<(), String> {
+    if matrix.iter().any(|x| !x.is_finite()) {
+        return Result::Err("transform must be finite".to_string());
+    }
+
+    if matrix.row(3).transpose() != Vec4::new(0.0, 0.0, 0.0, 1.0) {
+        return Result::Err("transform must be affine, last row must be 0,0,0,1".to_string());
+    }
+
+    let determinant = mat4_to_mat3(matrix).determinant();
+
+    if determinant.abs() < MIN_DETERMINANT {
+        return Result::Err(format!(
+            "transform must be invertible, determinant is {}",
+            determinant
+        ));
+    }
+
+    return Result::Ok(());
+}
@@ -1,10 +1,11 @@
-use nalgebra_glm::Vec3;
+use nalgebra_glm::{Vec2, Vec3};
 
 use crate::hittables::bounding::aabb::Aabb;
 use crate::hittables::hittable::Hittable;
 use crate::materials::material::MaterialRc;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
+use crate::misc::surface_sample::{area_pdf, SurfaceSample};
 
 pub struct Triangle {
     point_one: Vec3,
@@ -33,6 +34,19 @@ impl Triangle {
     }
 }
 
+impl Triangle {
+    pub fn get_area(&self) -> f32 {
+        return 0.5 * self.get_cross().magnitude();
+    }
+
+    fn get_cross(&self) -> Vec3 {
+        return Vec3::cross(
+            &(self.point_two - self.point_one),
+            &(self.point_three - self.point_one),
+        );
+    }
+}
+
 impl Hittable for Triangle {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
         let v0 = self.point_one;
@@ -60,9 +74,10 @@ impl Hittable for Triangle {
             return false;
         }
 
-        record.set_normal(n);
         record.set_distance(t);
         record.set_point(ray.get_at(t));
+        record.set_face_normal(ray, n.normalize());
+        record.set_uv(Vec2::new(u, v));
         record.set_material(self.material.clone());
 
         return true;
@@ -100,4 +115,18 @@ impl Hittable for Triangle {
             ),
         ));
     }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let cross = self.get_cross();
+        let sqrt_u = u.x.sqrt();
+        let b1 = 1.0 - sqrt_u;
+        let b2 = u.y * sqrt_u;
+        let point = (1.0 - b1 - b2) * self.point_one + b1 * self.point_two + b2 * self.point_three;
+
+        return SurfaceSample::from_area(origin, point, cross.normalize(), 0.5 * cross.magnitude());
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return area_pdf(self, self.get_area(), origin, direction);
+    }
 }
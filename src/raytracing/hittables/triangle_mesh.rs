@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::bounding::aabb::Aabb;
+use crate::hittables::hittable::Hittable;
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::materials::material::MaterialRc;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+use crate::misc::surface_sample::{area_to_solid_angle, SurfaceSample};
+
+const PDF_T_MIN: f32 = 0.0001;
+
+pub struct TriangleMesh {
+    geometry: Arc<MeshGeometry>,
+    material: MaterialRc,
+}
+
+impl TriangleMesh {
+    pub fn new(geometry: Arc<MeshGeometry>, material: MaterialRc) -> Self {
+        return Self { geometry, material };
+    }
+
+    pub fn boxed(geometry: Arc<MeshGeometry>, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(geometry, material));
+    }
+}
+
+impl TriangleMesh {
+    pub fn get_geometry(&self) -> &Arc<MeshGeometry> {
+        return &self.geometry;
+    }
+}
+
+impl Hittable for TriangleMesh {
+    fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
+        let geometry = self.geometry.as_ref();
+        let (triangle, t, u, v) = match geometry.closest_hit(ray, t_min, t_max) {
+            Some(closest) => closest,
+            None => return false,
+        };
+
+        record.set_distance(t);
+        record.set_point(ray.get_at(t));
+        record.set_face_normal(ray, geometry.get_shading_normal(triangle, u, v));
+        record.set_uv(geometry.get_uv(triangle, u, v));
+        record.set_material(self.material.clone());
+
+        return true;
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        return self.geometry.get_tree().bounding_box();
+    }
+
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        let (point, normal) = self.geometry.sample_point(u)?;
+
+        return SurfaceSample::from_area(origin, point, normal, self.geometry.get_area());
+    }
+
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        let ray = Ray::new(*origin, *direction);
+
+        return match self.geometry.closest_hit(&ray, PDF_T_MIN, f32::INFINITY) {
+            Some((triangle, t, _, _)) => area_to_solid_angle(
+                t,
+                direction.dot(&self.geometry.get_geometric_normal(triangle)),
+                self.geometry.get_area(),
+            ),
+            None => 0.0,
+        };
+    }
+}
@@ -2,15 +2,15 @@ use nalgebra_glm::{Vec2, Vec3};
 
 use crate::hittables::bounding::aabb::Aabb;
 use crate::hittables::hittable::Hittable;
+use crate::hittables::quad::Quad;
 use crate::materials::material::MaterialRc;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
+use crate::misc::surface_sample::SurfaceSample;
 
+/// Rectangle parallel to the xy plane facing +z, UV grows along x and y
 pub struct XyRect {
-    point_one: Vec2,
-    point_two: Vec2,
-    normal_displacement: f32,
-    material: MaterialRc,
+    quad: Quad,
 }
 
 impl XyRect {
@@ -20,11 +20,15 @@ impl XyRect {
         normal_displacement: f32,
         material: MaterialRc,
     ) -> Self {
+        let size = point_two - point_one;
+
         return XyRect {
-            point_one,
-            point_two,
-            normal_displacement,
-            material,
+            quad: Quad::new(
+                Vec3::new(point_one.x, point_one.y, normal_displacement),
+                Vec3::new(size.x, 0.0, 0.0),
+                Vec3::new(0.0, size.y, 0.0),
+                material,
+            ),
         };
     }
 
@@ -34,12 +38,12 @@ impl XyRect {
         normal_displacement: f32,
         material: MaterialRc,
     ) -> Box<Self> {
-        return Box::new(XyRect {
+        return Box::new(Self::new(
             point_one,
             point_two,
             normal_displacement,
             material,
-        });
+        ));
     }
 
     pub fn square_boxed(position: Vec3, edge_length: f32, material: MaterialRc) -> Box<Self> {
@@ -51,54 +55,26 @@ impl XyRect {
     }
 }
 
+impl XyRect {
+    pub fn get_area(&self) -> f32 {
+        return self.quad.get_area();
+    }
+}
+
 impl Hittable for XyRect {
     fn hit(&self, ray: &Ray, t_min: f32, t_max: f32, record: &mut HitRecord) -> bool {
-        let t = (self.normal_displacement - ray.get_origin().z) / ray.get_direction().z;
-
-        if t < t_min || t > t_max {
-            return false;
-        }
-
-        let x = ray.get_origin().x + t * ray.get_direction().x;
-        let y = ray.get_origin().y + t * ray.get_direction().y;
-
-        if x < self.point_one.x
-            || x > self.point_two.x
-            || y < self.point_one.y
-            || y > self.point_two.y
-        {
-            return false;
-        }
-
-        const SELF_NORMAL: Vec3 = Vec3::new(0.0, 0.0, 1.0);
-
-        if ray.get_direction().dot(&SELF_NORMAL) > 0.0 {
-            record.set_normal(SELF_NORMAL);
-            record.set_is_front_face(true);
-        } else {
-            record.set_normal(-SELF_NORMAL);
-            record.set_is_front_face(false);
-        };
+        return self.quad.hit(ray, t_min, t_max, record);
+    }
 
-        record.set_distance(t);
-        record.set_material(self.material.clone());
-        record.set_point(ray.get_at(t));
+    fn bounding_box(&self) -> Option<Aabb> {
+        return self.quad.bounding_box();
+    }
 
-        return true;
+    fn sample(&self, origin: &Vec3, u: &Vec2) -> Option<SurfaceSample> {
+        return self.quad.sample(origin, u);
     }
 
-    fn bounding_box(&self) -> Option<Aabb> {
-        return Option::Some(Aabb::new(
-            Vec3::new(
-                f32::min(self.point_one.x, self.point_two.x),
-                f32::min(self.point_one.y, self.point_two.y),
-                self.normal_displacement,
-            ),
-            Vec3::new(
-                f32::max(self.point_one.x, self.point_two.x),
-                f32::max(self.point_one.y, self.point_two.y),
-                self.normal_displacement,
-            ),
-        ));
+    fn pdf(&self, origin: &Vec3, direction: &Vec3) -> f32 {
+        return self.quad.pdf(origin, direction);
     }
 }
@@ -0,0 +1,55 @@
+use nalgebra_glm::{IVec2, Vec3};
+
+/// Read-only image of linear RGB values, decoded from files and shared between threads
+pub struct LinearImage {
+    resolution: IVec2,
+    pixels: Vec<Vec3>,
+}
+
+impl LinearImage {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution`: Width and height
+    /// * `pixels`: Rows from top to bottom, each from left to right
+    ///
+    /// returns: String with text what went wrong or image otherwise
+    pub fn new(resolution: IVec2, pixels: Vec<Vec3>) -> Result<Self, String> {
+        if resolution.x <= 0 || resolution.y <= 0 {
+            return Result::Err(format!(
+                "Non-positive resolution: x: {}, y: {}",
+                resolution.x, resolution.y
+            ));
+        }
+
+        if pixels.len() != (resolution.x * resolution.y) as usize {
+            return Result::Err(format!(
+                "Pixel count does not match resolution: {} != {}x{}",
+                pixels.len(),
+                resolution.x,
+                resolution.y
+            ));
+        }
+
+        return Result::Ok(Self { resolution, pixels });
+    }
+}
+
+impl LinearImage {
+    pub fn get_resolution(&self) -> IVec2 {
+        return self.resolution;
+    }
+
+    pub fn get_pixels(&self) -> &[Vec3] {
+        return &self.pixels;
+    }
+
+    /// Pixel at `x`, `y` with coordinates clamped to the image
+    pub fn get_pixel(&self, x: i32, y: i32) -> &Vec3 {
+        let x = x.clamp(0, self.resolution.x - 1) as usize;
+        let y = y.clamp(0, self.resolution.y - 1) as usize;
+
+        return &self.pixels[y * self.resolution.x as usize + x];
+    }
+}
@@ -1,5 +1,6 @@
 pub mod buffer_converter;
 pub mod default_image_buffer;
 pub mod image_buffer;
+pub mod linear_image;
 pub mod ppm_converter;
 pub mod u8_image_buffer;
@@ -0,0 +1,280 @@
+use std::fs;
+
+use nalgebra_glm::{IVec2, Vec3};
+
+use crate::image::linear_image::LinearImage;
+
+const MIN_RLE_WIDTH: usize = 8;
+const MAX_RLE_WIDTH: usize = 0x7fff;
+// Longest run covers 127 pixels with 2 bytes in each of 4 channels, so no encoding is denser
+const MAX_PIXELS_PER_BYTE: usize = 16;
+
+/// Reads Radiance RGBE `.hdr` image
+pub fn load(path: &str) -> Result<LinearImage, String> {
+    let bytes = fs::read(path).map_err(|x| format!("Error reading file: {}. {}", path, x))?;
+
+    return decode(&bytes).map_err(|x| format!("{}: {}", path, x));
+}
+
+/// Decodes Radiance RGBE image with flat or run-length encoded scanlines.
+/// Only the usual `-Y height +X width` orientation is supported
+///
+/// # Arguments
+///
+/// * `bytes`: File contents
+///
+/// returns: String with text what went wrong or image otherwise
+pub fn decode(bytes: &[u8]) -> Result<LinearImage, String> {
+    let mut position = 0;
+    let mut format_found = false;
+    let first_line = read_line(bytes, &mut position)?;
+
+    if !first_line.starts_with("#?") {
+        return Result::Err("Not a Radiance HDR file".to_string());
+    }
+
+    loop {
+        let line = read_line(bytes, &mut position)?;
+
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some(format) = line.strip_prefix("FORMAT=") {
+            if format != "32-bit_rle_rgbe" {
+                return Result::Err(format!("Unsupported format \"{}\"", format));
+            }
+
+            format_found = true;
+        }
+    }
+
+    if !format_found {
+        return Result::Err("Missing FORMAT=32-bit_rle_rgbe".to_string());
+    }
+
+    let resolution_line = read_line(bytes, &mut position)?;
+    let (width, height) = match resolution_line.split_whitespace().collect::<Vec<_>>()[..] {
+        ["-Y", height, "+X", width] => (
+            width
+                .parse::<usize>()
+                .map_err(|x| format!("Invalid width \"{}\". {}", width, x))?,
+            height
+                .parse::<usize>()
+                .map_err(|x| format!("Invalid height \"{}\". {}", height, x))?,
+        ),
+        _ => {
+            return Result::Err(format!(
+                "Unsupported resolution line \"{}\"",
+                resolution_line
+            ))
+        }
+    };
+
+    if width == 0 || height == 0 {
+        return Result::Err(format!("Empty image: {}x{}", width, height));
+    }
+
+    let pixel_count = width
+        .checked_mul(height)
+        .ok_or_else(|| format!("Image is too large: {}x{}", width, height))?;
+
+    // Corrupt headers must not make the allocation below huge
+    if pixel_count / MAX_PIXELS_PER_BYTE > bytes.len() - position {
+        return Result::Err(format!(
+            "Pixel data is too short for {}x{} image",
+            width, height
+        ));
+    }
+
+    let mut pixels = Vec::with_capacity(pixel_count);
+    let mut scanline = vec![[0_u8; 4]; width];
+
+    for row in 0..height {
+        read_scanline(bytes, &mut position, &mut scanline)
+            .map_err(|x| format!("Scanline {}: {}", row, x))?;
+
+        pixels.extend(scanline.iter().map(rgbe_to_rgb));
+    }
+
+    return LinearImage::new(IVec2::new(width as i32, height as i32), pixels);
+}
+
+fn read_line(bytes: &[u8], position: &mut usize) -> Result<String, String> {
+    let rest = &bytes[*position..];
+    let length = rest
+        .iter()
+        .position(|x| *x == b'\n')
+        .ok_or_else(|| "Unexpected end of header".to_string())?;
+
+    *position += length + 1;
+
+    return Result::Ok(String::from_utf8_lossy(&rest[..length]).trim().to_string());
+}
+
+fn read_byte(bytes: &[u8], position: &mut usize) -> Result<u8, String> {
+    let byte = *bytes
+        .get(*position)
+        .ok_or_else(|| "Unexpected end of pixel data".to_string())?;
+
+    *position += 1;
+
+    return Result::Ok(byte);
+}
+
+fn read_scanline(
+    bytes: &[u8],
+    position: &mut usize,
+    scanline: &mut [[u8; 4]],
+) -> Result<(), String> {
+    let width = scanline.len();
+    let is_rle = (MIN_RLE_WIDTH..=MAX_RLE_WIDTH).contains(&width)
+        && bytes.get(*position) == Option::Some(&2)
+        && bytes.get(*position + 1) == Option::Some(&2)
+        && bytes.get(*position + 2).is_some_and(|x| x & 0x80 == 0);
+
+    if !is_rle {
+        for pixel in scanline.iter_mut() {
+            for channel in pixel.iter_mut() {
+                *channel = read_byte(bytes, position)?;
+            }
+        }
+
+        return Result::Ok(());
+    }
+
+    let encoded_width = match (bytes.get(*position + 2), bytes.get(*position + 3)) {
+        (Some(high), Some(low)) => ((*high as usize) << 8) | *low as usize,
+        _ => return Result::Err("Unexpected end of scanline header".to_string()),
+    };
+
+    if encoded_width != width {
+        return Result::Err(format!(
+            "Encoded width does not match image width: {} != {}",
+            encoded_width, width
+        ));
+    }
+
+    *position += 4;
+
+    // Every channel is stored separately as runs of one value or literal byte sequences
+    for channel in 0..4 {
+        let mut x = 0;
+
+        while x < width {
+            let count = read_byte(bytes, position)? as usize;
+
+            if count > 128 {
+                let count = count - 128;
+                let value = read_byte(bytes, position)?;
+
+                if x + count > width {
+                    return Result::Err("Run goes past the end of the scanline".to_string());
+                }
+
+                for pixel in &mut scanline[x..x + count] {
+                    pixel[channel] = value;
+                }
+
+                x += count;
+            } else {
+                if count == 0 || x + count > width {
+                    return Result::Err("Invalid literal run length".to_string());
+                }
+
+                for pixel in &mut scanline[x..x + count] {
+                    pixel[channel] = read_byte(bytes, position)?;
+                }
+
+                x += count;
+            }
+        }
+    }
+
+    return Result::Ok(());
+}
+
+fn rgbe_to_rgb(rgbe: &[u8; 4]) -> Vec3 {
+    if rgbe[3] == 0 {
+        return Vec3::zeros();
+    }
+
+    let scale = 2.0_f32.powi(rgbe[3] as i32 - (128 + 8));
+
+    return Vec3::new(rgbe[0] as f32, rgbe[1] as f32, rgbe[2] as f32) * scale;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(width: &str, height: &str) -> Vec<u8> {
+        return format!(
+            "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y {} +X {}\n",
+            height, width
+        )
+        .into_bytes();
+    }
+
+    /// 8x2 image of run-length encoded scanlines where every pixel is (1, 2, 4)
+    fn run_length_encoded() -> Vec<u8> {
+        let mut bytes = header("8", "2");
+
+        for _ in 0..2 {
+            bytes.extend_from_slice(&[2, 2, 0, 8]);
+
+            for value in [1, 2, 4, 136] {
+                bytes.extend_from_slice(&[128 + 8, value]);
+            }
+        }
+
+        return bytes;
+    }
+
+    #[test]
+    fn decodes_flat_scanlines() {
+        let mut bytes = header("2", "1");
+
+        bytes.extend_from_slice(&[128, 64, 0, 129, 0, 0, 0, 0]);
+
+        let image = decode(&bytes).unwrap();
+
+        assert_eq!(image.get_resolution(), IVec2::new(2, 1));
+        assert_eq!(*image.get_pixel(0, 0), Vec3::new(1.0, 0.5, 0.0));
+        assert_eq!(*image.get_pixel(1, 0), Vec3::zeros());
+    }
+
+    #[test]
+    fn decodes_run_length_encoded_scanlines() {
+        let image = decode(&run_length_encoded()).unwrap();
+
+        assert!(image
+            .get_pixels()
+            .iter()
+            .all(|x| *x == Vec3::new(1.0, 2.0, 4.0)));
+    }
+
+    #[test]
+    fn truncated_file_is_error() {
+        let bytes = run_length_encoded();
+
+        for length in 0..bytes.len() {
+            assert!(decode(&bytes[..length]).is_err(), "length {}", length);
+        }
+    }
+
+    #[test]
+    fn truncated_scanline_header_is_error() {
+        let mut bytes = header("8", "2");
+
+        bytes.extend_from_slice(&[2, 2, 0]);
+
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn oversized_header_is_error() {
+        assert!(decode(&header("18446744073709551615", "2")).is_err());
+        assert!(decode(&header("100000", "100000")).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use std::fs;
+
+use crate::image::linear_image::LinearImage;
+use crate::import::{hdr_decoder, jpeg_decoder, png_decoder};
+use crate::misc::color::srgb_to_linear;
+
+/// Reads `.png`, `.jpg` or `.hdr` image as linear colors.
+/// 8 and 16 bit formats are treated as sRGB encoded, format is recognized by file contents
+pub fn load(path: &str) -> Result<LinearImage, String> {
+    let bytes = fs::read(path).map_err(|x| format!("Error reading file: {}. {}", path, x))?;
+
+    return decode(&bytes).map_err(|x| format!("{}: {}", path, x));
+}
+
+///
+///
+/// # Arguments
+///
+/// * `bytes`: File contents
+///
+/// returns: String with text what went wrong or image otherwise
+pub fn decode(bytes: &[u8]) -> Result<LinearImage, String> {
+    if bytes.starts_with(b"#?") {
+        return hdr_decoder::decode(bytes);
+    }
+
+    let image = if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        png_decoder::decode(bytes)?
+    } else if bytes.starts_with(&[0xff, 0xd8]) {
+        jpeg_decoder::decode(bytes)?
+    } else {
+        return Result::Err("Unknown image format".to_string());
+    };
+
+    let pixels = image
+        .get_pixels()
+        .iter()
+        .map(|x| x.map(srgb_to_linear))
+        .collect();
+
+    return LinearImage::new(image.get_resolution(), pixels);
+}
@@ -0,0 +1,315 @@
+//! Zlib stream decompression (RFC 1950 and RFC 1951) for PNG image data
+
+const MAX_BITS: usize = 15;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+// Order in which code length code lengths are stored in dynamic blocks
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// Decompresses zlib stream
+///
+/// # Arguments
+///
+/// * `data`: Zlib header, deflate blocks and checksum
+///
+/// returns: String with text what went wrong or decompressed bytes otherwise
+pub fn decompress_zlib(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < 2 {
+        return Result::Err("Zlib stream is too short".to_string());
+    }
+
+    let (cmf, flags) = (data[0], data[1]);
+
+    if cmf & 0x0f != 8 || !((cmf as u16) << 8 | flags as u16).is_multiple_of(31) {
+        return Result::Err("Invalid zlib header".to_string());
+    }
+
+    if flags & 0x20 != 0 {
+        return Result::Err("Zlib preset dictionaries are not supported".to_string());
+    }
+
+    return inflate(&data[2..]);
+}
+
+/// Decompresses raw deflate blocks
+fn inflate(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut reader = BitReader::new(data);
+    let mut output = Vec::new();
+
+    loop {
+        let is_final = reader.bits(1)? == 1;
+
+        match reader.bits(2)? {
+            0 => stored_block(&mut reader, &mut output)?,
+            1 => {
+                let (literals, distances) = fixed_codes();
+
+                compressed_block(&mut reader, &mut output, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut reader)?;
+
+                compressed_block(&mut reader, &mut output, &literals, &distances)?;
+            }
+            _ => return Result::Err("Invalid deflate block type".to_string()),
+        }
+
+        if is_final {
+            return Result::Ok(output);
+        }
+    }
+}
+
+/// Reads bits starting from the least significant one, as deflate stores them
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    buffer: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        return Self {
+            data,
+            position: 0,
+            buffer: 0,
+            count: 0,
+        };
+    }
+
+    fn bits(&mut self, count: u32) -> Result<u32, String> {
+        while self.count < count {
+            let byte = *self
+                .data
+                .get(self.position)
+                .ok_or("Unexpected end of deflate stream")?;
+
+            self.buffer |= (byte as u32) << self.count;
+            self.position += 1;
+            self.count += 8;
+        }
+
+        let value = self.buffer & ((1_u64 << count) - 1) as u32;
+
+        self.buffer >>= count;
+        self.count -= count;
+
+        return Result::Ok(value);
+    }
+
+    /// Drops the bits left in the current byte
+    fn align(&mut self) {
+        self.buffer = 0;
+        self.count = 0;
+    }
+}
+
+/// Canonical Huffman code decoded one bit at a time
+struct Huffman {
+    // Number of codes of every length
+    counts: [u16; MAX_BITS + 1],
+    // Symbols ordered by code
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, String> {
+        let mut counts = [0_u16; MAX_BITS + 1];
+
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+
+        counts[0] = 0;
+
+        let mut offsets = [0_u16; MAX_BITS + 2];
+
+        for length in 1..=MAX_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+
+        let mut symbols = vec![0_u16; offsets[MAX_BITS + 1] as usize];
+
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+
+        return Result::Ok(Self { counts, symbols });
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, String> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+
+        for length in 1..=MAX_BITS {
+            code |= reader.bits(1)? as i32;
+
+            let count = self.counts[length] as i32;
+
+            if code - first < count {
+                return Result::Ok(self.symbols[(index + code - first) as usize]);
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        return Result::Err("Invalid Huffman code in deflate stream".to_string());
+    }
+}
+
+fn stored_block(reader: &mut BitReader, output: &mut Vec<u8>) -> Result<(), String> {
+    reader.align();
+
+    let start = reader.position;
+    let header = reader
+        .data
+        .get(start..start + 4)
+        .ok_or("Unexpected end of deflate stream")?;
+    let length = u16::from_le_bytes([header[0], header[1]]);
+    let complement = u16::from_le_bytes([header[2], header[3]]);
+
+    if length != !complement {
+        return Result::Err("Stored deflate block length check failed".to_string());
+    }
+
+    let bytes = reader
+        .data
+        .get(start + 4..start + 4 + length as usize)
+        .ok_or("Unexpected end of deflate stream")?;
+
+    output.extend_from_slice(bytes);
+    reader.position = start + 4 + length as usize;
+
+    return Result::Ok(());
+}
+
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0_u8; 288];
+
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+
+    return (
+        Huffman::new(&lengths).unwrap(),
+        Huffman::new(&[5; 30]).unwrap(),
+    );
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman), String> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+
+    let mut code_length_lengths = [0_u8; 19];
+
+    for &index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_length_lengths[index] = reader.bits(3)? as u8;
+    }
+
+    let code_length_code = Huffman::new(&code_length_lengths)?;
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+
+    while lengths.len() < literal_count + distance_count {
+        let symbol = code_length_code.decode(reader)?;
+
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or("Deflate length repeat without previous length")?;
+
+                (previous, 3 + reader.bits(2)?)
+            }
+            17 => (0, 3 + reader.bits(3)?),
+            18 => (0, 11 + reader.bits(7)?),
+            _ => return Result::Err("Invalid code length symbol".to_string()),
+        };
+
+        for _ in 0..repeat {
+            lengths.push(value);
+        }
+    }
+
+    if lengths.len() != literal_count + distance_count {
+        return Result::Err("Deflate code lengths overflow".to_string());
+    }
+
+    return Result::Ok((
+        Huffman::new(&lengths[..literal_count])?,
+        Huffman::new(&lengths[literal_count..])?,
+    ));
+}
+
+fn compressed_block(
+    reader: &mut BitReader,
+    output: &mut Vec<u8>,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), String> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+
+        if symbol < 256 {
+            output.push(symbol as u8);
+            continue;
+        }
+
+        if symbol == 256 {
+            return Result::Ok(());
+        }
+
+        let index = symbol - 257;
+
+        if index >= LENGTH_BASE.len() {
+            return Result::Err("Invalid deflate length symbol".to_string());
+        }
+
+        let length =
+            LENGTH_BASE[index] as usize + reader.bits(LENGTH_EXTRA[index] as u32)? as usize;
+        let distance_symbol = distances.decode(reader)? as usize;
+
+        if distance_symbol >= DISTANCE_BASE.len() {
+            return Result::Err("Invalid deflate distance symbol".to_string());
+        }
+
+        let distance = DISTANCE_BASE[distance_symbol] as usize
+            + reader.bits(DISTANCE_EXTRA[distance_symbol] as u32)? as usize;
+
+        if distance > output.len() {
+            return Result::Err("Deflate distance reaches before the output".to_string());
+        }
+
+        // Copies byte by byte since the source may overlap what is being written
+        let start = output.len() - distance;
+
+        for i in 0..length {
+            output.push(output[start + i]);
+        }
+    }
+}
@@ -0,0 +1,868 @@
+use std::f32::consts::PI;
+use std::fs;
+
+use nalgebra_glm::{IVec2, Vec3};
+
+use crate::image::linear_image::LinearImage;
+
+// Position in 8x8 block of every coefficient in the order they are stored
+const ZIGZAG: [usize; 64] = [
+    0, 1, 8, 16, 9, 2, 3, 10, 17, 24, 32, 25, 18, 11, 4, 5, 12, 19, 26, 33, 40, 48, 41, 34, 27, 20,
+    13, 6, 7, 14, 21, 28, 35, 42, 49, 56, 57, 50, 43, 36, 29, 22, 15, 23, 30, 37, 44, 51, 58, 59,
+    52, 45, 38, 31, 39, 46, 53, 60, 61, 54, 47, 55, 62, 63,
+];
+
+/// Reads `.jpg` image
+pub fn load(path: &str) -> Result<LinearImage, String> {
+    let bytes = fs::read(path).map_err(|x| format!("Error reading file: {}. {}", path, x))?;
+
+    return decode(&bytes).map_err(|x| format!("{}: {}", path, x));
+}
+
+/// Decodes baseline or progressive JPEG image with grayscale, YCbCr or RGB color
+/// and any chroma subsampling. Values are returned as stored, without any color space
+/// conversion. Arithmetic coded, lossless and CMYK images are not supported
+///
+/// # Arguments
+///
+/// * `bytes`: File contents
+///
+/// returns: String with text what went wrong or image otherwise
+pub fn decode(bytes: &[u8]) -> Result<LinearImage, String> {
+    if !bytes.starts_with(&[0xff, 0xd8]) {
+        return Result::Err("Not a JPEG file".to_string());
+    }
+
+    let mut decoder = Decoder::new();
+    let mut position = 2;
+
+    loop {
+        // Markers may be preceded by any number of fill bytes
+        while bytes.get(position) == Option::Some(&0xff)
+            && bytes.get(position + 1) == Option::Some(&0xff)
+        {
+            position += 1;
+        }
+
+        let marker = match bytes.get(position..position + 2) {
+            Option::Some([0xff, marker]) => *marker,
+            _ => return Result::Err(format!("Expected marker at byte {}", position)),
+        };
+
+        position += 2;
+
+        if marker == 0xd9 {
+            break;
+        }
+
+        let length = match bytes.get(position..position + 2) {
+            Option::Some(&[high, low]) => u16::from_be_bytes([high, low]) as usize,
+            _ => return Result::Err("Unexpected end of file".to_string()),
+        };
+        let segment = bytes
+            .get(position + 2..position + length)
+            .ok_or_else(|| "Unexpected end of file".to_string())?;
+
+        position += length;
+
+        match marker {
+            0xc0 | 0xc1 => decoder.read_frame(segment, false)?,
+            0xc2 => decoder.read_frame(segment, true)?,
+            0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf => {
+                return Result::Err(format!("Unsupported JPEG process, marker 0x{:x}", marker))
+            }
+            0xc4 => decoder.read_huffman_tables(segment)?,
+            0xdb => decoder.read_quantization_tables(segment)?,
+            0xdd => {
+                let interval = segment
+                    .get(0..2)
+                    .ok_or_else(|| "Invalid DRI segment".to_string())?;
+
+                decoder.restart_interval = u16::from_be_bytes([interval[0], interval[1]]) as usize;
+            }
+            // Adobe segment tells if three components are RGB instead of YCbCr
+            0xee if segment.starts_with(b"Adobe") && segment.len() >= 12 => {
+                decoder.adobe_transform = Option::Some(segment[11]);
+            }
+            0xda => position = decoder.read_scan(segment, bytes, position)?,
+            _ => {}
+        }
+    }
+
+    return decoder.to_image();
+}
+
+struct Huffman {
+    // Largest code of every length, -1 if there are none
+    max_codes: [i32; 17],
+    // Difference between index of symbol and its code for every length
+    offsets: [i32; 17],
+    symbols: Vec<u8>,
+}
+
+impl Huffman {
+    fn new(counts: &[u8], symbols: &[u8]) -> Self {
+        let mut max_codes = [-1; 17];
+        let mut offsets = [0; 17];
+        let mut code = 0;
+        let mut index = 0;
+
+        for length in 1..=16 {
+            let count = counts[length - 1] as i32;
+
+            offsets[length] = index - code;
+            code += count;
+            index += count;
+            max_codes[length] = if count > 0 { code - 1 } else { -1 };
+            code <<= 1;
+        }
+
+        return Self {
+            max_codes,
+            offsets,
+            symbols: symbols.to_vec(),
+        };
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u8, String> {
+        let mut code = 0;
+
+        for length in 1..=16 {
+            code = (code << 1) | reader.bits(1) as i32;
+
+            if code <= self.max_codes[length] {
+                return self
+                    .symbols
+                    .get((code + self.offsets[length]) as usize)
+                    .copied()
+                    .ok_or_else(|| "Invalid Huffman table".to_string());
+            }
+        }
+
+        return Result::Err("Invalid Huffman code".to_string());
+    }
+}
+
+struct Component {
+    id: u8,
+    horizontal: usize,
+    vertical: usize,
+    quantization: usize,
+    dc_table: usize,
+    ac_table: usize,
+    dc_prediction: i32,
+    // Blocks covering the component, padded to whole MCUs
+    blocks_x: usize,
+    blocks_y: usize,
+    // Quantized coefficients of every block in natural order
+    coefficients: Vec<i16>,
+}
+
+/// Part of the coefficients coded by one scan
+struct Scan {
+    components: Vec<usize>,
+    start: usize,
+    end: usize,
+    // Bit position before and after the scan for successive approximation
+    high: u8,
+    low: u8,
+}
+
+struct Decoder {
+    width: usize,
+    height: usize,
+    progressive: bool,
+    components: Vec<Component>,
+    // Quantization tables in natural order
+    quantization: [[u16; 64]; 4],
+    dc_tables: [Option<Huffman>; 4],
+    ac_tables: [Option<Huffman>; 4],
+    restart_interval: usize,
+    adobe_transform: Option<u8>,
+    // Number of following blocks without nonzero coefficients in progressive AC scan
+    end_of_band_run: u32,
+}
+
+impl Decoder {
+    fn new() -> Self {
+        return Self {
+            width: 0,
+            height: 0,
+            progressive: false,
+            components: Vec::new(),
+            quantization: [[0; 64]; 4],
+            dc_tables: [Option::None, Option::None, Option::None, Option::None],
+            ac_tables: [Option::None, Option::None, Option::None, Option::None],
+            restart_interval: 0,
+            adobe_transform: Option::None,
+            end_of_band_run: 0,
+        };
+    }
+
+    fn max_sampling(&self) -> (usize, usize) {
+        return (
+            self.components
+                .iter()
+                .map(|x| x.horizontal)
+                .max()
+                .unwrap_or(1),
+            self.components
+                .iter()
+                .map(|x| x.vertical)
+                .max()
+                .unwrap_or(1),
+        );
+    }
+
+    fn read_quantization_tables(&mut self, mut segment: &[u8]) -> Result<(), String> {
+        while !segment.is_empty() {
+            let precision = segment[0] >> 4;
+            let index = (segment[0] & 0x0f) as usize;
+            let size = if precision == 0 { 64 } else { 128 };
+
+            if index >= 4 || segment.len() < size + 1 {
+                return Result::Err("Invalid DQT segment".to_string());
+            }
+
+            for (k, &position) in ZIGZAG.iter().enumerate() {
+                self.quantization[index][position] = if precision == 0 {
+                    segment[1 + k] as u16
+                } else {
+                    u16::from_be_bytes([segment[1 + k * 2], segment[2 + k * 2]])
+                };
+            }
+
+            segment = &segment[size + 1..];
+        }
+
+        return Result::Ok(());
+    }
+
+    fn read_huffman_tables(&mut self, mut segment: &[u8]) -> Result<(), String> {
+        while !segment.is_empty() {
+            if segment.len() < 17 {
+                return Result::Err("Invalid DHT segment".to_string());
+            }
+
+            let class = segment[0] >> 4;
+            let index = (segment[0] & 0x0f) as usize;
+            let counts = &segment[1..17];
+            let count = counts.iter().map(|x| *x as usize).sum::<usize>();
+
+            if index >= 4 || class > 1 || segment.len() < 17 + count {
+                return Result::Err("Invalid DHT segment".to_string());
+            }
+
+            let table = Huffman::new(counts, &segment[17..17 + count]);
+
+            if class == 0 {
+                self.dc_tables[index] = Option::Some(table);
+            } else {
+                self.ac_tables[index] = Option::Some(table);
+            }
+
+            segment = &segment[17 + count..];
+        }
+
+        return Result::Ok(());
+    }
+
+    fn read_frame(&mut self, segment: &[u8], progressive: bool) -> Result<(), String> {
+        if !self.components.is_empty() {
+            return Result::Err("More than one frame header".to_string());
+        }
+
+        if segment.len() < 6 {
+            return Result::Err("Invalid SOF segment".to_string());
+        }
+
+        if segment[0] != 8 {
+            return Result::Err(format!("Unsupported sample precision {}", segment[0]));
+        }
+
+        self.progressive = progressive;
+        self.height = u16::from_be_bytes([segment[1], segment[2]]) as usize;
+        self.width = u16::from_be_bytes([segment[3], segment[4]]) as usize;
+
+        let count = segment[5] as usize;
+
+        if self.width == 0 || self.height == 0 {
+            return Result::Err(format!("Unsupported size: {}x{}", self.width, self.height));
+        }
+
+        if count != 1 && count != 3 {
+            return Result::Err(format!("Unsupported component count {}", count));
+        }
+
+        if segment.len() < 6 + count * 3 {
+            return Result::Err("Invalid SOF segment".to_string());
+        }
+
+        for i in 0..count {
+            let data = &segment[6 + i * 3..9 + i * 3];
+            let horizontal = (data[1] >> 4) as usize;
+            let vertical = (data[1] & 0x0f) as usize;
+
+            if !(1..=4).contains(&horizontal) || !(1..=4).contains(&vertical) || data[2] >= 4 {
+                return Result::Err("Invalid SOF component".to_string());
+            }
+
+            self.components.push(Component {
+                id: data[0],
+                horizontal,
+                vertical,
+                quantization: data[2] as usize,
+                dc_table: 0,
+                ac_table: 0,
+                dc_prediction: 0,
+                blocks_x: 0,
+                blocks_y: 0,
+                coefficients: Vec::new(),
+            });
+        }
+
+        let (max_horizontal, max_vertical) = self.max_sampling();
+        let mcus_x = self.width.div_ceil(8 * max_horizontal);
+        let mcus_y = self.height.div_ceil(8 * max_vertical);
+
+        for component in &mut self.components {
+            component.blocks_x = mcus_x * component.horizontal;
+            component.blocks_y = mcus_y * component.vertical;
+            component.coefficients = vec![0; component.blocks_x * component.blocks_y * 64];
+        }
+
+        return Result::Ok(());
+    }
+
+    /// Decodes entropy coded data following SOS segment and returns position after it
+    fn read_scan(
+        &mut self,
+        segment: &[u8],
+        bytes: &[u8],
+        position: usize,
+    ) -> Result<usize, String> {
+        if self.components.is_empty() {
+            return Result::Err("Scan before frame header".to_string());
+        }
+
+        let count = *segment
+            .first()
+            .ok_or_else(|| "Invalid SOS segment".to_string())? as usize;
+
+        if count == 0 || segment.len() < 4 + count * 2 {
+            return Result::Err("Invalid SOS segment".to_string());
+        }
+
+        let mut components = Vec::with_capacity(count);
+
+        for i in 0..count {
+            let id = segment[1 + i * 2];
+            let tables = segment[2 + i * 2];
+            let index = self
+                .components
+                .iter()
+                .position(|x| x.id == id)
+                .ok_or_else(|| format!("Scan refers to unknown component {}", id))?;
+            let component = &mut self.components[index];
+
+            component.dc_table = (tables >> 4) as usize & 3;
+            component.ac_table = (tables & 0x0f) as usize & 3;
+            components.push(index);
+        }
+
+        let parameters = &segment[1 + count * 2..];
+        let scan = if self.progressive {
+            Scan {
+                components,
+                start: parameters[0] as usize,
+                end: parameters[1] as usize,
+                high: parameters[2] >> 4,
+                low: parameters[2] & 0x0f,
+            }
+        } else {
+            Scan {
+                components,
+                start: 0,
+                end: 63,
+                high: 0,
+                low: 0,
+            }
+        };
+
+        if scan.start > scan.end
+            || scan.end > 63
+            || (scan.start == 0 && scan.end != 0 && self.progressive)
+        {
+            return Result::Err("Invalid spectral selection".to_string());
+        }
+
+        if scan.start > 0 && scan.components.len() != 1 {
+            return Result::Err("AC scan with more than one component".to_string());
+        }
+
+        let (max_horizontal, max_vertical) = self.max_sampling();
+        // Single component scans are not interleaved and go over blocks of that component only
+        let (mcus_x, mcus_y) = if scan.components.len() == 1 {
+            let component = &self.components[scan.components[0]];
+
+            (
+                (self.width * component.horizontal).div_ceil(8 * max_horizontal),
+                (self.height * component.vertical).div_ceil(8 * max_vertical),
+            )
+        } else {
+            (
+                self.width.div_ceil(8 * max_horizontal),
+                self.height.div_ceil(8 * max_vertical),
+            )
+        };
+
+        let mut reader = BitReader::new(bytes, position);
+
+        self.reset();
+
+        for mcu in 0..mcus_x * mcus_y {
+            if self.restart_interval > 0 && mcu > 0 && mcu % self.restart_interval == 0 {
+                reader.restart()?;
+                self.reset();
+            }
+
+            let (mcu_x, mcu_y) = (mcu % mcus_x, mcu / mcus_x);
+
+            for &index in &scan.components {
+                let (blocks_x, blocks_y) = if scan.components.len() == 1 {
+                    (1, 1)
+                } else {
+                    (
+                        self.components[index].horizontal,
+                        self.components[index].vertical,
+                    )
+                };
+
+                for block_y in 0..blocks_y {
+                    for block_x in 0..blocks_x {
+                        let x = mcu_x * blocks_x + block_x;
+                        let y = mcu_y * blocks_y + block_y;
+
+                        self.decode_block(&scan, index, x, y, &mut reader)?;
+                    }
+                }
+            }
+        }
+
+        return Result::Ok(reader.end());
+    }
+
+    /// Clears the state carried between blocks, done at the start of scans and restart intervals
+    fn reset(&mut self) {
+        self.end_of_band_run = 0;
+
+        for component in &mut self.components {
+            component.dc_prediction = 0;
+        }
+    }
+
+    fn decode_block(
+        &mut self,
+        scan: &Scan,
+        index: usize,
+        x: usize,
+        y: usize,
+        reader: &mut BitReader,
+    ) -> Result<(), String> {
+        let component = &mut self.components[index];
+        let offset = (y * component.blocks_x + x) * 64;
+        let block = &mut component.coefficients[offset..offset + 64];
+        let dc_table = self.dc_tables[component.dc_table].as_ref();
+        let ac_table = self.ac_tables[component.ac_table].as_ref();
+        let missing_table = || "Missing Huffman table".to_string();
+
+        if scan.start == 0 {
+            if scan.high == 0 {
+                let size = dc_table.ok_or_else(missing_table)?.decode(reader)?;
+
+                component.dc_prediction += reader.signed(size);
+                block[0] = (component.dc_prediction << scan.low) as i16;
+            } else if reader.bits(1) == 1 {
+                block[0] |= 1 << scan.low;
+            }
+        }
+
+        if scan.end == 0 {
+            return Result::Ok(());
+        }
+
+        let ac_table = ac_table.ok_or_else(missing_table)?;
+        let start = scan.start.max(1);
+
+        return if scan.high == 0 {
+            decode_ac_first(
+                block,
+                ac_table,
+                start,
+                scan,
+                &mut self.end_of_band_run,
+                reader,
+            )
+        } else {
+            decode_ac_refine(
+                block,
+                ac_table,
+                start,
+                scan,
+                &mut self.end_of_band_run,
+                reader,
+            )
+        };
+    }
+
+    fn to_image(&self) -> Result<LinearImage, String> {
+        if self.components.is_empty() {
+            return Result::Err("Missing frame header".to_string());
+        }
+
+        let planes: Vec<Plane> = self
+            .components
+            .iter()
+            .map(|x| Plane::new(x, &self.quantization[x.quantization]))
+            .collect();
+        let (max_horizontal, max_vertical) = self.max_sampling();
+        let is_rgb = self.adobe_transform == Option::Some(0);
+        let mut pixels = Vec::with_capacity(self.width * self.height);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                // Subsampled components are interpolated between sample centers
+                let sample = |index: usize| {
+                    let component = &self.components[index];
+                    let sample_x = (x as f32 + 0.5) * component.horizontal as f32
+                        / max_horizontal as f32
+                        - 0.5;
+                    let sample_y =
+                        (y as f32 + 0.5) * component.vertical as f32 / max_vertical as f32 - 0.5;
+
+                    planes[index].sample(sample_x, sample_y)
+                };
+
+                if self.components.len() == 1 {
+                    pixels.push(Vec3::from_element(sample(0) / 255.0));
+                    continue;
+                }
+
+                let (first, second, third) = (sample(0), sample(1), sample(2));
+
+                let rgb = if is_rgb {
+                    Vec3::new(first, second, third)
+                } else {
+                    let (cb, cr) = (second - 128.0, third - 128.0);
+
+                    Vec3::new(
+                        first + 1.402 * cr,
+                        first - 0.344136 * cb - 0.714136 * cr,
+                        first + 1.772 * cb,
+                    )
+                };
+
+                pixels.push(rgb.map(|x| x.round().clamp(0.0, 255.0) / 255.0));
+            }
+        }
+
+        return LinearImage::new(IVec2::new(self.width as i32, self.height as i32), pixels);
+    }
+}
+
+fn decode_ac_first(
+    block: &mut [i16],
+    table: &Huffman,
+    start: usize,
+    scan: &Scan,
+    end_of_band_run: &mut u32,
+    reader: &mut BitReader,
+) -> Result<(), String> {
+    if *end_of_band_run > 0 {
+        *end_of_band_run -= 1;
+        return Result::Ok(());
+    }
+
+    let mut k = start;
+
+    while k <= scan.end {
+        let symbol = table.decode(reader)?;
+        let zeros = (symbol >> 4) as u32;
+        let size = symbol & 0x0f;
+
+        if size == 0 {
+            if zeros < 15 {
+                // This block and the following ones have nothing left in the band
+                *end_of_band_run = (1 << zeros) - 1 + reader.bits(zeros);
+                break;
+            }
+
+            k += 16;
+            continue;
+        }
+
+        k += zeros as usize;
+
+        if k > scan.end {
+            return Result::Err("Coefficient index out of block".to_string());
+        }
+
+        block[ZIGZAG[k]] = (reader.signed(size) << scan.low) as i16;
+        k += 1;
+    }
+
+    return Result::Ok(());
+}
+
+/// Adds one more bit to coefficients coded by the previous scans and places new ones
+fn decode_ac_refine(
+    block: &mut [i16],
+    table: &Huffman,
+    start: usize,
+    scan: &Scan,
+    end_of_band_run: &mut u32,
+    reader: &mut BitReader,
+) -> Result<(), String> {
+    let bit = 1_i16 << scan.low;
+    let mut k = start;
+
+    let refine = |coefficient: &mut i16, reader: &mut BitReader| {
+        if reader.bits(1) == 1 && *coefficient & bit == 0 {
+            *coefficient += if *coefficient >= 0 { bit } else { -bit };
+        }
+    };
+
+    if *end_of_band_run == 0 {
+        while k <= scan.end {
+            let symbol = table.decode(reader)?;
+            let mut zeros = (symbol >> 4) as i32;
+            let size = symbol & 0x0f;
+            let mut value = 0;
+
+            if size != 0 {
+                value = if reader.bits(1) == 1 { bit } else { -bit };
+            } else if zeros != 15 {
+                *end_of_band_run = (1 << zeros) + reader.bits(zeros as u32);
+                break;
+            }
+
+            // Skips the given number of zero coefficients, refining nonzero ones on the way
+            while k <= scan.end {
+                let coefficient = &mut block[ZIGZAG[k]];
+
+                if *coefficient != 0 {
+                    refine(coefficient, reader);
+                } else {
+                    if zeros == 0 {
+                        break;
+                    }
+
+                    zeros -= 1;
+                }
+
+                k += 1;
+            }
+
+            if value != 0 && k <= scan.end {
+                block[ZIGZAG[k]] = value;
+            }
+
+            k += 1;
+        }
+    }
+
+    if *end_of_band_run > 0 {
+        while k <= scan.end {
+            let coefficient = &mut block[ZIGZAG[k]];
+
+            if *coefficient != 0 {
+                refine(coefficient, reader);
+            }
+
+            k += 1;
+        }
+
+        *end_of_band_run -= 1;
+    }
+
+    return Result::Ok(());
+}
+
+/// Samples of one component after the inverse transform
+struct Plane {
+    width: usize,
+    height: usize,
+    samples: Vec<f32>,
+}
+
+impl Plane {
+    fn new(component: &Component, quantization: &[u16; 64]) -> Self {
+        // Cosine basis scaled for the inverse transform: x * 8 + u
+        let mut cosines = [0.0; 64];
+
+        for x in 0..8 {
+            for u in 0..8 {
+                let scale = if u == 0 { 0.5_f32.sqrt() } else { 1.0 };
+
+                cosines[x * 8 + u] =
+                    scale * ((2 * x + 1) as f32 * u as f32 * PI / 16.0).cos() / 2.0;
+            }
+        }
+
+        let width = component.blocks_x * 8;
+        let height = component.blocks_y * 8;
+        let mut samples = vec![0.0; width * height];
+        let mut rows = [0.0; 64];
+
+        for (i, block) in component.coefficients.chunks_exact(64).enumerate() {
+            let (block_x, block_y) = (i % component.blocks_x, i / component.blocks_x);
+
+            // Separable transform, first along rows of coefficients then along columns
+            for v in 0..8 {
+                for column in 0..8 {
+                    rows[v * 8 + column] = (0..8)
+                        .map(|u| {
+                            cosines[column * 8 + u]
+                                * (block[v * 8 + u] as i32 * quantization[v * 8 + u] as i32) as f32
+                        })
+                        .sum();
+                }
+            }
+
+            for row in 0..8 {
+                let offset = (block_y * 8 + row) * width + block_x * 8;
+
+                for column in 0..8 {
+                    let value: f32 = (0..8)
+                        .map(|v| cosines[row * 8 + v] * rows[v * 8 + column])
+                        .sum();
+
+                    samples[offset + column] = (value + 128.0).round().clamp(0.0, 255.0);
+                }
+            }
+        }
+
+        return Self {
+            width,
+            height,
+            samples,
+        };
+    }
+
+    /// Bilinear interpolation with coordinates clamped to the plane
+    fn sample(&self, x: f32, y: f32) -> f32 {
+        let x = x.clamp(0.0, (self.width - 1) as f32);
+        let y = y.clamp(0.0, (self.height - 1) as f32);
+        let (left, top) = (x as usize, y as usize);
+        let right = (left + 1).min(self.width - 1);
+        let bottom = (top + 1).min(self.height - 1);
+        let (tx, ty) = (x - left as f32, y - top as f32);
+        let at = |x: usize, y: usize| self.samples[y * self.width + x];
+
+        let upper = at(left, top) + (at(right, top) - at(left, top)) * tx;
+        let lower = at(left, bottom) + (at(right, bottom) - at(left, bottom)) * tx;
+
+        return upper + (lower - upper) * ty;
+    }
+}
+
+/// Reads entropy coded bits, skipping stuffed zero bytes and stopping at markers
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    buffer: u32,
+    count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8], position: usize) -> Self {
+        return Self {
+            bytes,
+            position,
+            buffer: 0,
+            count: 0,
+        };
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        let byte = match self.bytes.get(self.position) {
+            Option::Some(byte) => *byte,
+            Option::None => return 0,
+        };
+
+        if byte != 0xff {
+            self.position += 1;
+            return byte;
+        }
+
+        if self.bytes.get(self.position + 1) == Option::Some(&0) {
+            self.position += 2;
+            return byte;
+        }
+
+        // Marker reached, corrupted or truncated data is padded with zeros
+        return 0;
+    }
+
+    fn bits(&mut self, count: u32) -> u32 {
+        while self.count < count {
+            self.buffer = (self.buffer << 8) | self.next_byte() as u32;
+            self.count += 8;
+        }
+
+        self.count -= count;
+
+        return (self.buffer >> self.count) & ((1 << count) - 1);
+    }
+
+    /// Reads value of given bit size, where sizes above zero encode both signs
+    fn signed(&mut self, size: u8) -> i32 {
+        if size == 0 {
+            return 0;
+        }
+
+        let value = self.bits(size as u32) as i32;
+
+        if value < 1 << (size - 1) {
+            return value - (1 << size) + 1;
+        }
+
+        return value;
+    }
+
+    /// Skips to the byte after the next restart marker
+    fn restart(&mut self) -> Result<(), String> {
+        self.buffer = 0;
+        self.count = 0;
+
+        while let Option::Some(&byte) = self.bytes.get(self.position) {
+            self.position += 1;
+
+            let next = self.bytes.get(self.position).copied();
+
+            if byte == 0xff && next.is_some_and(|x| (0xd0..=0xd7).contains(&x)) {
+                self.position += 1;
+                return Result::Ok(());
+            }
+        }
+
+        return Result::Err("Missing restart marker".to_string());
+    }
+
+    /// Position of the first marker after the entropy coded data
+    fn end(&self) -> usize {
+        let mut position = self.position;
+
+        while position + 1 < self.bytes.len() {
+            let next = self.bytes[position + 1];
+
+            if self.bytes[position] == 0xff && next != 0 && !(0xd0..=0xd7).contains(&next) {
+                return position;
+            }
+
+            position += 1;
+        }
+
+        return self.bytes.len();
+    }
+}
@@ -0,0 +1,9 @@
+pub mod hdr_decoder;
+pub mod image_loader;
+mod inflate;
+pub mod jpeg_decoder;
+pub mod mtl_library;
+pub mod mtl_material;
+pub mod obj_importer;
+mod parsing;
+pub mod png_decoder;
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fs;
+
+use crate::import::mtl_material::MtlMaterial;
+use crate::import::parsing::{located, parse_f32, parse_name, parse_vec3};
+
+/// Materials of a Wavefront MTL file, by name
+#[derive(Clone, Default)]
+pub struct MtlLibrary {
+    materials: HashMap<String, MtlMaterial>,
+}
+
+impl MtlLibrary {
+    pub fn load(path: &str) -> Result<Self, String> {
+        let source =
+            fs::read_to_string(path).map_err(|x| format!("Error reading file: {}. {}", path, x))?;
+
+        return Self::parse(&source, path);
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: MTL file contents
+    /// * `path`: Path used in error messages
+    ///
+    /// returns: String with text what went wrong or library otherwise
+    pub fn parse(source: &str, path: &str) -> Result<Self, String> {
+        let mut materials = HashMap::new();
+        let mut current: Option<(String, MtlMaterial)> = Option::None;
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            let mut tokens = line.split_whitespace();
+            let keyword = match tokens.next() {
+                Some(keyword) if !keyword.starts_with('#') => keyword,
+                _ => continue,
+            };
+
+            if keyword == "newmtl" {
+                let name = parse_name(line, keyword).map_err(|x| located(path, index + 1, &x))?;
+
+                if let Some((name, material)) = current.take() {
+                    materials.insert(name, material);
+                }
+
+                current = Option::Some((name.to_string(), MtlMaterial::default()));
+
+                continue;
+            }
+
+            let material = match &mut current {
+                Some((_, material)) => material,
+                None => {
+                    return Result::Err(located(
+                        path,
+                        index + 1,
+                        &format!("\"{}\" before any \"newmtl\"", keyword),
+                    ))
+                }
+            };
+
+            let result = match keyword {
+                "Kd" => parse_vec3(&mut tokens, "diffuse color").map(|x| material.set_diffuse(x)),
+                "Ks" => parse_vec3(&mut tokens, "specular color").map(|x| material.set_specular(x)),
+                "Ke" => parse_vec3(&mut tokens, "emission color").map(|x| material.set_emission(x)),
+                "Ns" => parse_f32(tokens.next(), "specular exponent")
+                    .map(|x| material.set_specular_exponent(x)),
+                "Ni" => parse_f32(tokens.next(), "index of refraction")
+                    .map(|x| material.set_index_of_refraction(x)),
+                "d" => parse_f32(tokens.next(), "dissolve").map(|x| material.set_dissolve(x)),
+                "Tr" => {
+                    parse_f32(tokens.next(), "transparency").map(|x| material.set_dissolve(1.0 - x))
+                }
+                "illum" => parse_f32(tokens.next(), "illumination model")
+                    .map(|x| material.set_illumination_model(x as u32)),
+                "Pr" => parse_f32(tokens.next(), "roughness").map(|x| material.set_roughness(x)),
+                "Pm" => parse_f32(tokens.next(), "metallic").map(|x| material.set_metallic(x)),
+                "Ps" => parse_f32(tokens.next(), "sheen").map(|x| material.set_sheen(x)),
+                "Pc" => parse_f32(tokens.next(), "clearcoat").map(|x| material.set_clearcoat(x)),
+                // Texture maps and other parameters have no counterpart yet
+                _ => Result::Ok(()),
+            };
+
+            result.map_err(|x| located(path, index + 1, &x))?;
+        }
+
+        if let Some((name, material)) = current.take() {
+            materials.insert(name, material);
+        }
+
+        return Result::Ok(Self { materials });
+    }
+}
+
+impl MtlLibrary {
+    pub fn get(&self, name: &str) -> Option<&MtlMaterial> {
+        return self.materials.get(name);
+    }
+
+    pub fn extend(&mut self, other: MtlLibrary) {
+        self.materials.extend(other.materials);
+    }
+}
@@ -0,0 +1,195 @@
+use nalgebra_glm::Vec3;
+
+use crate::scene::scene_info::SceneInfo;
+
+/// Material parameters read from an MTL library
+#[derive(Clone)]
+pub struct MtlMaterial {
+    diffuse: Vec3,
+    specular: Vec3,
+    emission: Vec3,
+    specular_exponent: f32,
+    index_of_refraction: f32,
+    dissolve: f32,
+    illumination_model: u32,
+    // Physically based extension, None when the file does not set them
+    roughness: Option<f32>,
+    metallic: Option<f32>,
+    sheen: Option<f32>,
+    clearcoat: Option<f32>,
+}
+
+impl Default for MtlMaterial {
+    fn default() -> Self {
+        return Self {
+            diffuse: Vec3::new(0.8, 0.8, 0.8),
+            specular: Vec3::new(0.0, 0.0, 0.0),
+            emission: Vec3::new(0.0, 0.0, 0.0),
+            specular_exponent: 0.0,
+            index_of_refraction: 1.0,
+            dissolve: 1.0,
+            illumination_model: 2,
+            roughness: Option::None,
+            metallic: Option::None,
+            sheen: Option::None,
+            clearcoat: Option::None,
+        };
+    }
+}
+
+impl MtlMaterial {
+    pub fn get_diffuse(&self) -> &Vec3 {
+        return &self.diffuse;
+    }
+
+    pub fn get_specular(&self) -> &Vec3 {
+        return &self.specular;
+    }
+
+    pub fn get_emission(&self) -> &Vec3 {
+        return &self.emission;
+    }
+
+    pub fn get_specular_exponent(&self) -> f32 {
+        return self.specular_exponent;
+    }
+
+    pub fn get_index_of_refraction(&self) -> f32 {
+        return self.index_of_refraction;
+    }
+
+    pub fn get_dissolve(&self) -> f32 {
+        return self.dissolve;
+    }
+
+    pub fn get_illumination_model(&self) -> u32 {
+        return self.illumination_model;
+    }
+
+    pub fn get_roughness(&self) -> Option<f32> {
+        return self.roughness;
+    }
+
+    pub fn get_metallic(&self) -> Option<f32> {
+        return self.metallic;
+    }
+
+    pub fn get_sheen(&self) -> Option<f32> {
+        return self.sheen;
+    }
+
+    pub fn get_clearcoat(&self) -> Option<f32> {
+        return self.clearcoat;
+    }
+
+    pub fn set_diffuse(&mut self, diffuse: Vec3) {
+        self.diffuse = diffuse;
+    }
+
+    pub fn set_specular(&mut self, specular: Vec3) {
+        self.specular = specular;
+    }
+
+    pub fn set_emission(&mut self, emission: Vec3) {
+        self.emission = emission;
+    }
+
+    pub fn set_specular_exponent(&mut self, specular_exponent: f32) {
+        self.specular_exponent = specular_exponent;
+    }
+
+    pub fn set_index_of_refraction(&mut self, index_of_refraction: f32) {
+        self.index_of_refraction = index_of_refraction;
+    }
+
+    pub fn set_dissolve(&mut self, dissolve: f32) {
+        self.dissolve = dissolve;
+    }
+
+    pub fn set_illumination_model(&mut self, illumination_model: u32) {
+        self.illumination_model = illumination_model;
+    }
+
+    pub fn set_roughness(&mut self, roughness: f32) {
+        self.roughness = Option::Some(roughness);
+    }
+
+    pub fn set_metallic(&mut self, metallic: f32) {
+        self.metallic = Option::Some(metallic);
+    }
+
+    pub fn set_sheen(&mut self, sheen: f32) {
+        self.sheen = Option::Some(sheen);
+    }
+
+    pub fn set_clearcoat(&mut self, clearcoat: f32) {
+        self.clearcoat = Option::Some(clearcoat);
+    }
+}
+
+impl MtlMaterial {
+    /// Adds the closest matching scene material.
+    /// Emissive materials become emission, ones with any physically based parameter
+    /// (Pr, Pm, Ps, Pc) become principled, transparent or refracting (illum 4, 6, 7, 9)
+    /// become refractive, mirror-like (illum 3, 5) become reflective, the rest are lambertian.
+    ///
+    /// # Arguments
+    ///
+    /// * `scene_info`: Scene to add material to
+    /// * `name`: Scene material name
+    ///
+    /// returns: SceneInfo Scene with the material added
+    pub fn add_to(&self, scene_info: SceneInfo, name: &str) -> SceneInfo {
+        let emission_power = self.emission.max();
+
+        if emission_power > 0.0 {
+            return scene_info.emission(name, self.emission / emission_power, emission_power);
+        }
+
+        let is_physically_based = [self.roughness, self.metallic, self.sheen, self.clearcoat]
+            .iter()
+            .any(|x| x.is_some());
+
+        if is_physically_based {
+            return self.add_principled_to(scene_info, name);
+        }
+
+        if self.dissolve < 1.0 || matches!(self.illumination_model, 4 | 6 | 7 | 9) {
+            return scene_info.refractive(name, self.diffuse, self.index_of_refraction);
+        }
+
+        if matches!(self.illumination_model, 3 | 5) {
+            let color = if self.specular.max() > 0.0 {
+                self.specular
+            } else {
+                self.diffuse
+            };
+
+            return scene_info.reflective(name, color, self.specular_exponent);
+        }
+
+        return scene_info.lambertian(name, self.diffuse);
+    }
+
+    fn add_principled_to(&self, scene_info: SceneInfo, name: &str) -> SceneInfo {
+        // Reflectance at normal incidence of the index of refraction, 0.5 maps to 4%
+        let specular = if self.index_of_refraction > 1.0 {
+            let ratio = (self.index_of_refraction - 1.0) / (self.index_of_refraction + 1.0);
+
+            (ratio * ratio / 0.08).min(1.0)
+        } else {
+            0.5
+        };
+
+        return scene_info.principled(
+            name,
+            self.diffuse,
+            self.metallic.unwrap_or(0.0),
+            self.roughness.unwrap_or(0.5),
+            specular,
+            self.clearcoat.unwrap_or(0.0),
+            self.sheen.unwrap_or(0.0),
+            1.0 - self.dissolve,
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::import::mtl_library::MtlLibrary;
+use crate::import::mtl_material::MtlMaterial;
+use crate::import::parsing::{located, parse_name, parse_vec2, parse_vec3};
+use crate::scene::scene_info::SceneInfo;
+
+const DEFAULT_GROUP: &str = "default";
+const DEFAULT_MATERIAL: &str = "default";
+
+/// Mesh of one group and material, with vertices deduplicated by their index triplet
+struct MeshBuilder {
+    group: String,
+    material: Option<String>,
+    vertex_map: HashMap<(usize, Option<usize>, Option<usize>), u32>,
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    has_normals: bool,
+    has_uvs: bool,
+    triangles: Vec<[u32; 3]>,
+}
+
+impl MeshBuilder {
+    fn new(group: &str, material: Option<String>) -> Self {
+        return Self {
+            group: group.to_string(),
+            material,
+            vertex_map: HashMap::new(),
+            positions: Vec::new(),
+            normals: Vec::new(),
+            uvs: Vec::new(),
+            has_normals: true,
+            has_uvs: true,
+            triangles: Vec::new(),
+        };
+    }
+
+    fn vertex(&mut self, obj: &ObjData, key: (usize, Option<usize>, Option<usize>)) -> u32 {
+        if let Some(index) = self.vertex_map.get(&key) {
+            return *index;
+        }
+
+        let (position, uv, normal) = key;
+        let index = self.positions.len() as u32;
+
+        self.positions.push(obj.positions[position]);
+
+        match uv {
+            Some(uv) => self.uvs.push(obj.uvs[uv]),
+            None => {
+                self.has_uvs = false;
+                self.uvs.push(Vec2::new(0.0, 0.0));
+            }
+        }
+
+        match normal {
+            Some(normal) => self.normals.push(obj.normals[normal]),
+            None => {
+                self.has_normals = false;
+                self.normals.push(Vec3::new(0.0, 0.0, 0.0));
+            }
+        }
+
+        self.vertex_map.insert(key, index);
+
+        return index;
+    }
+
+    fn build(self) -> Result<MeshGeometry, String> {
+        let normals = if self.has_normals {
+            Option::Some(self.normals)
+        } else {
+            Option::None
+        };
+        let uvs = if self.has_uvs {
+            Option::Some(self.uvs)
+        } else {
+            Option::None
+        };
+
+        return MeshGeometry::new(self.positions, normals, uvs, self.triangles);
+    }
+}
+
+#[derive(Default)]
+struct ObjData {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+}
+
+/// Loads Wavefront OBJ files with their MTL material libraries into a `SceneInfo`.
+/// Every group and material pair becomes a mesh named `name/group/material`,
+/// every used material is added as `name/material`.
+pub struct ObjImporter {
+    path: String,
+    name: String,
+}
+
+impl ObjImporter {
+    pub fn new(path: &str, name: &str) -> Result<Self, String> {
+        if path.is_empty() {
+            return Result::Err("Path is empty".to_string());
+        }
+
+        return Result::Ok(Self {
+            path: path.to_string(),
+            name: name.to_string(),
+        });
+    }
+}
+
+impl ObjImporter {
+    pub fn import(&self, scene_info: SceneInfo) -> Result<SceneInfo, String> {
+        let source = fs::read_to_string(&self.path)
+            .map_err(|x| format!("Error reading file: {}. {}", self.path, x))?;
+        let directory = Path::new(&self.path)
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+
+        return self.import_str(&source, scene_info, |file| {
+            MtlLibrary::load(&directory.join(file).to_string_lossy())
+        });
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: OBJ file contents
+    /// * `scene_info`: Scene to add meshes and materials to
+    /// * `load_library`: Loads MTL library by the file name given in `mtllib`
+    ///
+    /// returns: String with text what went wrong or scene with the meshes added
+    pub fn import_str<F>(
+        &self,
+        source: &str,
+        scene_info: SceneInfo,
+        load_library: F,
+    ) -> Result<SceneInfo, String>
+    where
+        F: Fn(&str) -> Result<MtlLibrary, String>,
+    {
+        let mut obj = ObjData::default();
+        let mut library = MtlLibrary::default();
+        let mut meshes: Vec<MeshBuilder> = Vec::new();
+        let mut mesh_indices: HashMap<(String, Option<String>), usize> = HashMap::new();
+        let mut group = DEFAULT_GROUP.to_string();
+        let mut material: Option<String> = Option::None;
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            let mut tokens = line.split_whitespace();
+            let keyword = match tokens.next() {
+                Some(keyword) if !keyword.starts_with('#') => keyword,
+                _ => continue,
+            };
+            let error = |message: &str| located(&self.path, index + 1, message);
+
+            match keyword {
+                "v" => obj
+                    .positions
+                    .push(parse_vec3(&mut tokens, "vertex position").map_err(|x| error(&x))?),
+                "vn" => obj
+                    .normals
+                    .push(parse_vec3(&mut tokens, "vertex normal").map_err(|x| error(&x))?),
+                "vt" => obj
+                    .uvs
+                    .push(parse_vec2(&mut tokens, "texture coordinate").map_err(|x| error(&x))?),
+                "g" | "o" => {
+                    group = match tokens.next() {
+                        Some(name) => name.to_string(),
+                        None => DEFAULT_GROUP.to_string(),
+                    }
+                }
+                "usemtl" => {
+                    let name = parse_name(line, keyword).map_err(|x| error(&x))?;
+
+                    if library.get(name).is_none() {
+                        return Result::Err(error(&format!("Unknown material \"{}\"", name)));
+                    }
+
+                    material = Option::Some(name.to_string());
+                }
+                "mtllib" => {
+                    for file in tokens {
+                        library.extend(load_library(file).map_err(|x| error(&x))?);
+                    }
+                }
+                "f" => {
+                    let mut face = Vec::new();
+
+                    for token in tokens {
+                        face.push(parse_face_vertex(token, &obj).map_err(|x| error(&x))?);
+                    }
+
+                    if face.len() < 3 {
+                        return Result::Err(error(&format!(
+                            "Face has {} vertices, at least 3 are required",
+                            face.len()
+                        )));
+                    }
+
+                    let key = (group.clone(), material.clone());
+                    let mesh_index = *mesh_indices.entry(key).or_insert_with(|| {
+                        meshes.push(MeshBuilder::new(&group, material.clone()));
+
+                        meshes.len() - 1
+                    });
+                    let mesh = &mut meshes[mesh_index];
+                    let indices: Vec<u32> = face.iter().map(|x| mesh.vertex(&obj, *x)).collect();
+
+                    // Polygons are split into a triangle fan
+                    for i in 1..indices.len() - 1 {
+                        mesh.triangles
+                            .push([indices[0], indices[i], indices[i + 1]]);
+                    }
+                }
+                // Smoothing groups, lines, points and curves are not supported
+                _ => {}
+            }
+        }
+
+        let mut scene_info = scene_info;
+        let mut added_materials: Vec<String> = Vec::new();
+
+        for mesh in meshes {
+            let material_name = mesh.material.as_deref().unwrap_or(DEFAULT_MATERIAL);
+            let scene_material_name = format!("{}/{}", self.name, material_name);
+            let mesh_name = format!("{}/{}/{}", self.name, mesh.group, material_name);
+
+            if !added_materials.contains(&scene_material_name) {
+                let mtl_material = match &mesh.material {
+                    Some(name) => library.get(name).cloned().unwrap(),
+                    None => MtlMaterial::default(),
+                };
+
+                scene_info = mtl_material.add_to(scene_info, &scene_material_name);
+                added_materials.push(scene_material_name.clone());
+            }
+
+            let geometry = mesh
+                .build()
+                .map_err(|x| format!("{}: mesh \"{}\": {}", self.path, mesh_name, x))?;
+
+            scene_info = scene_info.mesh(&mesh_name, &scene_material_name, Arc::new(geometry));
+        }
+
+        return Result::Ok(scene_info);
+    }
+}
+
+/// Resolves 1-based or negative (relative to the end) OBJ index
+fn resolve_index(token: &str, count: usize, what: &str) -> Result<usize, String> {
+    let index = token
+        .parse::<i64>()
+        .map_err(|_| format!("Cannot parse {} index from \"{}\"", what, token))?;
+    let resolved = if index < 0 {
+        count as i64 + index
+    } else {
+        index - 1
+    };
+
+    if resolved < 0 || resolved >= count as i64 {
+        return Result::Err(format!(
+            "{} index {} is out of range, there are {}",
+            what, index, count
+        ));
+    }
+
+    return Result::Ok(resolved as usize);
+}
+
+/// Parses `v`, `v/vt`, `v//vn` or `v/vt/vn`
+fn parse_face_vertex(
+    token: &str,
+    obj: &ObjData,
+) -> Result<(usize, Option<usize>, Option<usize>), String> {
+    let mut parts = token.split('/');
+    let position = resolve_index(parts.next().unwrap(), obj.positions.len(), "Vertex")?;
+    let uv = match parts.next() {
+        Some(part) if !part.is_empty() => {
+            Option::Some(resolve_index(part, obj.uvs.len(), "Texture coordinate")?)
+        }
+        _ => Option::None,
+    };
+    let normal = match parts.next() {
+        Some(part) if !part.is_empty() => {
+            Option::Some(resolve_index(part, obj.normals.len(), "Normal")?)
+        }
+        _ => Option::None,
+    };
+
+    if parts.next().is_some() {
+        return Result::Err(format!("Malformed face vertex \"{}\"", token));
+    }
+
+    return Result::Ok((position, uv, normal));
+}
@@ -0,0 +1,45 @@
+use std::str::SplitWhitespace;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+pub(super) fn located(path: &str, line: usize, message: &str) -> String {
+    return format!("{}:{}: {}", path, line, message);
+}
+
+pub(super) fn parse_f32(token: Option<&str>, what: &str) -> Result<f32, String> {
+    let token = token.ok_or_else(|| format!("Missing {}", what))?;
+
+    return token
+        .parse::<f32>()
+        .map_err(|_| format!("Cannot parse {} from \"{}\"", what, token));
+}
+
+pub(super) fn parse_vec2(tokens: &mut SplitWhitespace, what: &str) -> Result<Vec2, String> {
+    let x = parse_f32(tokens.next(), what)?;
+    // Second texture coordinate is optional in OBJ
+    let y = match tokens.next() {
+        Some(token) => parse_f32(Option::Some(token), what)?,
+        None => 0.0,
+    };
+
+    return Result::Ok(Vec2::new(x, y));
+}
+
+pub(super) fn parse_vec3(tokens: &mut SplitWhitespace, what: &str) -> Result<Vec3, String> {
+    return Result::Ok(Vec3::new(
+        parse_f32(tokens.next(), what)?,
+        parse_f32(tokens.next(), what)?,
+        parse_f32(tokens.next(), what)?,
+    ));
+}
+
+/// Rest of the line after the keyword, for names that may contain spaces
+pub(super) fn parse_name<'a>(line: &'a str, keyword: &str) -> Result<&'a str, String> {
+    let name = line[keyword.len()..].trim();
+
+    if name.is_empty() {
+        return Result::Err(format!("Missing name after \"{}\"", keyword));
+    }
+
+    return Result::Ok(name);
+}
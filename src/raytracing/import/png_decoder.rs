@@ -0,0 +1,409 @@
+use std::fs;
+
+use nalgebra_glm::{IVec2, Vec3};
+
+use crate::image::linear_image::LinearImage;
+use crate::import::inflate::decompress_zlib;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+// Origin and step of every Adam7 pass: x, y, step x, step y
+const ADAM7_PASSES: [(usize, usize, usize, usize); 7] = [
+    (0, 0, 8, 8),
+    (4, 0, 8, 8),
+    (0, 4, 4, 8),
+    (2, 0, 4, 4),
+    (0, 2, 2, 4),
+    (1, 0, 2, 2),
+    (0, 1, 1, 2),
+];
+
+/// Reads `.png` image
+pub fn load(path: &str) -> Result<LinearImage, String> {
+    let bytes = fs::read(path).map_err(|x| format!("Error reading file: {}. {}", path, x))?;
+
+    return decode(&bytes).map_err(|x| format!("{}: {}", path, x));
+}
+
+/// Decodes PNG image of any standard color type and bit depth, interlaced or not.
+/// Values are returned as stored, without any color space conversion, and alpha is dropped
+///
+/// # Arguments
+///
+/// * `bytes`: File contents
+///
+/// returns: String with text what went wrong or image otherwise
+pub fn decode(bytes: &[u8]) -> Result<LinearImage, String> {
+    if !bytes.starts_with(&SIGNATURE) {
+        return Result::Err("Not a PNG file".to_string());
+    }
+
+    let mut position = SIGNATURE.len();
+    let mut header = Option::None;
+    let mut palette = Vec::new();
+    let mut compressed = Vec::new();
+
+    loop {
+        let (chunk_type, data) = read_chunk(bytes, &mut position)?;
+
+        match &chunk_type {
+            b"IHDR" => header = Option::Some(Header::parse(data)?),
+            b"PLTE" => {
+                palette = data
+                    .chunks_exact(3)
+                    .map(|x| Vec3::new(x[0] as f32, x[1] as f32, x[2] as f32) / 255.0)
+                    .collect();
+            }
+            b"IDAT" => compressed.extend_from_slice(data),
+            b"IEND" => break,
+            _ => {
+                // Uppercase first letter marks chunks needed to show the image correctly
+                if chunk_type[0].is_ascii_uppercase() {
+                    return Result::Err(format!(
+                        "Unsupported critical chunk \"{}\"",
+                        String::from_utf8_lossy(&chunk_type)
+                    ));
+                }
+            }
+        }
+    }
+
+    let header = header.ok_or_else(|| "Missing IHDR chunk".to_string())?;
+
+    if header.color_type == 3 && palette.is_empty() {
+        return Result::Err("Missing PLTE chunk for palette image".to_string());
+    }
+
+    let data = decompress_zlib(&compressed).map_err(|x| format!("Image data: {}", x))?;
+
+    // Corrupt headers must not make the allocation below huge
+    if data.len() < header.data_length() {
+        return Result::Err(format!(
+            "Image data is too short for {}x{} image",
+            header.width, header.height
+        ));
+    }
+
+    let mut pixels = vec![Vec3::zeros(); header.width * header.height];
+
+    if header.interlace {
+        let mut offset = 0;
+
+        for (x0, y0, step_x, step_y) in ADAM7_PASSES {
+            // Passes that would start outside of small images are left out of the data
+            if header.width <= x0 || header.height <= y0 {
+                continue;
+            }
+
+            let width = (header.width - x0).div_ceil(step_x);
+            let height = (header.height - y0).div_ceil(step_y);
+            let rows = unfilter(&header, data.get(offset..).unwrap_or(&[]), width, height)?;
+
+            offset += height * (header.row_length(width) + 1);
+
+            for (y, row) in rows.iter().enumerate() {
+                for x in 0..width {
+                    let index = (y0 + y * step_y) * header.width + x0 + x * step_x;
+
+                    pixels[index] = header.pixel(row, x, &palette)?;
+                }
+            }
+        }
+    } else {
+        let rows = unfilter(&header, &data, header.width, header.height)?;
+
+        for (y, row) in rows.iter().enumerate() {
+            for x in 0..header.width {
+                pixels[y * header.width + x] = header.pixel(row, x, &palette)?;
+            }
+        }
+    }
+
+    return LinearImage::new(
+        IVec2::new(header.width as i32, header.height as i32),
+        pixels,
+    );
+}
+
+struct Header {
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    color_type: u8,
+    interlace: bool,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self, String> {
+        if data.len() != 13 {
+            return Result::Err("Invalid IHDR chunk length".to_string());
+        }
+
+        let width = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+        let height = u32::from_be_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let bit_depth = data[8];
+        let color_type = data[9];
+
+        if width == 0 || height == 0 {
+            return Result::Err(format!("Empty image: {}x{}", width, height));
+        }
+
+        let depths: &[u8] = match color_type {
+            0 => &[1, 2, 4, 8, 16],
+            3 => &[1, 2, 4, 8],
+            2 | 4 | 6 => &[8, 16],
+            _ => return Result::Err(format!("Unknown color type {}", color_type)),
+        };
+
+        if !depths.contains(&bit_depth) {
+            return Result::Err(format!(
+                "Bit depth {} is not allowed for color type {}",
+                bit_depth, color_type
+            ));
+        }
+
+        if data[10] != 0 || data[11] != 0 {
+            return Result::Err("Unknown compression or filter method".to_string());
+        }
+
+        if data[12] > 1 {
+            return Result::Err(format!("Unknown interlace method {}", data[12]));
+        }
+
+        return Result::Ok(Self {
+            width,
+            height,
+            bit_depth,
+            color_type,
+            interlace: data[12] == 1,
+        });
+    }
+
+    fn channels(&self) -> usize {
+        return match self.color_type {
+            2 => 3,
+            4 => 2,
+            6 => 4,
+            _ => 1,
+        };
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        return self.channels() * self.bit_depth as usize;
+    }
+
+    /// Bytes of one row without the filter type byte
+    fn row_length(&self, width: usize) -> usize {
+        return (width * self.bits_per_pixel()).div_ceil(8);
+    }
+
+    /// Bytes of filtered rows of the whole image, of every pass for interlaced one
+    fn data_length(&self) -> usize {
+        let passes = if self.interlace {
+            &ADAM7_PASSES[..]
+        } else {
+            &[(0, 0, 1, 1)][..]
+        };
+
+        return passes
+            .iter()
+            .filter(|(x0, y0, _, _)| self.width > *x0 && self.height > *y0)
+            .map(|(x0, y0, step_x, step_y)| {
+                let width = (self.width - x0).div_ceil(*step_x);
+                let height = (self.height - y0).div_ceil(*step_y);
+
+                height.saturating_mul(self.row_length(width) + 1)
+            })
+            .fold(0, usize::saturating_add);
+    }
+
+    fn sample(&self, row: &[u8], index: usize) -> u16 {
+        return match self.bit_depth {
+            16 => u16::from_be_bytes([row[index * 2], row[index * 2 + 1]]),
+            8 => row[index] as u16,
+            depth => {
+                let bit = index * depth as usize;
+                let shift = 8 - depth as usize - bit % 8;
+
+                ((row[bit / 8] >> shift) & ((1 << depth) - 1)) as u16
+            }
+        };
+    }
+
+    fn pixel(&self, row: &[u8], x: usize, palette: &[Vec3]) -> Result<Vec3, String> {
+        let channels = self.channels();
+        let max = ((1_u32 << self.bit_depth) - 1) as f32;
+        let value = |channel: usize| self.sample(row, x * channels + channel) as f32 / max;
+
+        return match self.color_type {
+            0 | 4 => Result::Ok(Vec3::from_element(value(0))),
+            2 | 6 => Result::Ok(Vec3::new(value(0), value(1), value(2))),
+            _ => {
+                let index = self.sample(row, x) as usize;
+
+                palette
+                    .get(index)
+                    .copied()
+                    .ok_or_else(|| format!("Palette index {} is out of range", index))
+            }
+        };
+    }
+}
+
+fn read_chunk<'a>(bytes: &'a [u8], position: &mut usize) -> Result<([u8; 4], &'a [u8]), String> {
+    let start = *position;
+    let header = bytes
+        .get(start..start + 8)
+        .ok_or_else(|| "Unexpected end of file".to_string())?;
+    let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let chunk_type = [header[4], header[5], header[6], header[7]];
+    let data = bytes
+        .get(start + 8..start + 8 + length)
+        .ok_or_else(|| "Unexpected end of file".to_string())?;
+
+    // Chunk data is followed by CRC which is not checked
+    *position = start + 12 + length;
+
+    return Result::Ok((chunk_type, data));
+}
+
+/// Reverses per row filters of image or Adam7 pass with given size
+fn unfilter(
+    header: &Header,
+    data: &[u8],
+    width: usize,
+    height: usize,
+) -> Result<Vec<Vec<u8>>, String> {
+    let length = header.row_length(width);
+    // Filters work on bytes of the previous pixel, rounded up to a whole byte
+    let step = header.bits_per_pixel().div_ceil(8);
+    let mut rows: Vec<Vec<u8>> = Vec::with_capacity(height);
+    let empty = vec![0_u8; length];
+
+    for y in 0..height {
+        let start = y * (length + 1);
+        let encoded = data
+            .get(start..start + length + 1)
+            .ok_or_else(|| "Image data is too short".to_string())?;
+        let filter = encoded[0];
+        let mut row = encoded[1..].to_vec();
+        let previous = rows.last().unwrap_or(&empty);
+
+        for i in 0..length {
+            let left = if i >= step { row[i - step] } else { 0 };
+            let up = previous[i];
+            let up_left = if i >= step { previous[i - step] } else { 0 };
+
+            let prediction = match filter {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => paeth(left, up, up_left),
+                _ => return Result::Err(format!("Unknown filter type {} in row {}", filter, y)),
+            };
+
+            row[i] = row[i].wrapping_add(prediction);
+        }
+
+        rows.push(row);
+    }
+
+    return Result::Ok(rows);
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = left as i16 + up as i16 - up_left as i16;
+    let distance_left = (estimate - left as i16).abs();
+    let distance_up = (estimate - up as i16).abs();
+    let distance_up_left = (estimate - up_left as i16).abs();
+
+    if distance_left <= distance_up && distance_left <= distance_up_left {
+        return left;
+    }
+
+    if distance_up <= distance_up_left {
+        return up;
+    }
+
+    return up_left;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut bytes = (data.len() as u32).to_be_bytes().to_vec();
+
+        bytes.extend_from_slice(chunk_type);
+        bytes.extend_from_slice(data);
+        // CRC is not checked
+        bytes.extend_from_slice(&[0; 4]);
+
+        return bytes;
+    }
+
+    /// 8-bit RGB image with its filtered rows in one stored deflate block
+    fn png(width: u32, height: u32, rows: &[u8]) -> Vec<u8> {
+        let mut header = width.to_be_bytes().to_vec();
+
+        header.extend_from_slice(&height.to_be_bytes());
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let length = rows.len() as u16;
+        let mut compressed = vec![0x78, 0x01, 0x01];
+
+        compressed.extend_from_slice(&length.to_le_bytes());
+        compressed.extend_from_slice(&(!length).to_le_bytes());
+        compressed.extend_from_slice(rows);
+        // Adler-32 is not checked
+        compressed.extend_from_slice(&[0; 4]);
+
+        let mut bytes = SIGNATURE.to_vec();
+
+        bytes.extend(chunk(b"IHDR", &header));
+        bytes.extend(chunk(b"IDAT", &compressed));
+        bytes.extend(chunk(b"IEND", &[]));
+
+        return bytes;
+    }
+
+    fn two_by_two() -> Vec<u8> {
+        // Second row is filtered by the one above it
+        return png(2, 2, &[0, 255, 0, 0, 0, 255, 0, 2, 0, 0, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn decodes_filtered_rows() {
+        let image = decode(&two_by_two()).unwrap();
+
+        assert_eq!(image.get_resolution(), IVec2::new(2, 2));
+        assert_eq!(*image.get_pixel(0, 0), Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(*image.get_pixel(1, 0), Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(*image.get_pixel(0, 1), Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(*image.get_pixel(1, 1), Vec3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn truncated_file_is_error() {
+        let bytes = two_by_two();
+
+        // Without the last 4 bytes only the unchecked CRC of the end chunk is missing
+        for length in 0..bytes.len() - 4 {
+            assert!(decode(&bytes[..length]).is_err(), "length {}", length);
+        }
+    }
+
+    #[test]
+    fn oversized_header_is_error() {
+        let mut bytes = two_by_two();
+        let header = SIGNATURE.len() + 8;
+
+        bytes[header..header + 8].copy_from_slice(&[0, 1, 0, 0, 0, 1, 0, 0]);
+        assert!(decode(&bytes).is_err());
+
+        bytes[header..header + 8].copy_from_slice(&[0xff; 8]);
+        assert!(decode(&bytes).is_err());
+    }
+}
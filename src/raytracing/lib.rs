@@ -4,7 +4,10 @@
 pub mod examples;
 pub mod hittables;
 pub mod image;
+pub mod import;
+pub mod lights;
 pub mod materials;
 pub mod misc;
 pub mod scene;
+pub mod textures;
 pub mod thread_pool;
@@ -0,0 +1,77 @@
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::hittables::hittable::Hittable;
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+use crate::materials::material::MaterialRc;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+const DISTANCE_TOLERANCE: f32 = 1e-3;
+
+/// Emissive hittable lighting the scene through points sampled on its surface
+pub struct AreaLight {
+    hittable: Box<dyn Hittable>,
+    material: MaterialRc,
+}
+
+impl AreaLight {
+    pub fn new(hittable: Box<dyn Hittable>, material: MaterialRc) -> Self {
+        return Self { hittable, material };
+    }
+
+    pub fn boxed(hittable: Box<dyn Hittable>, material: MaterialRc) -> Box<Self> {
+        return Box::new(Self::new(hittable, material));
+    }
+}
+
+impl AreaLight {
+    pub fn get_hittable(&self) -> &dyn Hittable {
+        return self.hittable.as_ref();
+    }
+
+    pub fn get_material(&self) -> &MaterialRc {
+        return &self.material;
+    }
+}
+
+impl Light for AreaLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        let surface_sample = self.hittable.sample(point, u)?;
+        let to_light = surface_sample.get_point() - point;
+        let distance = to_light.magnitude();
+        let direction = to_light / distance;
+        let ray = Ray::no_norm(*point, direction);
+        let mut record = HitRecord::default();
+
+        record.set_distance(distance);
+        record.set_point(*surface_sample.get_point());
+        record.set_face_normal(&ray, *surface_sample.get_normal());
+        record.set_material(self.material.clone());
+
+        let radiance = self.material.borrow().emitted(&ray, &record);
+
+        return Option::Some(LightSample::new(
+            direction,
+            distance,
+            radiance,
+            surface_sample.get_pdf(),
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        let mut record = HitRecord::default();
+
+        // Lights hidden behind the thing seen could not have lit the point
+        if !self.hittable.hit(
+            &Ray::no_norm(*point, *direction),
+            0.0,
+            distance * (1.0 + DISTANCE_TOLERANCE),
+            &mut record,
+        ) {
+            return 0.0;
+        }
+
+        return self.hittable.pdf(point, direction);
+    }
+}
@@ -0,0 +1,101 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+use crate::misc::utils::{one_minus_cos, sample_cone};
+
+/// Infinitely far light like the sun, arriving from a single direction
+/// or from a small disk of directions
+pub struct DirectionalLight {
+    direction: Vec3,
+    // Radiance inside the disk, or irradiance without one
+    radiance: Vec3,
+    // One minus cosine of the angular radius of the disk, zero without the disk
+    cone: f32,
+}
+
+impl DirectionalLight {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `direction`: Direction towards the light
+    /// * `color`:
+    /// * `power`: Power per unit area arriving at a surface facing the light
+    /// * `angle`: Angular diameter of the light in degrees, 0 for perfectly sharp shadows
+    pub fn new(direction: Vec3, color: Vec3, power: f32, angle: f32) -> Self {
+        let half_angle = (angle * 0.5).to_radians();
+        let sin_half_angle = half_angle.sin();
+        let irradiance = color * power;
+
+        let radiance = if angle > 0.0 {
+            irradiance / (PI * sin_half_angle * sin_half_angle)
+        } else {
+            irradiance
+        };
+
+        return Self {
+            direction: direction.normalize(),
+            radiance,
+            cone: one_minus_cos(half_angle),
+        };
+    }
+
+    pub fn boxed(direction: Vec3, color: Vec3, power: f32, angle: f32) -> Box<Self> {
+        return Box::new(Self::new(direction, color, power, angle));
+    }
+}
+
+impl DirectionalLight {
+    pub fn get_direction(&self) -> &Vec3 {
+        return &self.direction;
+    }
+
+    fn is_in_disk(&self, direction: &Vec3) -> bool {
+        return self.cone > 0.0 && 1.0 - direction.dot(&self.direction) <= self.cone;
+    }
+}
+
+impl Light for DirectionalLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        if self.is_delta() {
+            return Option::Some(LightSample::new(
+                self.direction,
+                f32::INFINITY,
+                self.radiance,
+                1.0,
+            ));
+        }
+
+        let direction = sample_cone(&self.direction, self.cone, u);
+
+        return Option::Some(LightSample::new(
+            direction,
+            f32::INFINITY,
+            self.radiance,
+            1.0 / (2.0 * PI * self.cone),
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        if distance.is_finite() || !self.is_in_disk(direction) {
+            return 0.0;
+        }
+
+        return 1.0 / (2.0 * PI * self.cone);
+    }
+
+    fn is_delta(&self) -> bool {
+        return self.cone <= 0.0;
+    }
+
+    fn background(&self, direction: &Vec3) -> Vec3 {
+        if !self.is_in_disk(direction) {
+            return Vec3::zeros();
+        }
+
+        return self.radiance;
+    }
+}
@@ -0,0 +1,71 @@
+use std::f32::consts::PI;
+use std::sync::Arc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::environment_map::{direction_to_uv, uv_to_direction, EnvironmentMap};
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+
+/// Light arriving from infinitely far away, looked up in an environment map
+pub struct EnvironmentLight {
+    map: Arc<EnvironmentMap>,
+    rotation: f32,
+    intensity: f32,
+}
+
+impl EnvironmentLight {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `map`: Environment map with z up
+    /// * `rotation`: Rotation around z axis in degrees
+    /// * `intensity`: Multiplier of the map radiance
+    pub fn new(map: Arc<EnvironmentMap>, rotation: f32, intensity: f32) -> Self {
+        return Self {
+            map,
+            rotation: rotation.to_radians(),
+            intensity,
+        };
+    }
+
+    pub fn boxed(map: Arc<EnvironmentMap>, rotation: f32, intensity: f32) -> Box<Self> {
+        return Box::new(Self::new(map, rotation, intensity));
+    }
+}
+
+impl Light for EnvironmentLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        let (uv, uv_pdf) = self.map.sample(u);
+        let (direction, sin_theta) = uv_to_direction(&uv, self.rotation);
+
+        if uv_pdf <= 0.0 || sin_theta <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(LightSample::new(
+            direction,
+            f32::INFINITY,
+            self.map.lookup(&uv) * self.intensity,
+            uv_pdf / (2.0 * PI * PI * sin_theta),
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        let (uv, sin_theta) = direction_to_uv(direction, self.rotation);
+
+        if distance.is_finite() || sin_theta <= 0.0 {
+            return 0.0;
+        }
+
+        return self.map.pdf(&uv) / (2.0 * PI * PI * sin_theta);
+    }
+
+    fn background(&self, direction: &Vec3) -> Vec3 {
+        return self
+            .map
+            .lookup(&direction_to_uv(direction, self.rotation).0)
+            * self.intensity;
+    }
+}
@@ -0,0 +1,98 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::image::linear_image::LinearImage;
+use crate::misc::color::luminance;
+use crate::misc::distribution_2d::Distribution2d;
+
+/// Equirectangular image around the scene with z up, together with the distribution
+/// for sampling its bright parts. Contains no materials, so it can be shared between threads
+pub struct EnvironmentMap {
+    image: LinearImage,
+    distribution: Distribution2d,
+}
+
+impl EnvironmentMap {
+    pub fn new(image: LinearImage) -> Self {
+        let resolution = image.get_resolution();
+        let (width, height) = (resolution.x as usize, resolution.y as usize);
+        let mut weights = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            // Rows near the poles cover less of the sphere
+            let sin_theta = (PI * (y as f32 + 0.5) / height as f32).sin();
+
+            for x in 0..width {
+                weights.push(luminance(image.get_pixel(x as i32, y as i32)).max(0.0) * sin_theta);
+            }
+        }
+
+        let distribution = Distribution2d::new(&weights, width, height);
+
+        return Self {
+            image,
+            distribution,
+        };
+    }
+}
+
+impl EnvironmentMap {
+    pub fn get_image(&self) -> &LinearImage {
+        return &self.image;
+    }
+
+    /// Radiance at image coordinates in [0, 1)², without filtering to match the sampling density
+    pub fn lookup(&self, uv: &Vec2) -> Vec3 {
+        let resolution = self.image.get_resolution();
+
+        return *self.image.get_pixel(
+            (uv.x * resolution.x as f32) as i32,
+            (uv.y * resolution.y as f32) as i32,
+        );
+    }
+
+    /// Picks image coordinates with density proportional to luminance
+    ///
+    /// returns: (Vec2, f32) Image coordinates and their density over [0, 1)²
+    pub fn sample(&self, u: &Vec2) -> (Vec2, f32) {
+        return self.distribution.sample(u);
+    }
+
+    /// Density of `sample` picking image coordinates `uv`
+    pub fn pdf(&self, uv: &Vec2) -> f32 {
+        return self.distribution.pdf(uv);
+    }
+}
+
+/// Equirectangular image coordinates of `direction` and sine of its angle to the z axis
+///
+/// # Arguments
+///
+/// * `direction`: Unit direction
+/// * `rotation`: Rotation of the image around z axis in radians
+///
+/// returns: (Vec2, f32)
+pub fn direction_to_uv(direction: &Vec3, rotation: f32) -> (Vec2, f32) {
+    let cos_theta = direction.z.clamp(-1.0, 1.0);
+    let phi = direction.y.atan2(direction.x) - rotation;
+    let u = (phi / (2.0 * PI)).rem_euclid(1.0);
+    let v = cos_theta.acos() / PI;
+
+    return (
+        Vec2::new(u, v),
+        (1.0 - cos_theta * cos_theta).max(0.0).sqrt(),
+    );
+}
+
+/// Inverse of `direction_to_uv`
+pub fn uv_to_direction(uv: &Vec2, rotation: f32) -> (Vec3, f32) {
+    let phi = uv.x * 2.0 * PI + rotation;
+    let theta = uv.y * PI;
+    let sin_theta = theta.sin();
+
+    return (
+        Vec3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), theta.cos()),
+        sin_theta,
+    );
+}
@@ -0,0 +1,37 @@
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::light_sample::LightSample;
+
+pub trait Light {
+    /// Samples light arriving at `point`
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: Point being lit
+    /// * `u`: Two uniform random numbers in [0, 1)
+    ///
+    /// returns: None if no light arrives from the chosen direction
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample>;
+
+    /// Solid angle density with which `sample` picks `direction` from `point`
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: Point being lit
+    /// * `direction`: Unit direction from `point`
+    /// * `distance`: Distance to the first thing seen along `direction`, infinite if nothing
+    ///
+    /// returns: f32 Zero if the light is not what is seen at `distance`
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32;
+
+    /// Lights at a single point or in a single direction can only be reached by sampling them,
+    /// rays leaving a surface never find them
+    fn is_delta(&self) -> bool {
+        return false;
+    }
+
+    /// Radiance arriving from infinitely far away along `direction`, seen by rays that miss the scene
+    fn background(&self, direction: &Vec3) -> Vec3 {
+        return Vec3::zeros();
+    }
+}
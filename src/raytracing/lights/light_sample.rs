@@ -0,0 +1,46 @@
+use nalgebra_glm::Vec3;
+
+/// Direction towards a light together with the radiance it sends along it
+pub struct LightSample {
+    direction: Vec3,
+    distance: f32,
+    radiance: Vec3,
+    pdf: f32,
+}
+
+impl LightSample {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `direction`: Unit direction from the lit point towards the light
+    /// * `distance`: Distance to the light, anything closer occludes it
+    /// * `radiance`: Radiance arriving along `direction`
+    /// * `pdf`: Density of `direction` with respect to solid angle
+    pub fn new(direction: Vec3, distance: f32, radiance: Vec3, pdf: f32) -> Self {
+        return Self {
+            direction,
+            distance,
+            radiance,
+            pdf,
+        };
+    }
+}
+
+impl LightSample {
+    pub fn get_direction(&self) -> &Vec3 {
+        return &self.direction;
+    }
+
+    pub fn get_distance(&self) -> f32 {
+        return self.distance;
+    }
+
+    pub fn get_radiance(&self) -> &Vec3 {
+        return &self.radiance;
+    }
+
+    pub fn get_pdf(&self) -> f32 {
+        return self.pdf;
+    }
+}
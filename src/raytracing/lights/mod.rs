@@ -0,0 +1,10 @@
+pub mod area_light;
+pub mod directional_light;
+pub mod environment_light;
+pub mod environment_map;
+pub mod light;
+pub mod light_sample;
+pub mod point_light;
+pub mod preetham_sky;
+pub mod sky_light;
+pub mod spot_light;
@@ -0,0 +1,71 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+
+/// Infinitely small light shining equally in every direction, fading with squared distance
+pub struct PointLight {
+    position: Vec3,
+    intensity: Vec3,
+}
+
+impl PointLight {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `position`:
+    /// * `color`:
+    /// * `power`: Total power spread over the whole sphere of directions
+    pub fn new(position: Vec3, color: Vec3, power: f32) -> Self {
+        return Self {
+            position,
+            intensity: color * power / (4.0 * PI),
+        };
+    }
+
+    pub fn boxed(position: Vec3, color: Vec3, power: f32) -> Box<Self> {
+        return Box::new(Self::new(position, color, power));
+    }
+}
+
+impl PointLight {
+    pub fn get_position(&self) -> &Vec3 {
+        return &self.position;
+    }
+
+    /// Power per unit solid angle
+    pub fn get_intensity(&self) -> &Vec3 {
+        return &self.intensity;
+    }
+}
+
+impl Light for PointLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        let to_light = self.position - point;
+        let distance_squared = to_light.magnitude_squared();
+
+        if distance_squared <= 0.0 {
+            return Option::None;
+        }
+
+        let distance = distance_squared.sqrt();
+
+        return Option::Some(LightSample::new(
+            to_light / distance,
+            distance,
+            self.intensity / distance_squared,
+            1.0,
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        return 0.0;
+    }
+
+    fn is_delta(&self) -> bool {
+        return true;
+    }
+}
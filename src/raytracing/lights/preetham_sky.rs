@@ -0,0 +1,149 @@
+use nalgebra_glm::Vec3;
+
+// Perez distribution coefficients as linear functions of turbidity, a * turbidity + b
+const LUMINANCE_COEFFICIENTS: [(f32, f32); 5] = [
+    (0.1787, -1.4630),
+    (-0.3554, 0.4275),
+    (-0.0227, 5.3251),
+    (0.1206, -2.5771),
+    (-0.0670, 0.3703),
+];
+const X_COEFFICIENTS: [(f32, f32); 5] = [
+    (-0.0193, -0.2592),
+    (-0.0665, 0.0008),
+    (-0.0004, 0.2125),
+    (-0.0641, -0.8989),
+    (-0.0033, 0.0452),
+];
+const Y_COEFFICIENTS: [(f32, f32); 5] = [
+    (-0.0167, -0.2608),
+    (-0.0950, 0.0092),
+    (-0.0079, 0.2102),
+    (-0.0441, -1.6537),
+    (-0.0109, 0.0529),
+];
+
+// Wavelengths in micrometers standing for red, green and blue when attenuating sunlight
+const WAVELENGTHS: Vec3 = Vec3::new(0.680, 0.550, 0.440);
+
+/// Clear sky from "A Practical Analytic Model for Daylight" by Preetham, Shirley and Smits.
+/// Radiance is in kcd/m² with z up and is meaningful only for the sun above the horizon
+pub struct PreethamSky {
+    sun_direction: Vec3,
+    turbidity: f32,
+    // Perez coefficients for luminance and the two chromaticity coordinates
+    coefficients: [[f32; 5]; 3],
+    // Zenith values divided by the Perez function at zenith
+    zenith: Vec3,
+}
+
+impl PreethamSky {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `sun_direction`: Direction towards the sun, z up
+    /// * `turbidity`: Haziness of the atmosphere, from 2 for very clear to 10 for hazy
+    pub fn new(sun_direction: &Vec3, turbidity: f32) -> Self {
+        let sun_direction = sun_direction.normalize();
+        let theta_sun = sun_direction.z.clamp(0.0, 1.0).acos();
+
+        let coefficients = [LUMINANCE_COEFFICIENTS, X_COEFFICIENTS, Y_COEFFICIENTS]
+            .map(|channel| channel.map(|(a, b)| a * turbidity + b));
+
+        let chi = (4.0 / 9.0 - turbidity / 120.0) * (std::f32::consts::PI - 2.0 * theta_sun);
+        let zenith_luminance =
+            (4.0453 * turbidity - 4.9710) * chi.tan() - 0.2155 * turbidity + 2.4192;
+
+        let theta = Vec3::new(theta_sun.powi(3), theta_sun.powi(2), theta_sun);
+        let turbidity_powers = Vec3::new(turbidity * turbidity, turbidity, 1.0);
+        let zenith_x = turbidity_powers.dot(&Vec3::new(
+            theta.dot(&Vec3::new(0.00166, -0.00375, 0.00209)),
+            theta.dot(&Vec3::new(-0.02903, 0.06377, -0.03202)) + 0.00394,
+            theta.dot(&Vec3::new(0.11693, -0.21196, 0.06052)) + 0.25886,
+        ));
+        let zenith_y = turbidity_powers.dot(&Vec3::new(
+            theta.dot(&Vec3::new(0.00275, -0.00610, 0.00317)),
+            theta.dot(&Vec3::new(-0.04214, 0.08970, -0.04153)) + 0.00516,
+            theta.dot(&Vec3::new(0.15346, -0.26756, 0.06670)) + 0.26688,
+        ));
+
+        let zenith = Vec3::new(zenith_luminance, zenith_x, zenith_y);
+        let perez_zenith = Vec3::from_fn(|i, _| perez(&coefficients[i], 1.0, theta_sun));
+
+        return Self {
+            sun_direction,
+            turbidity,
+            coefficients,
+            zenith: zenith.component_div(&perez_zenith),
+        };
+    }
+}
+
+impl PreethamSky {
+    pub fn get_sun_direction(&self) -> &Vec3 {
+        return &self.sun_direction;
+    }
+
+    pub fn get_turbidity(&self) -> f32 {
+        return self.turbidity;
+    }
+
+    /// Linear RGB radiance of the sky without the sun disk, directions below the horizon
+    /// see the sky at the horizon
+    pub fn radiance(&self, direction: &Vec3) -> Vec3 {
+        let cos_theta = direction.z.max(1e-3);
+        let gamma = direction.dot(&self.sun_direction).clamp(-1.0, 1.0).acos();
+
+        let value = Vec3::from_fn(|i, _| perez(&self.coefficients[i], cos_theta, gamma))
+            .component_mul(&self.zenith);
+
+        return xyy_to_rgb(value.x, value.y, value.z).map(|x| x.max(0.0));
+    }
+
+    /// Fraction of sunlight per RGB channel left after Rayleigh and aerosol scattering
+    pub fn sun_transmittance(&self) -> Vec3 {
+        let cos_theta = self.sun_direction.z.clamp(0.0, 1.0);
+        let theta_degrees = cos_theta.acos().to_degrees();
+        // Relative optical mass of the air the sunlight crosses
+        let air_mass = 1.0 / (cos_theta + 0.15 * (93.885 - theta_degrees).powf(-1.253));
+        let angstrom_beta = 0.04608 * self.turbidity - 0.04586;
+
+        return WAVELENGTHS.map(|wavelength| {
+            let rayleigh = 0.008735 * wavelength.powf(-4.08);
+            let aerosol = angstrom_beta * wavelength.powf(-1.3);
+
+            (-(rayleigh + aerosol) * air_mass).exp()
+        });
+    }
+}
+
+/// Perez sky distribution function
+///
+/// # Arguments
+///
+/// * `coefficients`: A to E
+/// * `cos_theta`: Cosine of the angle to zenith
+/// * `gamma`: Angle to the sun in radians
+fn perez(coefficients: &[f32; 5], cos_theta: f32, gamma: f32) -> f32 {
+    let [a, b, c, d, e] = *coefficients;
+    let cos_gamma = gamma.cos();
+
+    return (1.0 + a * (b / cos_theta).exp())
+        * (1.0 + c * (d * gamma).exp() + e * cos_gamma * cos_gamma);
+}
+
+/// Converts luminance with chromaticity to linear Rec. 709 RGB
+fn xyy_to_rgb(luminance: f32, x: f32, y: f32) -> Vec3 {
+    if y <= 0.0 {
+        return Vec3::zeros();
+    }
+
+    let xyz = Vec3::new(x * luminance / y, luminance, (1.0 - x - y) * luminance / y);
+
+    return Vec3::new(
+        Vec3::new(3.2406, -1.5372, -0.4986).dot(&xyz),
+        Vec3::new(-0.9689, 1.8758, 0.0415).dot(&xyz),
+        Vec3::new(0.0557, -0.2040, 1.0570).dot(&xyz),
+    );
+}
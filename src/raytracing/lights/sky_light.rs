@@ -0,0 +1,217 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::environment_map::{direction_to_uv, uv_to_direction};
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+use crate::lights::preetham_sky::PreethamSky;
+use crate::misc::color::luminance;
+use crate::misc::distribution_2d::Distribution2d;
+use crate::misc::utils::{one_minus_cos, sample_cone};
+
+// Resolution of the table the sky is sampled from
+const TABLE_WIDTH: usize = 128;
+const TABLE_HEIGHT: usize = 64;
+
+// Luminance of the sun seen from outside the atmosphere in kcd/m² and its angular diameter
+const SUN_LUMINANCE: f32 = 2.0e6;
+const SUN_SIZE: f32 = 0.53;
+
+/// Daylight sky with optional sun disk and uniformly lit ground below the horizon
+pub struct SkyLight {
+    sky: PreethamSky,
+    ground_radiance: Vec3,
+    sun_radiance: Vec3,
+    // One minus cosine of the angular radius of the sun disk, zero without the disk
+    sun_cone: f32,
+    sun_probability: f32,
+    distribution: Distribution2d,
+    intensity: f32,
+}
+
+impl SkyLight {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `sun_direction`: Direction towards the sun, z up
+    /// * `turbidity`: Haziness of the atmosphere, from 2 for very clear to 10 for hazy
+    /// * `ground_albedo`: Color of the ground lit by the sky and the sun
+    /// * `sun_size`: Angular diameter of the sun disk in degrees, 0 for no disk.
+    ///   Bigger disks give softer shadows with the same amount of sunlight
+    /// * `intensity`: Multiplier of the radiance in kcd/m²
+    pub fn new(
+        sun_direction: &Vec3,
+        turbidity: f32,
+        ground_albedo: &Vec3,
+        sun_size: f32,
+        intensity: f32,
+    ) -> Self {
+        let sky = PreethamSky::new(sun_direction, turbidity);
+        let sun_cone = one_minus_cos((sun_size * 0.5).to_radians());
+        let sun_radiance = if sun_cone > 0.0 {
+            sky.sun_transmittance() * SUN_LUMINANCE * one_minus_cos((SUN_SIZE * 0.5).to_radians())
+                / sun_cone
+        } else {
+            Vec3::zeros()
+        };
+
+        // Sky values at the centers of the table cells, rows from zenith to nadir
+        let cell_solid_angle = 2.0 * PI * PI / (TABLE_WIDTH * TABLE_HEIGHT) as f32;
+        let mut radiances = Vec::with_capacity(TABLE_WIDTH * TABLE_HEIGHT);
+        let mut irradiance = Vec3::zeros();
+
+        for y in 0..TABLE_HEIGHT {
+            for x in 0..TABLE_WIDTH {
+                let uv = Vec2::new(
+                    (x as f32 + 0.5) / TABLE_WIDTH as f32,
+                    (y as f32 + 0.5) / TABLE_HEIGHT as f32,
+                );
+                let (direction, sin_theta) = uv_to_direction(&uv, 0.0);
+
+                if direction.z > 0.0 {
+                    let radiance = sky.radiance(&direction);
+
+                    irradiance += radiance * direction.z * sin_theta * cell_solid_angle;
+                    radiances.push(radiance);
+                } else {
+                    radiances.push(Vec3::zeros());
+                }
+            }
+        }
+
+        let sun_solid_angle = 2.0 * PI * sun_cone;
+        irradiance += sun_radiance * sun_solid_angle * sky.get_sun_direction().z.max(0.0);
+
+        let ground_radiance = ground_albedo.component_mul(&irradiance) / PI;
+
+        let weights: Vec<f32> = radiances
+            .iter()
+            .enumerate()
+            .map(|(i, radiance)| {
+                let uv = Vec2::new(0.0, ((i / TABLE_WIDTH) as f32 + 0.5) / TABLE_HEIGHT as f32);
+                let (direction, sin_theta) = uv_to_direction(&uv, 0.0);
+                let radiance = if direction.z > 0.0 {
+                    radiance
+                } else {
+                    &ground_radiance
+                };
+
+                luminance(radiance) * sin_theta
+            })
+            .collect();
+
+        let sky_power: f32 = weights.iter().sum::<f32>() * cell_solid_angle;
+        let sun_power = luminance(&sun_radiance) * sun_solid_angle;
+        let sun_probability = if sun_power > 0.0 {
+            sun_power / (sun_power + sky_power)
+        } else {
+            0.0
+        };
+
+        return Self {
+            sky,
+            ground_radiance,
+            sun_radiance,
+            sun_cone,
+            sun_probability,
+            distribution: Distribution2d::new(&weights, TABLE_WIDTH, TABLE_HEIGHT),
+            intensity,
+        };
+    }
+
+    pub fn boxed(
+        sun_direction: &Vec3,
+        turbidity: f32,
+        ground_albedo: &Vec3,
+        sun_size: f32,
+        intensity: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            sun_direction,
+            turbidity,
+            ground_albedo,
+            sun_size,
+            intensity,
+        ));
+    }
+}
+
+impl SkyLight {
+    fn is_in_sun(&self, direction: &Vec3) -> bool {
+        return self.sun_cone > 0.0
+            && 1.0 - direction.dot(self.sky.get_sun_direction()) <= self.sun_cone;
+    }
+
+    /// Density of `sample` picking `direction`, combining the sky table and the sun disk
+    fn direction_pdf(&self, direction: &Vec3) -> f32 {
+        let (uv, sin_theta) = direction_to_uv(direction, 0.0);
+        let mut pdf = 0.0;
+
+        if sin_theta > 0.0 {
+            pdf += (1.0 - self.sun_probability) * self.distribution.pdf(&uv)
+                / (2.0 * PI * PI * sin_theta);
+        }
+
+        if self.is_in_sun(direction) {
+            pdf += self.sun_probability / (2.0 * PI * self.sun_cone);
+        }
+
+        return pdf;
+    }
+}
+
+impl Light for SkyLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        let direction = if u.x < self.sun_probability {
+            sample_cone(
+                self.sky.get_sun_direction(),
+                self.sun_cone,
+                &Vec2::new(u.x / self.sun_probability, u.y),
+            )
+        } else {
+            let remapped = (u.x - self.sun_probability) / (1.0 - self.sun_probability);
+            let (uv, _) = self
+                .distribution
+                .sample(&Vec2::new(remapped.min(1.0 - f32::EPSILON), u.y));
+
+            uv_to_direction(&uv, 0.0).0
+        };
+
+        let pdf = self.direction_pdf(&direction);
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(LightSample::new(
+            direction,
+            f32::INFINITY,
+            self.background(&direction),
+            pdf,
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        if distance.is_finite() {
+            return 0.0;
+        }
+
+        return self.direction_pdf(direction);
+    }
+
+    fn background(&self, direction: &Vec3) -> Vec3 {
+        let mut radiance = if direction.z > 0.0 {
+            self.sky.radiance(direction)
+        } else {
+            self.ground_radiance
+        };
+
+        if self.is_in_sun(direction) {
+            radiance += self.sun_radiance;
+        }
+
+        return radiance * self.intensity;
+    }
+}
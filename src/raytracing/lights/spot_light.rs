@@ -0,0 +1,120 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::lights::light::Light;
+use crate::lights::light_sample::LightSample;
+
+/// Point light shining only inside a cone, fading out towards the cone side
+pub struct SpotLight {
+    position: Vec3,
+    direction: Vec3,
+    intensity: Vec3,
+    cos_outer: f32,
+    cos_inner: f32,
+}
+
+impl SpotLight {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `position`:
+    /// * `direction`: Direction the light shines in
+    /// * `color`:
+    /// * `power`: Power of a point light with the same brightness inside the cone
+    /// * `angle`: Angle between opposite sides of the cone in degrees
+    /// * `softness`: Part of the cone from 0 to 1, measured from its side, over which light fades
+    pub fn new(
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+        softness: f32,
+    ) -> Self {
+        let half_angle = (angle * 0.5).to_radians();
+
+        return Self {
+            position,
+            direction: direction.normalize(),
+            intensity: color * power / (4.0 * PI),
+            cos_outer: half_angle.cos(),
+            cos_inner: (half_angle * (1.0 - softness)).cos(),
+        };
+    }
+
+    pub fn boxed(
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+        softness: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            position, direction, color, power, angle, softness,
+        ));
+    }
+}
+
+impl SpotLight {
+    pub fn get_position(&self) -> &Vec3 {
+        return &self.position;
+    }
+
+    pub fn get_direction(&self) -> &Vec3 {
+        return &self.direction;
+    }
+
+    /// Part of the light left in `direction` leaving the light
+    fn falloff(&self, direction: &Vec3) -> f32 {
+        let cos_theta = direction.dot(&self.direction);
+
+        if cos_theta <= self.cos_outer {
+            return 0.0;
+        }
+
+        if cos_theta >= self.cos_inner {
+            return 1.0;
+        }
+
+        let t = (cos_theta - self.cos_outer) / (self.cos_inner - self.cos_outer);
+
+        return t * t * (3.0 - 2.0 * t);
+    }
+}
+
+impl Light for SpotLight {
+    fn sample(&self, point: &Vec3, u: &Vec2) -> Option<LightSample> {
+        let to_light = self.position - point;
+        let distance_squared = to_light.magnitude_squared();
+
+        if distance_squared <= 0.0 {
+            return Option::None;
+        }
+
+        let distance = distance_squared.sqrt();
+        let direction = to_light / distance;
+        let falloff = self.falloff(&-direction);
+
+        if falloff <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(LightSample::new(
+            direction,
+            distance,
+            self.intensity * falloff / distance_squared,
+            1.0,
+        ));
+    }
+
+    fn pdf(&self, point: &Vec3, direction: &Vec3, distance: f32) -> f32 {
+        return 0.0;
+    }
+
+    fn is_delta(&self) -> bool {
+        return true;
+    }
+}
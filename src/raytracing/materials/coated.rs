@@ -0,0 +1,255 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nalgebra_glm::{reflect_vec, Vec2, Vec3};
+
+use crate::materials::fresnel::fresnel_dielectric;
+use crate::materials::ggx::Ggx;
+use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::ray::Ray;
+use crate::textures::texture::TextureRc;
+
+/// Thin dielectric layer like varnish over another material.
+/// Light not reflected by the layer reaches the base and leaves through the layer again,
+/// changes of direction inside the layer are ignored
+pub struct Coated {
+    base: MaterialRc,
+    color: TextureRc,
+    index_of_refraction: f32,
+    ggx: Ggx,
+    rand_generator: RandGenRc,
+}
+
+impl Coated {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: Material under the layer
+    /// * `color`: Tint of light passing through the layer to the base and back
+    /// * `index_of_refraction`: Of the layer
+    /// * `ggx`: Distribution of microfacets of the layer surface
+    /// * `rand_generator`:
+    pub fn new(
+        base: MaterialRc,
+        color: TextureRc,
+        index_of_refraction: f32,
+        ggx: Ggx,
+        rand_generator: RandGenRc,
+    ) -> Self {
+        return Self {
+            base,
+            color,
+            index_of_refraction,
+            ggx,
+            rand_generator,
+        };
+    }
+
+    pub fn new_rc(
+        base: MaterialRc,
+        color: TextureRc,
+        index_of_refraction: f32,
+        ggx: Ggx,
+        rand_generator: RandGenRc,
+    ) -> MaterialRc {
+        return Rc::new(RefCell::new(Box::new(Self::new(
+            base,
+            color,
+            index_of_refraction,
+            ggx,
+            rand_generator,
+        ))));
+    }
+}
+
+impl Coated {
+    /// Shading frame on the side the ray came from and direction towards the viewer in it
+    fn get_frame(ray: &Ray, hit_record: &HitRecord) -> (Frame, Vec3) {
+        let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
+            -*hit_record.get_normal()
+        } else {
+            *hit_record.get_normal()
+        };
+        let frame = Frame::new(&corrected_normal);
+        let outgoing = frame.to_local(&-ray.get_direction());
+
+        return (frame, outgoing);
+    }
+
+    fn get_reflectance(&self, cosine: f32) -> f32 {
+        return fresnel_dielectric(cosine.abs(), self.index_of_refraction);
+    }
+
+    /// Tint and loss of light passing through the layer in both directions
+    fn get_transmittance(&self, hit_record: &HitRecord, outgoing: &Vec3, incoming: &Vec3) -> Vec3 {
+        return self
+            .color
+            .value(hit_record.get_uv(), hit_record.get_point())
+            * ((1.0 - self.get_reflectance(outgoing.z))
+                * (1.0 - self.get_reflectance(incoming.z)));
+    }
+}
+
+impl Material for Coated {
+    /// Reflects from the layer with the chance given by the Fresnel equations,
+    /// scatters from the base otherwise
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+
+        if outgoing.z <= 0.0 {
+            return Option::None;
+        }
+
+        let (choice, u) = {
+            let mut rand_generator = RefCell::borrow_mut(&self.rand_generator);
+
+            (
+                rand_generator.uniform(),
+                Vec2::new(rand_generator.uniform(), rand_generator.uniform()),
+            )
+        };
+        let reflectance = self.get_reflectance(outgoing.z);
+
+        let direction = if choice < reflectance {
+            if self.ggx.is_smooth() {
+                return Option::Some(ScatterRecord::specular(
+                    Ray::new(
+                        *hit_record.get_point(),
+                        frame.to_world(&Vec3::new(-outgoing.x, -outgoing.y, outgoing.z)),
+                    ),
+                    Vec3::from_element(1.0),
+                ));
+            }
+
+            let microfacet_normal = self.ggx.sample_visible_normal(&outgoing, &u);
+            let incoming = reflect_vec(&-outgoing, &microfacet_normal);
+
+            // Reflections going under the surface are shadowed by other microfacets
+            if incoming.z <= 0.0 {
+                return Option::None;
+            }
+
+            frame.to_world(&incoming)
+        } else {
+            let scatter_record = self.base.borrow_mut().scatter(ray, hit_record)?;
+            let incoming = frame.to_local(scatter_record.get_scattered().get_direction());
+
+            // Chance of passing the layer cancels out its transmittance towards the viewer
+            if scatter_record.is_specular() {
+                let attenuation = scatter_record
+                    .get_attenuation()
+                    .component_mul(&self.get_transmittance(hit_record, &outgoing, &incoming))
+                    / (1.0 - reflectance);
+
+                return Option::Some(ScatterRecord::specular(
+                    scatter_record.into_scattered(),
+                    attenuation,
+                ));
+            }
+
+            *scatter_record.get_scattered().get_direction()
+        };
+
+        let pdf = self.pdf(ray, hit_record, &direction);
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), direction),
+            self.eval(ray, hit_record, &direction) / pdf,
+            pdf,
+        ));
+    }
+
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .base
+            .borrow()
+            .get_attenuation(hit_record)
+            .component_mul(
+                &self
+                    .color
+                    .value(hit_record.get_uv(), hit_record.get_point()),
+            );
+    }
+
+    fn emitted(&self, ray: &Ray, hit_record: &HitRecord) -> Vec3 {
+        let (_, outgoing) = Self::get_frame(ray, hit_record);
+
+        return self.base.borrow().emitted(ray, hit_record).component_mul(
+            &self
+                .color
+                .value(hit_record.get_uv(), hit_record.get_point()),
+        ) * (1.0 - self.get_reflectance(outgoing.z));
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 {
+            return Vec3::zeros();
+        }
+
+        let mut result = Vec3::zeros();
+
+        if !self.ggx.is_smooth() && incoming.z > 0.0 {
+            let microfacet_normal = (outgoing + incoming).normalize();
+
+            result.add_scalar_mut(
+                self.get_reflectance(outgoing.dot(&microfacet_normal))
+                    * self.ggx.distribution(&microfacet_normal)
+                    * self.ggx.masking_shadowing(&outgoing, &incoming)
+                    / (4.0 * outgoing.z),
+            );
+        }
+
+        let base = self.base.borrow();
+
+        if !base.is_specular() {
+            result += base
+                .eval(ray, hit_record, direction)
+                .component_mul(&self.get_transmittance(hit_record, &outgoing, &incoming));
+        }
+
+        return result;
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 {
+            return 0.0;
+        }
+
+        let reflectance = self.get_reflectance(outgoing.z);
+        let mut result = 0.0;
+
+        if !self.ggx.is_smooth() && incoming.z > 0.0 {
+            let microfacet_normal = (outgoing + incoming).normalize();
+
+            result += reflectance * self.ggx.visible_normal_pdf(&outgoing, &microfacet_normal)
+                / (4.0 * outgoing.dot(&microfacet_normal));
+        }
+
+        let base = self.base.borrow();
+
+        if !base.is_specular() {
+            result += (1.0 - reflectance) * base.pdf(ray, hit_record, direction);
+        }
+
+        return result;
+    }
+
+    fn is_specular(&self) -> bool {
+        return self.ggx.is_smooth() && self.base.borrow().is_specular();
+    }
+}
@@ -0,0 +1,144 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nalgebra_glm::{reflect_vec, Vec2, Vec3};
+
+use crate::materials::fresnel::fresnel_conductor;
+use crate::materials::ggx::Ggx;
+use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::ray::Ray;
+
+/// Metal with microfacet roughness and color coming from its complex index of refraction
+pub struct Conductor {
+    eta: Vec3,
+    k: Vec3,
+    ggx: Ggx,
+    rand_generator: RandGenRc,
+}
+
+impl Conductor {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `eta`: Real part of the index of refraction per channel
+    /// * `k`: Extinction coefficient per channel
+    /// * `ggx`: Distribution of microfacets, anisotropic ones use world x as the tangent
+    /// * `rand_generator`:
+    pub fn new(eta: Vec3, k: Vec3, ggx: Ggx, rand_generator: RandGenRc) -> Self {
+        return Self {
+            eta,
+            k,
+            ggx,
+            rand_generator,
+        };
+    }
+
+    pub fn new_rc(eta: Vec3, k: Vec3, ggx: Ggx, rand_generator: RandGenRc) -> MaterialRc {
+        return Rc::new(RefCell::new(Box::new(Self::new(
+            eta,
+            k,
+            ggx,
+            rand_generator,
+        ))));
+    }
+}
+
+impl Conductor {
+    /// Shading frame on the side the ray came from and direction towards the viewer in it
+    fn get_frame(ray: &Ray, hit_record: &HitRecord) -> (Frame, Vec3) {
+        let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
+            -*hit_record.get_normal()
+        } else {
+            *hit_record.get_normal()
+        };
+        let frame = Frame::with_tangent(&corrected_normal, &Vec3::x());
+        let outgoing = frame.to_local(&-ray.get_direction());
+
+        return (frame, outgoing);
+    }
+}
+
+impl Material for Conductor {
+    /// Reflects around microfacet normal picked among the ones visible from the ray
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+
+        if self.ggx.is_smooth() {
+            return Option::Some(ScatterRecord::specular(
+                Ray::new(
+                    *hit_record.get_point(),
+                    frame.to_world(&Vec3::new(-outgoing.x, -outgoing.y, outgoing.z)),
+                ),
+                fresnel_conductor(outgoing.z, &self.eta, &self.k),
+            ));
+        }
+
+        let u = {
+            let mut rand_generator = RefCell::borrow_mut(&self.rand_generator);
+
+            Vec2::new(rand_generator.uniform(), rand_generator.uniform())
+        };
+        let microfacet_normal = self.ggx.sample_visible_normal(&outgoing, &u);
+        let incoming = reflect_vec(&-outgoing, &microfacet_normal);
+
+        // Reflections going under the surface are shadowed by other microfacets
+        if incoming.z <= 0.0 {
+            return Option::None;
+        }
+
+        let cosine = outgoing.dot(&microfacet_normal);
+        let fresnel = fresnel_conductor(cosine, &self.eta, &self.k);
+        let weight = self.ggx.masking_shadowing(&outgoing, &incoming) / self.ggx.masking(&outgoing);
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), frame.to_world(&incoming)),
+            fresnel * weight,
+            self.ggx.visible_normal_pdf(&outgoing, &microfacet_normal) / (4.0 * cosine),
+        ));
+    }
+
+    /// Reflectance at normal incidence
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return fresnel_conductor(1.0, &self.eta, &self.k);
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 || incoming.z <= 0.0 {
+            return Vec3::zeros();
+        }
+
+        let microfacet_normal = (outgoing + incoming).normalize();
+        let fresnel = fresnel_conductor(outgoing.dot(&microfacet_normal), &self.eta, &self.k);
+
+        return fresnel
+            * (self.ggx.distribution(&microfacet_normal)
+                * self.ggx.masking_shadowing(&outgoing, &incoming)
+                / (4.0 * outgoing.z));
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 || incoming.z <= 0.0 {
+            return 0.0;
+        }
+
+        let microfacet_normal = (outgoing + incoming).normalize();
+
+        return self.ggx.visible_normal_pdf(&outgoing, &microfacet_normal)
+            / (4.0 * outgoing.dot(&microfacet_normal));
+    }
+
+    fn is_specular(&self) -> bool {
+        return self.ggx.is_smooth();
+    }
+}
@@ -4,6 +4,7 @@ use std::rc::Rc;
 use nalgebra_glm::Vec3;
 
 use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
 
@@ -23,19 +24,15 @@ impl Emission {
 }
 
 impl Material for Emission {
-    fn scatter(
-        &mut self,
-        ray: &Ray,
-        hit_record: &HitRecord,
-        attenuation: &mut Vec3,
-        scattered: &mut Ray,
-    ) -> bool {
-        *attenuation = self.get_attenuation();
-
-        return false;
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        return Option::None;
     }
 
-    fn get_attenuation(&self) -> Vec3 {
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
         return self.color * self.power;
     }
+
+    fn emitted(&self, ray: &Ray, hit_record: &HitRecord) -> Vec3 {
+        return self.get_attenuation(hit_record);
+    }
 }
@@ -0,0 +1,53 @@
+use nalgebra_glm::Vec3;
+
+/// Reflectance of a metal for unpolarized light
+///
+/// # Arguments
+///
+/// * `cos_theta`: Cosine between the incoming direction and the normal
+/// * `eta`: Real part of the index of refraction per channel
+/// * `k`: Extinction coefficient per channel
+///
+/// returns: Part of the light reflected per channel
+pub fn fresnel_conductor(cos_theta: f32, eta: &Vec3, k: &Vec3) -> Vec3 {
+    let cos2 = cos_theta.clamp(0.0, 1.0).powi(2);
+    let sin2 = 1.0 - cos2;
+
+    return Vec3::from_fn(|i, _| {
+        let (eta, k) = (eta[i], k[i]);
+        let t0 = eta * eta - k * k - sin2;
+        let a2_plus_b2 = (t0 * t0 + 4.0 * eta * eta * k * k).sqrt();
+        let t1 = a2_plus_b2 + cos2;
+        let a = (0.5 * (a2_plus_b2 + t0)).max(0.0).sqrt();
+        let t2 = 2.0 * cos_theta * a;
+        let perpendicular = (t1 - t2) / (t1 + t2);
+        let t3 = cos2 * a2_plus_b2 + sin2 * sin2;
+        let t4 = t2 * sin2;
+        let parallel = perpendicular * (t3 - t4) / (t3 + t4);
+
+        0.5 * (perpendicular + parallel)
+    });
+}
+
+/// Reflectance of a boundary between two transparent media for unpolarized light
+///
+/// # Arguments
+///
+/// * `cos_theta`: Cosine between the incoming direction and the normal on its side
+/// * `eta`: Index of refraction on the other side divided by the one on the incoming side
+///
+/// returns: Part of the light reflected, 1 for total internal reflection
+pub fn fresnel_dielectric(cos_theta: f32, eta: f32) -> f32 {
+    let cos_i = cos_theta.clamp(0.0, 1.0);
+    let sin2_t = (1.0 - cos_i * cos_i) / (eta * eta);
+
+    if sin2_t >= 1.0 {
+        return 1.0;
+    }
+
+    let cos_t = (1.0 - sin2_t).sqrt();
+    let parallel = (eta * cos_i - cos_t) / (eta * cos_i + cos_t);
+    let perpendicular = (cos_i - eta * cos_t) / (cos_i + eta * cos_t);
+
+    return 0.5 * (parallel * parallel + perpendicular * perpendicular);
+}
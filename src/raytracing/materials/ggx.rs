@@ -0,0 +1,167 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+// Below this roughness surfaces are treated as perfectly smooth
+const MIN_ALPHA: f32 = 1e-3;
+
+/// Trowbridge-Reitz microfacet distribution, directions are in a frame where the normal is z
+#[derive(Clone, Copy)]
+pub struct Ggx {
+    alpha_x: f32,
+    alpha_y: f32,
+}
+
+impl Ggx {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha_x`: Width of the distribution along x
+    /// * `alpha_y`: Width of the distribution along y
+    pub fn new(alpha_x: f32, alpha_y: f32) -> Self {
+        return Self {
+            alpha_x: alpha_x.max(MIN_ALPHA),
+            alpha_y: alpha_y.max(MIN_ALPHA),
+        };
+    }
+
+    /// Distribution from artist friendly parameters, squared roughness looks more linear
+    ///
+    /// # Arguments
+    ///
+    /// * `roughness`: From 0 for a mirror to 1
+    /// * `anisotropy`: From 0 for equal roughness in every direction to 1 for rougher along x
+    pub fn from_roughness(roughness: f32, anisotropy: f32) -> Self {
+        let alpha = roughness * roughness;
+        let aspect = (1.0 - 0.9 * anisotropy).sqrt();
+
+        return Self::new(alpha / aspect, alpha * aspect);
+    }
+
+    /// Whether the surface is so smooth it is better treated as a perfect mirror
+    pub fn is_smooth(&self) -> bool {
+        return self.alpha_x.max(self.alpha_y) <= MIN_ALPHA;
+    }
+
+    /// Density of microfacet normals `normal` per projected area
+    pub fn distribution(&self, normal: &Vec3) -> f32 {
+        if normal.z <= 0.0 {
+            return 0.0;
+        }
+
+        let x = normal.x / self.alpha_x;
+        let y = normal.y / self.alpha_y;
+        let term = x * x + y * y + normal.z * normal.z;
+
+        return 1.0 / (PI * self.alpha_x * self.alpha_y * term * term);
+    }
+
+    fn lambda(&self, direction: &Vec3) -> f32 {
+        let z2 = direction.z * direction.z;
+
+        if z2 <= 0.0 {
+            return f32::INFINITY;
+        }
+
+        let x = direction.x * self.alpha_x;
+        let y = direction.y * self.alpha_y;
+
+        return 0.5 * ((1.0 + (x * x + y * y) / z2).sqrt() - 1.0);
+    }
+
+    /// Part of microfacets visible from `direction`
+    pub fn masking(&self, direction: &Vec3) -> f32 {
+        return 1.0 / (1.0 + self.lambda(direction));
+    }
+
+    /// Part of microfacets visible from both directions, with height correlation
+    pub fn masking_shadowing(&self, outgoing: &Vec3, incoming: &Vec3) -> f32 {
+        return 1.0 / (1.0 + self.lambda(outgoing) + self.lambda(incoming));
+    }
+
+    /// Picks microfacet normal seen from `outgoing`, weighted by its visible area
+    ///
+    /// # Arguments
+    ///
+    /// * `outgoing`: Unit direction towards the viewer, above the surface
+    /// * `u`: Two uniform random numbers in [0, 1)
+    ///
+    /// returns: Unit microfacet normal, picked with density `visible_normal_pdf`
+    pub fn sample_visible_normal(&self, outgoing: &Vec3, u: &Vec2) -> Vec3 {
+        // Stretches the view so the distribution becomes a hemisphere of unit radius
+        let view = Vec3::new(
+            self.alpha_x * outgoing.x,
+            self.alpha_y * outgoing.y,
+            outgoing.z,
+        )
+        .normalize();
+        let length_squared = view.x * view.x + view.y * view.y;
+        let first = if length_squared > 0.0 {
+            Vec3::new(-view.y, view.x, 0.0) / length_squared.sqrt()
+        } else {
+            Vec3::x()
+        };
+        let second = view.cross(&first);
+
+        // Uniform point on the projected disk, half of which is squeezed by the view angle
+        let radius = u.x.sqrt();
+        let phi = 2.0 * PI * u.y;
+        let t1 = radius * phi.cos();
+        let s = 0.5 * (1.0 + view.z);
+        let t2 = (1.0 - s) * (1.0 - t1 * t1).max(0.0).sqrt() + s * radius * phi.sin();
+        let hemisphere =
+            first * t1 + second * t2 + view * (1.0 - t1 * t1 - t2 * t2).max(0.0).sqrt();
+
+        return Vec3::new(
+            self.alpha_x * hemisphere.x,
+            self.alpha_y * hemisphere.y,
+            hemisphere.z.max(0.0),
+        )
+        .normalize();
+    }
+
+    /// Density of `sample_visible_normal` picking `normal` with respect to solid angle
+    pub fn visible_normal_pdf(&self, outgoing: &Vec3, normal: &Vec3) -> f32 {
+        if outgoing.z <= 0.0 {
+            return 0.0;
+        }
+
+        return self.masking(outgoing) * outgoing.dot(normal).max(0.0) * self.distribution(normal)
+            / outgoing.z;
+    }
+}
+
+/// Microfacet normal turning `outgoing` into `incoming` by reflection or refraction
+///
+/// # Arguments
+///
+/// * `outgoing`: Unit direction towards the viewer, above the surface
+/// * `incoming`: Unit direction, below the surface for refraction
+/// * `eta`: Index of refraction below the surface divided by the one above it
+///
+/// returns: None if no microfacet facing both directions does it
+pub fn microfacet_normal(outgoing: &Vec3, incoming: &Vec3, eta: f32) -> Option<Vec3> {
+    let half = if incoming.z > 0.0 {
+        outgoing + incoming
+    } else {
+        outgoing + incoming * eta
+    };
+
+    if half.magnitude_squared() == 0.0 {
+        return Option::None;
+    }
+
+    let normal = if half.z < 0.0 {
+        -half.normalize()
+    } else {
+        half.normalize()
+    };
+
+    // Microfacets seen from behind cannot take part
+    if outgoing.dot(&normal) <= 0.0 || incoming.dot(&normal) * incoming.z <= 0.0 {
+        return Option::None;
+    }
+
+    return Option::Some(normal);
+}
@@ -1,69 +1,89 @@
 use std::cell::RefCell;
+use std::f32::consts::PI;
 use std::rc::Rc;
 
 use nalgebra_glm::Vec3;
 
 use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::rand_gen::RandGenRc;
 use crate::misc::ray::Ray;
+use crate::textures::texture::TextureRc;
 
 pub struct Lambertian {
-    albedo: Vec3,
+    albedo: TextureRc,
     rand_generator: RandGenRc,
 }
 
 impl Lambertian {
-    pub fn new(albedo: Vec3, rand_generator: RandGenRc) -> Self {
+    pub fn new(albedo: TextureRc, rand_generator: RandGenRc) -> Self {
         return Lambertian {
             albedo,
             rand_generator,
         };
     }
 
-    pub fn new_rc(albedo: Vec3, rand_generator: RandGenRc) -> MaterialRc {
+    pub fn new_rc(albedo: TextureRc, rand_generator: RandGenRc) -> MaterialRc {
         return Rc::new(RefCell::new(Box::new(Self::new(albedo, rand_generator))));
     }
 }
 
-impl Material for Lambertian {
-    fn scatter(
-        &mut self,
-        ray: &Ray,
-        hit_record: &HitRecord,
-        attenuation: &mut Vec3,
-        scattered: &mut Ray,
-    ) -> bool {
-        let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
+impl Lambertian {
+    /// Normal on the side the ray came from
+    fn correct_normal(ray: &Ray, hit_record: &HitRecord) -> Vec3 {
+        return if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
             -*hit_record.get_normal()
         } else {
             *hit_record.get_normal()
         };
+    }
+}
+
+impl Material for Lambertian {
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let corrected_normal = Self::correct_normal(ray, hit_record);
+
+        // Offsetting the normal by a unit vector gives cosine-weighted directions
+        let unit_vector = RefCell::borrow_mut(&self.rand_generator)
+            .unit_sphere()
+            .normalize();
+        let target = corrected_normal + unit_vector;
+
+        let scatter_direction = if target.magnitude_squared() > 1e-8 {
+            target.normalize()
+        } else {
+            corrected_normal
+        };
 
-        let target = RefCell::borrow_mut(&self.rand_generator).unit_hemisphere(&corrected_normal);
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), scatter_direction),
+            self.get_attenuation(hit_record),
+            Vec3::dot(&corrected_normal, &scatter_direction).max(0.0) / PI,
+        ));
+    }
 
-        // let target;
-        //
-        // if unit_sphere_sample.dot(hit_record.get_normal()) > 0.0 {
-        //     target = -unit_sphere_sample;
-        // } else {
-        //     target = unit_sphere_sample;
-        // }
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .albedo
+            .value(hit_record.get_uv(), hit_record.get_point());
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let cosine = Vec3::dot(&Self::correct_normal(ray, hit_record), direction);
 
-        let scatter_direction = (target).normalize();
-        *scattered = Ray::new(*hit_record.get_point(), scatter_direction);
-        *attenuation = self.get_attenuation();
-        // *attenuation = hit_record.get_normal();
+        if cosine <= 0.0 {
+            return Vec3::zeros();
+        }
 
-        return true;
+        return self.get_attenuation(hit_record) * (cosine / PI);
+    }
 
-        // auto scatter_direction = rec.normal + random_unit_vector();
-        // scattered = ray(rec.p, scatter_direction);
-        // attenuation = albedo;
-        // return true;
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        return Vec3::dot(&Self::correct_normal(ray, hit_record), direction).max(0.0) / PI;
     }
 
-    fn get_attenuation(&self) -> Vec3 {
-        return self.albedo;
+    fn is_specular(&self) -> bool {
+        return false;
     }
 }
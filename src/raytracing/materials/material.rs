@@ -3,19 +3,49 @@ use std::rc::Rc;
 
 use nalgebra_glm::Vec3;
 
+use crate::materials::scatter_record::ScatterRecord;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
 
 pub type MaterialRc = Rc<RefCell<Box<dyn Material>>>;
 
 pub trait Material {
-    fn scatter(
-        &mut self,
-        ray: &Ray,
-        hit_record: &HitRecord,
-        attenuation: &mut Vec3,
-        scattered: &mut Ray,
-    ) -> bool;
-
-    fn get_attenuation(&self) -> Vec3;
+    /// Picks direction the light continues in
+    ///
+    /// # Arguments
+    ///
+    /// * `ray`: Ray that hit the surface
+    /// * `hit_record`:
+    ///
+    /// returns: None if the light is absorbed
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord>;
+
+    /// Color of the surface at the hit point
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3;
+
+    /// Light emitted from the hit point back along `ray`
+    fn emitted(&self, ray: &Ray, hit_record: &HitRecord) -> Vec3 {
+        return Vec3::zeros();
+    }
+
+    /// Scattering function times cosine for light arriving from `direction`, used by light sampling
+    ///
+    /// # Arguments
+    ///
+    /// * `ray`: Ray that hit the surface
+    /// * `hit_record`:
+    /// * `direction`: Unit direction towards the light
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        return Vec3::zeros();
+    }
+
+    /// Solid angle density with which `scatter` picks `direction`, specular directions excluded
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        return 0.0;
+    }
+
+    /// Whether only directions chosen by `scatter` carry light, so lights are not sampled directly
+    fn is_specular(&self) -> bool {
+        return true;
+    }
 }
@@ -0,0 +1,51 @@
+use nalgebra_glm::Vec3;
+
+/// Measured complex indices of refraction of common metals, sampled at 650, 550 and 450 nm
+#[derive(Clone, Copy, PartialEq)]
+pub enum Metal {
+    Aluminum,
+    Copper,
+    Gold,
+    Silver,
+}
+
+impl Metal {
+    /// Real part of the index of refraction per channel
+    pub fn get_eta(&self) -> Vec3 {
+        return match self {
+            Metal::Aluminum => Vec3::new(1.65746, 0.880369, 0.521229),
+            Metal::Copper => Vec3::new(0.200438, 0.924033, 1.102212),
+            Metal::Gold => Vec3::new(0.143119, 0.374957, 1.442479),
+            Metal::Silver => Vec3::new(0.155265, 0.116723, 0.138342),
+        };
+    }
+
+    /// Extinction coefficient per channel
+    pub fn get_k(&self) -> Vec3 {
+        return match self {
+            Metal::Aluminum => Vec3::new(9.223869, 6.269523, 4.837001),
+            Metal::Copper => Vec3::new(3.912949, 2.452848, 2.142188),
+            Metal::Gold => Vec3::new(3.98316, 2.385721, 1.603215),
+            Metal::Silver => Vec3::new(4.828181, 3.122249, 2.146961),
+        };
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            Metal::Aluminum => "aluminum",
+            Metal::Copper => "copper",
+            Metal::Gold => "gold",
+            Metal::Silver => "silver",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "aluminum" => Option::Some(Metal::Aluminum),
+            "copper" => Option::Some(Metal::Copper),
+            "gold" => Option::Some(Metal::Gold),
+            "silver" => Option::Some(Metal::Silver),
+            _ => Option::None,
+        };
+    }
+}
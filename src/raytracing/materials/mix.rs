@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nalgebra_glm::Vec3;
+
+use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::ray::Ray;
+use crate::textures::texture::TextureRc;
+
+/// Blend of two materials, every ray scatters from one of them picked at random
+pub struct Mix {
+    first: MaterialRc,
+    second: MaterialRc,
+    factor: TextureRc,
+    rand_generator: RandGenRc,
+}
+
+impl Mix {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `first`:
+    /// * `second`:
+    /// * `factor`: Share of `second`, read as the mean of the channels and clamped to [0, 1]
+    /// * `rand_generator`:
+    pub fn new(
+        first: MaterialRc,
+        second: MaterialRc,
+        factor: TextureRc,
+        rand_generator: RandGenRc,
+    ) -> Self {
+        return Self {
+            first,
+            second,
+            factor,
+            rand_generator,
+        };
+    }
+
+    pub fn new_rc(
+        first: MaterialRc,
+        second: MaterialRc,
+        factor: TextureRc,
+        rand_generator: RandGenRc,
+    ) -> MaterialRc {
+        return Rc::new(RefCell::new(Box::new(Self::new(
+            first,
+            second,
+            factor,
+            rand_generator,
+        ))));
+    }
+}
+
+impl Mix {
+    /// Both materials with their shares at the hit point
+    fn get_parts(&self, hit_record: &HitRecord) -> [(&MaterialRc, f32); 2] {
+        let factor = self
+            .factor
+            .value(hit_record.get_uv(), hit_record.get_point())
+            .mean()
+            .clamp(0.0, 1.0);
+
+        return [(&self.first, 1.0 - factor), (&self.second, factor)];
+    }
+}
+
+impl Material for Mix {
+    /// Picks material by its share, directions either of them could pick
+    /// are weighted by both of them
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let [(first, share), (second, _)] = self.get_parts(hit_record);
+        let chosen = if RefCell::borrow_mut(&self.rand_generator).uniform() < share {
+            first
+        } else {
+            second
+        };
+        let scatter_record = chosen.borrow_mut().scatter(ray, hit_record)?;
+
+        // Chance of picking the material cancels out its share
+        if scatter_record.is_specular() {
+            return Option::Some(scatter_record);
+        }
+
+        let direction = scatter_record.get_scattered().get_direction();
+        let pdf = self.pdf(ray, hit_record, direction);
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), *direction),
+            self.eval(ray, hit_record, direction) / pdf,
+            pdf,
+        ));
+    }
+
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .get_parts(hit_record)
+            .iter()
+            .map(|(material, share)| material.borrow().get_attenuation(hit_record) * *share)
+            .sum();
+    }
+
+    fn emitted(&self, ray: &Ray, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .get_parts(hit_record)
+            .iter()
+            .map(|(material, share)| material.borrow().emitted(ray, hit_record) * *share)
+            .sum();
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        return self
+            .get_parts(hit_record)
+            .iter()
+            .filter(|(material, _)| !material.borrow().is_specular())
+            .map(|(material, share)| material.borrow().eval(ray, hit_record, direction) * *share)
+            .sum();
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        return self
+            .get_parts(hit_record)
+            .iter()
+            .filter(|(material, _)| !material.borrow().is_specular())
+            .map(|(material, share)| material.borrow().pdf(ray, hit_record, direction) * share)
+            .sum();
+    }
+
+    fn is_specular(&self) -> bool {
+        return self.first.borrow().is_specular() && self.second.borrow().is_specular();
+    }
+}
@@ -1,5 +1,13 @@
+pub mod coated;
+pub mod conductor;
 pub mod emission;
+pub mod fresnel;
+pub mod ggx;
 pub mod lambertian;
 pub mod material;
+pub mod metal;
+pub mod mix;
+pub mod principled;
 pub mod reflective;
 pub mod refractive;
+pub mod scatter_record;
@@ -0,0 +1,396 @@
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+use nalgebra_glm::{reflect_vec, Vec2, Vec3};
+
+use crate::materials::fresnel::fresnel_dielectric;
+use crate::materials::ggx::{microfacet_normal, Ggx};
+use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
+use crate::misc::frame::Frame;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::ray::Ray;
+use crate::misc::utils::refract;
+use crate::textures::texture::TextureRc;
+
+// Clear coat is a thin glossy varnish with the reflectance of an index of refraction of 1.5
+const CLEARCOAT_ROUGHNESS: f32 = 0.2;
+const CLEARCOAT_REFLECTANCE: f32 = 0.04;
+// Without refraction rough transmission would become a single direction
+const MIN_INDEX_OF_REFRACTION: f32 = 1.01;
+
+/// Disney-style material blending diffuse, dielectric and metallic reflection, clear coat,
+/// sheen and rough glass-like transmission. Every parameter except the color is read from
+/// the mean of its texture and clamped to [0, 1]
+pub struct Principled {
+    base_color: TextureRc,
+    metallic: TextureRc,
+    roughness: TextureRc,
+    specular: TextureRc,
+    clearcoat: TextureRc,
+    sheen: TextureRc,
+    transmission: TextureRc,
+    rand_generator: RandGenRc,
+}
+
+impl Principled {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `base_color`: Diffuse color, reflection color of metals and transmission tint
+    /// * `metallic`: From 0 for dielectrics to 1 for metals
+    /// * `roughness`: From 0 for mirror-like reflection to 1
+    /// * `specular`: Reflectance of dielectrics at normal incidence, 0.5 is 4%
+    /// * `clearcoat`: Strength of the clear coat layer
+    /// * `sheen`: Strength of the grazing reflection of cloth
+    /// * `transmission`: Part of dielectric light passing through the surface
+    /// * `rand_generator`:
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        base_color: TextureRc,
+        metallic: TextureRc,
+        roughness: TextureRc,
+        specular: TextureRc,
+        clearcoat: TextureRc,
+        sheen: TextureRc,
+        transmission: TextureRc,
+        rand_generator: RandGenRc,
+    ) -> Self {
+        return Self {
+            base_color,
+            metallic,
+            roughness,
+            specular,
+            clearcoat,
+            sheen,
+            transmission,
+            rand_generator,
+        };
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_rc(
+        base_color: TextureRc,
+        metallic: TextureRc,
+        roughness: TextureRc,
+        specular: TextureRc,
+        clearcoat: TextureRc,
+        sheen: TextureRc,
+        transmission: TextureRc,
+        rand_generator: RandGenRc,
+    ) -> MaterialRc {
+        return Rc::new(RefCell::new(Box::new(Self::new(
+            base_color,
+            metallic,
+            roughness,
+            specular,
+            clearcoat,
+            sheen,
+            transmission,
+            rand_generator,
+        ))));
+    }
+}
+
+/// Parameters at one hit point
+struct Surface {
+    base_color: Vec3,
+    metallic: f32,
+    roughness: f32,
+    reflectance: f32,
+    clearcoat: f32,
+    sheen: f32,
+    transmission: f32,
+    ggx: Ggx,
+    clearcoat_ggx: Ggx,
+    // Index of refraction on the other side divided by the one on the side of the ray
+    eta: f32,
+}
+
+impl Surface {
+    fn get_diffuse_weight(&self) -> f32 {
+        return (1.0 - self.metallic) * (1.0 - self.transmission);
+    }
+
+    fn get_glass_weight(&self) -> f32 {
+        return (1.0 - self.metallic) * self.transmission;
+    }
+
+    /// Part of the light the dielectric specular layer lets through to the diffuse base
+    fn get_diffuse_transmittance(&self, cosine: f32) -> f32 {
+        return 1.0 - schlick(&Vec3::from_element(self.reflectance), cosine).x;
+    }
+
+    /// Reflectance of dielectric and metallic layers together, without glass
+    fn get_specular_fresnel(&self, cosine: f32) -> Vec3 {
+        return schlick(&Vec3::from_element(self.reflectance), cosine) * self.get_diffuse_weight()
+            + schlick(&self.base_color, cosine) * self.metallic;
+    }
+
+    /// Chances of sampling diffuse, specular, glass and clear coat lobes
+    fn get_lobe_weights(&self, outgoing: &Vec3) -> [f32; 4] {
+        let specular = self.get_specular_fresnel(outgoing.z).mean();
+        let clearcoat =
+            self.clearcoat * schlick(&Vec3::from_element(CLEARCOAT_REFLECTANCE), outgoing.z).x;
+        let weights = [
+            self.get_diffuse_weight() * self.get_diffuse_transmittance(outgoing.z),
+            specular,
+            self.get_glass_weight(),
+            clearcoat,
+        ];
+        let sum: f32 = weights.iter().sum();
+
+        if sum <= 0.0 {
+            return [0.0; 4];
+        }
+
+        return weights.map(|x| x / sum);
+    }
+
+    /// Scattering function times cosine, directions are in the shading frame
+    fn eval(&self, outgoing: &Vec3, incoming: &Vec3) -> Vec3 {
+        if incoming.z < 0.0 {
+            return self.eval_transmission(outgoing, incoming);
+        }
+
+        let half = match microfacet_normal(outgoing, incoming, self.eta) {
+            Some(half) => half,
+            None => return Vec3::zeros(),
+        };
+        let cos_d = incoming.dot(&half);
+
+        // Burley diffuse darkens or brightens grazing angles depending on roughness
+        let diffuse_90 = 0.5 + 2.0 * self.roughness * cos_d * cos_d;
+        let retro_reflection = (1.0 + (diffuse_90 - 1.0) * (1.0 - incoming.z).powi(5))
+            * (1.0 + (diffuse_90 - 1.0) * (1.0 - outgoing.z).powi(5));
+        let diffuse = self.base_color * (retro_reflection / PI)
+            + Vec3::from_element(self.sheen * (1.0 - cos_d).powi(5));
+
+        let microfacets = self.ggx.distribution(&half)
+            * self.ggx.masking_shadowing(outgoing, incoming)
+            / (4.0 * outgoing.z);
+        let fresnel = self.get_specular_fresnel(cos_d).add_scalar(
+            self.get_glass_weight() * fresnel_dielectric(outgoing.dot(&half), self.eta),
+        );
+
+        let clearcoat = self.clearcoat
+            * schlick(&Vec3::from_element(CLEARCOAT_REFLECTANCE), cos_d).x
+            * self.clearcoat_ggx.distribution(&half)
+            * self.clearcoat_ggx.masking_shadowing(outgoing, incoming)
+            / (4.0 * outgoing.z);
+
+        // Light reflected by the specular layer never reaches the diffuse base, the direction
+        // closer to grazing reflects more and bounds what passes both ways
+        let transmittance = self.get_diffuse_transmittance(incoming.z.min(outgoing.z));
+
+        return diffuse * (self.get_diffuse_weight() * transmittance * incoming.z)
+            + fresnel * microfacets
+            + Vec3::from_element(clearcoat);
+    }
+
+    fn eval_transmission(&self, outgoing: &Vec3, incoming: &Vec3) -> Vec3 {
+        let glass_weight = self.get_glass_weight();
+
+        if glass_weight <= 0.0 {
+            return Vec3::zeros();
+        }
+
+        let half = match microfacet_normal(outgoing, incoming, self.eta) {
+            Some(half) => half,
+            None => return Vec3::zeros(),
+        };
+        let cosine = outgoing.dot(&half);
+        let denominator = incoming.dot(&half) + cosine / self.eta;
+        let transmitted = (1.0 - fresnel_dielectric(cosine, self.eta))
+            * self.ggx.distribution(&half)
+            * self.ggx.masking_shadowing(outgoing, incoming)
+            * cosine
+            * incoming.dot(&half).abs()
+            / (outgoing.z * denominator * denominator * self.eta * self.eta);
+
+        return self.base_color * (glass_weight * transmitted);
+    }
+
+    /// Density of picking `incoming` by `sample`, directions are in the shading frame
+    fn pdf(&self, outgoing: &Vec3, incoming: &Vec3) -> f32 {
+        let [diffuse, specular, glass, clearcoat] = self.get_lobe_weights(outgoing);
+        let half = match microfacet_normal(outgoing, incoming, self.eta) {
+            Some(half) => half,
+            None => return 0.0,
+        };
+        let cosine = outgoing.dot(&half);
+        let reflectance = fresnel_dielectric(cosine, self.eta);
+        let normal_pdf = self.ggx.visible_normal_pdf(outgoing, &half);
+
+        if incoming.z < 0.0 {
+            let denominator = incoming.dot(&half) + cosine / self.eta;
+
+            return glass * (1.0 - reflectance) * normal_pdf * incoming.dot(&half).abs()
+                / (denominator * denominator);
+        }
+
+        return diffuse * incoming.z / PI
+            + (specular + glass * reflectance) * normal_pdf / (4.0 * cosine)
+            + clearcoat * self.clearcoat_ggx.visible_normal_pdf(outgoing, &half) / (4.0 * cosine);
+    }
+
+    /// Picks direction from one of the lobes
+    ///
+    /// # Arguments
+    ///
+    /// * `outgoing`: Direction towards the viewer in the shading frame
+    /// * `u`: Four uniform random numbers in [0, 1)
+    ///
+    /// returns: None if the direction would go under the surface
+    fn sample(&self, outgoing: &Vec3, u: &[f32; 4]) -> Option<Vec3> {
+        let [diffuse, specular, glass, _] = self.get_lobe_weights(outgoing);
+        let position = Vec2::new(u[1], u[2]);
+
+        if u[0] < diffuse {
+            // Uniform point on the disk lifted to the hemisphere gives cosine-weighted directions
+            let radius = position.x.sqrt();
+            let phi = 2.0 * PI * position.y;
+
+            return Option::Some(Vec3::new(
+                radius * phi.cos(),
+                radius * phi.sin(),
+                (1.0 - position.x).max(0.0).sqrt(),
+            ));
+        }
+
+        let ggx = if u[0] < diffuse + specular + glass {
+            &self.ggx
+        } else {
+            &self.clearcoat_ggx
+        };
+        let half = ggx.sample_visible_normal(outgoing, &position);
+        let is_glass = u[0] >= diffuse + specular && u[0] < diffuse + specular + glass;
+
+        // Glass refracts what it does not reflect
+        if is_glass && u[3] >= fresnel_dielectric(outgoing.dot(&half), self.eta) {
+            return refract(outgoing, &half, self.eta).filter(|x| x.z < 0.0);
+        }
+
+        return Option::Some(reflect_vec(&-outgoing, &half)).filter(|x| x.z > 0.0);
+    }
+}
+
+/// Schlick approximation of reflectance
+fn schlick(normal_reflectance: &Vec3, cosine: f32) -> Vec3 {
+    let weight = (1.0 - cosine.clamp(0.0, 1.0)).powi(5);
+
+    return normal_reflectance + (Vec3::from_element(1.0) - normal_reflectance) * weight;
+}
+
+impl Principled {
+    fn get_value(texture: &TextureRc, hit_record: &HitRecord) -> f32 {
+        return texture
+            .value(hit_record.get_uv(), hit_record.get_point())
+            .mean()
+            .clamp(0.0, 1.0);
+    }
+
+    fn get_surface(&self, hit_record: &HitRecord) -> Surface {
+        let roughness = Self::get_value(&self.roughness, hit_record);
+        let reflectance = 0.08 * Self::get_value(&self.specular, hit_record);
+        // Index of refraction with the same reflectance at normal incidence
+        let index_of_refraction =
+            ((1.0 + reflectance.sqrt()) / (1.0 - reflectance.sqrt())).max(MIN_INDEX_OF_REFRACTION);
+
+        return Surface {
+            base_color: self.get_attenuation(hit_record),
+            metallic: Self::get_value(&self.metallic, hit_record),
+            roughness,
+            reflectance,
+            clearcoat: Self::get_value(&self.clearcoat, hit_record),
+            sheen: Self::get_value(&self.sheen, hit_record),
+            transmission: Self::get_value(&self.transmission, hit_record),
+            ggx: Ggx::from_roughness(roughness, 0.0),
+            clearcoat_ggx: Ggx::from_roughness(CLEARCOAT_ROUGHNESS, 0.0),
+            eta: if hit_record.get_is_front_face() {
+                index_of_refraction
+            } else {
+                1.0 / index_of_refraction
+            },
+        };
+    }
+
+    /// Shading frame on the side the ray came from and direction towards the viewer in it
+    fn get_frame(ray: &Ray, hit_record: &HitRecord) -> (Frame, Vec3) {
+        let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
+            -*hit_record.get_normal()
+        } else {
+            *hit_record.get_normal()
+        };
+        let frame = Frame::new(&corrected_normal);
+        let outgoing = frame.to_local(&-ray.get_direction());
+
+        return (frame, outgoing);
+    }
+}
+
+impl Material for Principled {
+    /// Picks one lobe by its expected contribution and samples direction from it,
+    /// the weight accounts for every lobe which could have picked the same direction
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+
+        if outgoing.z <= 0.0 {
+            return Option::None;
+        }
+
+        let u = {
+            let mut rand_generator = RefCell::borrow_mut(&self.rand_generator);
+
+            [(); 4].map(|_| rand_generator.uniform())
+        };
+        let surface = self.get_surface(hit_record);
+        let incoming = surface.sample(&outgoing, &u)?;
+        let pdf = surface.pdf(&outgoing, &incoming);
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), frame.to_world(&incoming)),
+            surface.eval(&outgoing, &incoming) / pdf,
+            pdf,
+        ));
+    }
+
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .base_color
+            .value(hit_record.get_uv(), hit_record.get_point());
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 || incoming.z == 0.0 {
+            return Vec3::zeros();
+        }
+
+        return self.get_surface(hit_record).eval(&outgoing, &incoming);
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        let (frame, outgoing) = Self::get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if outgoing.z <= 0.0 || incoming.z == 0.0 {
+            return 0.0;
+        }
+
+        return self.get_surface(hit_record).pdf(&outgoing, &incoming);
+    }
+
+    fn is_specular(&self) -> bool {
+        return false;
+    }
+}
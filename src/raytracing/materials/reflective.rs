@@ -1,62 +1,136 @@
 use std::cell::RefCell;
+use std::f32::consts::PI;
 use std::rc::Rc;
 
 use nalgebra_glm::{reflect_vec, Vec3};
 
 use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::rand_gen::RandGenRc;
 use crate::misc::ray::Ray;
+use crate::misc::utils::orthonormal_basis;
+use crate::textures::texture::TextureRc;
 
+/// Glossy mirror reflecting into a Phong lobe around the mirror direction
 pub struct Reflective {
-    albedo: Vec3,
-    reflectiveness: f32,
+    albedo: TextureRc,
+    exponent: f32,
     rand_generator: RandGenRc,
 }
 
 impl Reflective {
-    pub fn new(albedo: Vec3, reflectiveness: f32, rand_generator: RandGenRc) -> Self {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `albedo`:
+    /// * `exponent`: Phong exponent of the lobe, larger values give sharper reflections
+    /// * `rand_generator`:
+    pub fn new(albedo: TextureRc, exponent: f32, rand_generator: RandGenRc) -> Self {
         return Self {
             albedo,
-            reflectiveness,
+            exponent,
             rand_generator,
         };
     }
 
-    pub fn new_rc(albedo: Vec3, reflectiveness: f32, rand_generator: RandGenRc) -> MaterialRc {
+    pub fn new_rc(albedo: TextureRc, exponent: f32, rand_generator: RandGenRc) -> MaterialRc {
         return Rc::new(RefCell::new(Box::new(Self::new(
             albedo,
-            reflectiveness,
+            exponent,
             rand_generator,
         ))));
     }
 }
 
-impl Material for Reflective {
-    fn scatter(
-        &mut self,
-        ray: &Ray,
-        hit_record: &HitRecord,
-        attenuation: &mut Vec3,
-        scattered: &mut Ray,
-    ) -> bool {
+impl Reflective {
+    /// Normal on the side the ray came from and mirror direction around it
+    fn get_frame(ray: &Ray, hit_record: &HitRecord) -> (Vec3, Vec3) {
         let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
             -*hit_record.get_normal()
         } else {
             *hit_record.get_normal()
         };
 
-        let reflection = reflect_vec(ray.get_direction(), &corrected_normal);
-        let target = RefCell::borrow_mut(&self.rand_generator).unit_hemisphere(&corrected_normal);
+        return (
+            corrected_normal,
+            reflect_vec(ray.get_direction(), &corrected_normal),
+        );
+    }
+
+    /// Density of the Phong lobe around `reflection`
+    fn lobe_pdf(&self, reflection: &Vec3, direction: &Vec3) -> f32 {
+        let cosine = Vec3::dot(reflection, direction).max(0.0);
+
+        return (self.exponent + 1.0) / (2.0 * PI) * cosine.powf(self.exponent);
+    }
+}
+
+impl Material for Reflective {
+    /// Samples Phong lobe around the mirror direction
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let (corrected_normal, reflection) = Self::get_frame(ray, hit_record);
+        let (u, v) = {
+            let mut rand_generator = RefCell::borrow_mut(&self.rand_generator);
+
+            (rand_generator.uniform(), rand_generator.uniform())
+        };
+
+        let (tangent, bitangent) = orthonormal_basis(&reflection);
+        let cos_alpha = u.powf(1.0 / (self.exponent + 1.0));
+        let sin_alpha = (1.0 - cos_alpha * cos_alpha).max(0.0).sqrt();
+        let phi = 2.0 * PI * v;
+        let scatter_direction =
+            (tangent * phi.cos() + bitangent * phi.sin()) * sin_alpha + reflection * cos_alpha;
+        let cosine = Vec3::dot(&corrected_normal, &scatter_direction);
+
+        // Directions under the surface are absorbed
+        if cosine <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), scatter_direction),
+            self.get_attenuation(hit_record)
+                * ((self.exponent + 2.0) / (self.exponent + 1.0) * cosine),
+            self.lobe_pdf(&reflection, &scatter_direction),
+        ));
+    }
+
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .albedo
+            .value(hit_record.get_uv(), hit_record.get_point());
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let (corrected_normal, reflection) = Self::get_frame(ray, hit_record);
+        let cosine = Vec3::dot(&corrected_normal, direction);
+
+        if cosine <= 0.0 {
+            return Vec3::zeros();
+        }
+
+        let lobe = Vec3::dot(&reflection, direction)
+            .max(0.0)
+            .powf(self.exponent);
+
+        return self.get_attenuation(hit_record)
+            * ((self.exponent + 2.0) / (2.0 * PI) * lobe * cosine);
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        let (corrected_normal, reflection) = Self::get_frame(ray, hit_record);
 
-        let scatter_direction = (target + reflection * self.reflectiveness).normalize();
-        *scattered = Ray::new(*hit_record.get_point(), scatter_direction);
-        *attenuation = self.get_attenuation();
+        if Vec3::dot(&corrected_normal, direction) <= 0.0 {
+            return 0.0;
+        }
 
-        return true;
+        return self.lobe_pdf(&reflection, direction);
     }
 
-    fn get_attenuation(&self) -> Vec3 {
-        return self.albedo;
+    fn is_specular(&self) -> bool {
+        return false;
     }
 }
@@ -1,100 +1,261 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use nalgebra_glm::{reflect_vec, Vec3};
+use nalgebra_glm::{reflect_vec, Vec2, Vec3};
 
+use crate::materials::fresnel::fresnel_dielectric;
+use crate::materials::ggx::{microfacet_normal, Ggx};
 use crate::materials::material::{Material, MaterialRc};
+use crate::materials::scatter_record::ScatterRecord;
+use crate::misc::frame::Frame;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::rand_gen::RandGenRc;
 use crate::misc::ray::Ray;
+use crate::misc::utils::refract;
+use crate::textures::texture::TextureRc;
 
+/// Glass-like material reflecting or refracting light according to the Fresnel equations.
+/// Outside of the surface is assumed to be vacuum
 pub struct Refractive {
-    albedo: Vec3,
+    albedo: TextureRc,
     index_of_refraction: f32,
+    ggx: Ggx,
+    absorption: Vec3,
     rand_generator: RandGenRc,
 }
 
 impl Refractive {
-    pub fn new(albedo: Vec3, index_of_refraction: f32, rand_generator: RandGenRc) -> Self {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `albedo`: Color of light passing through the surface
+    /// * `index_of_refraction`: Of the inside, pointed to by outward normals
+    /// * `ggx`: Distribution of microfacets, smooth one makes clear glass
+    /// * `absorption`: Part of light absorbed per unit of distance travelled inside, per channel
+    /// * `rand_generator`:
+    pub fn new(
+        albedo: TextureRc,
+        index_of_refraction: f32,
+        ggx: Ggx,
+        absorption: Vec3,
+        rand_generator: RandGenRc,
+    ) -> Self {
         return Self {
             albedo,
             index_of_refraction,
+            ggx,
+            absorption,
             rand_generator,
         };
     }
 
-    pub fn new_rc(albedo: Vec3, index_of_refraction: f32, rand_generator: RandGenRc) -> MaterialRc {
+    pub fn new_rc(
+        albedo: TextureRc,
+        index_of_refraction: f32,
+        ggx: Ggx,
+        absorption: Vec3,
+        rand_generator: RandGenRc,
+    ) -> MaterialRc {
         return Rc::new(RefCell::new(Box::new(Self::new(
             albedo,
             index_of_refraction,
+            ggx,
+            absorption,
             rand_generator,
         ))));
     }
 }
 
-fn refract(uv: &Vec3, n: &Vec3, etai_over_etat: f32) -> Vec3 {
-    let cos_theta = f32::min(Vec3::dot(&-uv, n), 1.0);
-    let r_out_perp = etai_over_etat * (uv + cos_theta * n);
-    let r_out_parallel = -f32::sqrt(f32::abs(1.0 - r_out_perp.magnitude_squared())) * n;
+impl Refractive {
+    /// Shading frame on the side the ray came from, direction towards the viewer in it
+    /// and relative index of refraction of the other side
+    fn get_frame(&self, ray: &Ray, hit_record: &HitRecord) -> (Frame, Vec3, f32) {
+        let frame = Frame::new(hit_record.get_normal());
+        let outgoing = frame.to_local(&-ray.get_direction());
+        let eta = if hit_record.get_is_front_face() {
+            self.index_of_refraction
+        } else {
+            1.0 / self.index_of_refraction
+        };
+
+        return (frame, outgoing, eta);
+    }
+
+    /// Light left after travelling from the previous hit, which is inside when hitting a back face
+    fn get_transmittance(&self, hit_record: &HitRecord) -> Vec3 {
+        if hit_record.get_is_front_face() {
+            return Vec3::from_element(1.0);
+        }
+
+        return (-self.absorption * hit_record.get_distance()).map(f32::exp);
+    }
+
+    fn scatter_smooth(
+        &self,
+        frame: &Frame,
+        outgoing: &Vec3,
+        eta: f32,
+        hit_record: &HitRecord,
+    ) -> ScatterRecord {
+        let reflectance = fresnel_dielectric(outgoing.z, eta);
+        let transmittance = self.get_transmittance(hit_record);
+
+        if RefCell::borrow_mut(&self.rand_generator).uniform() < reflectance {
+            return ScatterRecord::specular(
+                Ray::new(
+                    *hit_record.get_point(),
+                    frame.to_world(&Vec3::new(-outgoing.x, -outgoing.y, outgoing.z)),
+                ),
+                transmittance,
+            );
+        }
 
-    return r_out_perp + r_out_parallel;
+        // Reflectance below 1 means the light is not totally reflected, so refraction exists
+        let refracted = refract(outgoing, &Vec3::z(), eta).unwrap_or(-outgoing);
+
+        return ScatterRecord::specular(
+            Ray::new(*hit_record.get_point(), frame.to_world(&refracted)),
+            self.get_attenuation(hit_record)
+                .component_mul(&transmittance)
+                / (eta * eta),
+        );
+    }
 }
 
 impl Material for Refractive {
-    fn scatter(
-        &mut self,
-        ray: &Ray,
-        hit_record: &HitRecord,
-        attenuation: &mut Vec3,
-        scattered: &mut Ray,
-    ) -> bool {
-        // attenuation = color(1.0, 1.0, 1.0);
-        // double refraction_ratio = rec.front_face ? (1.0/ir) : ir;
-        //
-        // vec3 unit_direction = unit_vector(r_in.direction());
-        // vec3 refracted = refract(unit_direction, rec.normal, refraction_ratio);
-        //
-        // scattered = ray(rec.p, refracted);
-
-        let refraction_ratio = if !hit_record.get_is_front_face() {
-            1.0 / self.index_of_refraction
-        } else {
-            self.index_of_refraction
+    /// Picks microfacet normal visible from the ray, then reflects with probability
+    /// given by the Fresnel equations and refracts otherwise
+    fn scatter(&mut self, ray: &Ray, hit_record: &HitRecord) -> Option<ScatterRecord> {
+        let (frame, outgoing, eta) = self.get_frame(ray, hit_record);
+
+        if outgoing.z <= 0.0 {
+            return Option::None;
+        }
+
+        if self.ggx.is_smooth() {
+            return Option::Some(self.scatter_smooth(&frame, &outgoing, eta, hit_record));
+        }
+
+        let (u, choice) = {
+            let mut rand_generator = RefCell::borrow_mut(&self.rand_generator);
+
+            (
+                Vec2::new(rand_generator.uniform(), rand_generator.uniform()),
+                rand_generator.uniform(),
+            )
         };
+        let microfacet_normal = self.ggx.sample_visible_normal(&outgoing, &u);
+        let cosine = outgoing.dot(&microfacet_normal);
+        let reflectance = fresnel_dielectric(cosine, eta);
+        let normal_pdf = self.ggx.visible_normal_pdf(&outgoing, &microfacet_normal);
 
-        let unit_direction = ray.get_direction();
-        let refracted = refract(unit_direction, hit_record.get_normal(), refraction_ratio);
+        let (incoming, attenuation, pdf) = if choice < reflectance {
+            let incoming = reflect_vec(&-outgoing, &microfacet_normal);
 
-        *attenuation = self.albedo;
+            // Reflections going under the surface are shadowed by other microfacets
+            if incoming.z <= 0.0 {
+                return Option::None;
+            }
 
-        if RefCell::borrow_mut(&self.rand_generator).uniform() > 0.85 {
-            *scattered = Ray::new(
-                *hit_record.get_point(),
-                reflect_vec(ray.get_direction(), hit_record.get_normal()),
-            );
+            (
+                incoming,
+                Vec3::from_element(1.0),
+                reflectance * normal_pdf / (4.0 * cosine),
+            )
         } else {
-            *scattered = Ray::new(*hit_record.get_point(), refracted);
+            let incoming = refract(&outgoing, &microfacet_normal, eta)?;
+
+            if incoming.z >= 0.0 {
+                return Option::None;
+            }
+
+            let denominator = incoming.dot(&microfacet_normal) + cosine / eta;
+
+            (
+                incoming,
+                self.get_attenuation(hit_record) / (eta * eta),
+                (1.0 - reflectance) * normal_pdf * incoming.dot(&microfacet_normal).abs()
+                    / (denominator * denominator),
+            )
+        };
+
+        let weight = self.ggx.masking_shadowing(&outgoing, &incoming) / self.ggx.masking(&outgoing);
+
+        return Option::Some(ScatterRecord::new(
+            Ray::new(*hit_record.get_point(), frame.to_world(&incoming)),
+            attenuation.component_mul(&self.get_transmittance(hit_record)) * weight,
+            pdf,
+        ));
+    }
+
+    fn get_attenuation(&self, hit_record: &HitRecord) -> Vec3 {
+        return self
+            .albedo
+            .value(hit_record.get_uv(), hit_record.get_point());
+    }
+
+    fn eval(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> Vec3 {
+        let (frame, outgoing, eta) = self.get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if self.ggx.is_smooth() || outgoing.z <= 0.0 || incoming.z == 0.0 {
+            return Vec3::zeros();
         }
 
-        // *scattered = Ray::new(*hit_record.get_point(), *ray.get_direction());
-
-        // let corrected_normal = if Vec3::dot(ray.get_direction(), hit_record.get_normal()) > 0.0 {
-        //     *hit_record.get_normal()
-        // } else {
-        //     -*hit_record.get_normal()
-        // };
-        //
-        // let reflection = reflect_vec(ray.get_direction(), &corrected_normal);
-        // let target = RefCell::borrow_mut(&self.rand_generator).unit_hemisphere(&corrected_normal);
-        //
-        // let scatter_direction = (target + reflection * self.reflectiveness).normalize();
-        // *scattered = Ray::new(*hit_record.get_point(), scatter_direction);
-        // *attenuation = self.get_attenuation();
-
-        return true;
+        let microfacet_normal = match microfacet_normal(&outgoing, &incoming, eta) {
+            Some(microfacet_normal) => microfacet_normal,
+            None => return Vec3::zeros(),
+        };
+        let cosine = outgoing.dot(&microfacet_normal);
+        let reflectance = fresnel_dielectric(cosine, eta);
+        let microfacets = self.ggx.distribution(&microfacet_normal)
+            * self.ggx.masking_shadowing(&outgoing, &incoming);
+        let transmittance = self.get_transmittance(hit_record);
+
+        if incoming.z > 0.0 {
+            return transmittance * (reflectance * microfacets / (4.0 * outgoing.z));
+        }
+
+        let denominator = incoming.dot(&microfacet_normal) + cosine / eta;
+
+        return self
+            .get_attenuation(hit_record)
+            .component_mul(&transmittance)
+            * ((1.0 - reflectance)
+                * microfacets
+                * cosine
+                * incoming.dot(&microfacet_normal).abs()
+                / (outgoing.z * denominator * denominator * eta * eta));
+    }
+
+    fn pdf(&self, ray: &Ray, hit_record: &HitRecord, direction: &Vec3) -> f32 {
+        let (frame, outgoing, eta) = self.get_frame(ray, hit_record);
+        let incoming = frame.to_local(direction);
+
+        if self.ggx.is_smooth() || outgoing.z <= 0.0 || incoming.z == 0.0 {
+            return 0.0;
+        }
+
+        let microfacet_normal = match microfacet_normal(&outgoing, &incoming, eta) {
+            Some(microfacet_normal) => microfacet_normal,
+            None => return 0.0,
+        };
+        let cosine = outgoing.dot(&microfacet_normal);
+        let reflectance = fresnel_dielectric(cosine, eta);
+        let normal_pdf = self.ggx.visible_normal_pdf(&outgoing, &microfacet_normal);
+
+        if incoming.z > 0.0 {
+            return reflectance * normal_pdf / (4.0 * cosine);
+        }
+
+        let denominator = incoming.dot(&microfacet_normal) + cosine / eta;
+
+        return (1.0 - reflectance) * normal_pdf * incoming.dot(&microfacet_normal).abs()
+            / (denominator * denominator);
     }
 
-    fn get_attenuation(&self) -> Vec3 {
-        return self.albedo;
+    fn is_specular(&self) -> bool {
+        return self.ggx.is_smooth();
     }
 }
@@ -0,0 +1,62 @@
+use nalgebra_glm::Vec3;
+
+use crate::misc::ray::Ray;
+
+/// Direction chosen by a material together with how much light it carries
+pub struct ScatterRecord {
+    scattered: Ray,
+    attenuation: Vec3,
+    pdf: f32,
+    is_specular: bool,
+}
+
+impl ScatterRecord {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `scattered`: Ray leaving the hit point
+    /// * `attenuation`: Scattering function times cosine divided by `pdf`
+    /// * `pdf`: Density of the direction with respect to solid angle
+    pub fn new(scattered: Ray, attenuation: Vec3, pdf: f32) -> Self {
+        return Self {
+            scattered,
+            attenuation,
+            pdf,
+            is_specular: false,
+        };
+    }
+
+    /// Creates record of a direction no other sampling strategy can pick, like a perfect mirror
+    pub fn specular(scattered: Ray, attenuation: Vec3) -> Self {
+        return Self {
+            scattered,
+            attenuation,
+            pdf: 0.0,
+            is_specular: true,
+        };
+    }
+}
+
+impl ScatterRecord {
+    pub fn get_scattered(&self) -> &Ray {
+        return &self.scattered;
+    }
+
+    pub fn into_scattered(self) -> Ray {
+        return self.scattered;
+    }
+
+    pub fn get_attenuation(&self) -> &Vec3 {
+        return &self.attenuation;
+    }
+
+    /// Zero for specular records
+    pub fn get_pdf(&self) -> f32 {
+        return self.pdf;
+    }
+
+    pub fn is_specular(&self) -> bool {
+        return self.is_specular;
+    }
+}
@@ -242,3 +242,17 @@ pub fn rgb_to_hsv(rgb: &Vec3) -> Vec3 {
         r,
     );
 }
+
+/// Relative luminance of linear Rec. 709 color
+pub fn luminance(rgb: &Vec3) -> f32 {
+    return 0.2126 * rgb.x + 0.7152 * rgb.y + 0.0722 * rgb.z;
+}
+
+/// Converts sRGB encoded value from range [0.0, 1.0] to linear
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        return value / 12.92;
+    }
+
+    return ((value + 0.055) / 1.055).powf(2.4);
+}
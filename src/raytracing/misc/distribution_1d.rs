@@ -0,0 +1,91 @@
+/// Piecewise-constant density over [0, 1) proportional to non-negative function values
+pub struct Distribution1d {
+    function: Vec<f32>,
+    cdf: Vec<f32>,
+    integral: f32,
+}
+
+impl Distribution1d {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `function`: Non-negative values of equal width segments, uniform density if all are zero
+    pub fn new(function: Vec<f32>) -> Self {
+        let count = function.len().max(1);
+        let mut cdf = Vec::with_capacity(count + 1);
+
+        cdf.push(0.0);
+
+        for value in &function {
+            cdf.push(cdf.last().unwrap() + value.max(0.0) / count as f32);
+        }
+
+        let integral = *cdf.last().unwrap();
+
+        if integral > 0.0 {
+            for value in cdf.iter_mut() {
+                *value /= integral;
+            }
+        } else {
+            cdf = (0..=count).map(|x| x as f32 / count as f32).collect();
+        }
+
+        return Self {
+            function,
+            cdf,
+            integral,
+        };
+    }
+}
+
+impl Distribution1d {
+    /// Average of the function over [0, 1)
+    pub fn get_integral(&self) -> f32 {
+        return self.integral;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.function.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.function.is_empty();
+    }
+
+    /// Picks position with density proportional to the function
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: Uniform random number in [0, 1)
+    ///
+    /// returns: (f32, f32, usize) Position in [0, 1), its density and segment
+    pub fn sample(&self, u: f32) -> (f32, f32, usize) {
+        let segments = self.cdf.len() - 1;
+        let segment = (self.cdf.partition_point(|x| *x <= u).max(1) - 1).min(segments - 1);
+        let width = self.cdf[segment + 1] - self.cdf[segment];
+        let offset = if width > 0.0 {
+            (u - self.cdf[segment]) / width
+        } else {
+            0.0
+        };
+        let position =
+            ((segment as f32 + offset.clamp(0.0, 1.0)) / segments as f32).min(1.0 - f32::EPSILON);
+
+        return (position, self.pdf_of_segment(segment), segment);
+    }
+
+    /// Density of position `x` in [0, 1)
+    pub fn pdf(&self, x: f32) -> f32 {
+        let segments = self.cdf.len() - 1;
+        let segment = ((x * segments as f32) as usize).min(segments - 1);
+
+        return self.pdf_of_segment(segment);
+    }
+
+    fn pdf_of_segment(&self, segment: usize) -> f32 {
+        let segments = self.cdf.len() - 1;
+
+        return (self.cdf[segment + 1] - self.cdf[segment]) * segments as f32;
+    }
+}
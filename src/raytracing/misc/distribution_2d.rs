@@ -0,0 +1,51 @@
+use nalgebra_glm::Vec2;
+
+use crate::misc::distribution_1d::Distribution1d;
+
+/// Piecewise-constant density over [0, 1)² proportional to a grid of function values
+pub struct Distribution2d {
+    rows: Vec<Distribution1d>,
+    marginal: Distribution1d,
+}
+
+impl Distribution2d {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `function`: Non-negative values row by row
+    /// * `width`: Values in one row
+    /// * `height`: Number of rows
+    pub fn new(function: &[f32], width: usize, height: usize) -> Self {
+        let rows: Vec<Distribution1d> = function
+            .chunks(width)
+            .take(height)
+            .map(|row| Distribution1d::new(row.to_vec()))
+            .collect();
+        let marginal = Distribution1d::new(rows.iter().map(|x| x.get_integral()).collect());
+
+        return Self { rows, marginal };
+    }
+}
+
+impl Distribution2d {
+    /// Picks position with density proportional to the function
+    ///
+    /// # Arguments
+    ///
+    /// * `u`: Two uniform random numbers in [0, 1)
+    ///
+    /// returns: (Vec2, f32) Position, x along rows and y across them, and its density
+    pub fn sample(&self, u: &Vec2) -> (Vec2, f32) {
+        let (y, marginal_pdf, row) = self.marginal.sample(u.y);
+        let (x, row_pdf, _) = self.rows[row].sample(u.x);
+
+        return (Vec2::new(x, y), marginal_pdf * row_pdf);
+    }
+
+    pub fn pdf(&self, position: &Vec2) -> f32 {
+        let row = ((position.y * self.rows.len() as f32) as usize).min(self.rows.len() - 1);
+
+        return self.marginal.pdf(position.y) * self.rows[row].pdf(position.x);
+    }
+}
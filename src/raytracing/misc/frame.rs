@@ -0,0 +1,69 @@
+use nalgebra_glm::Vec3;
+
+use crate::misc::utils::orthonormal_basis;
+
+/// Orthonormal basis for moving directions to and from a space where the normal is z
+pub struct Frame {
+    tangent: Vec3,
+    bitangent: Vec3,
+    normal: Vec3,
+}
+
+impl Frame {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `normal`: Unit vector becoming z
+    pub fn new(normal: &Vec3) -> Self {
+        let (tangent, bitangent) = orthonormal_basis(normal);
+
+        return Self {
+            tangent,
+            bitangent,
+            normal: *normal,
+        };
+    }
+
+    /// Frame with x along `direction` projected onto the surface, for anisotropic materials
+    ///
+    /// # Arguments
+    ///
+    /// * `normal`: Unit vector becoming z
+    /// * `direction`: Preferred x, any basis is used if it is parallel to `normal`
+    pub fn with_tangent(normal: &Vec3, direction: &Vec3) -> Self {
+        let projected = direction - normal * normal.dot(direction);
+
+        if projected.magnitude_squared() < 1e-8 {
+            return Self::new(normal);
+        }
+
+        let tangent = projected.normalize();
+
+        return Self {
+            tangent,
+            bitangent: normal.cross(&tangent),
+            normal: *normal,
+        };
+    }
+}
+
+impl Frame {
+    pub fn get_normal(&self) -> &Vec3 {
+        return &self.normal;
+    }
+
+    pub fn to_local(&self, direction: &Vec3) -> Vec3 {
+        return Vec3::new(
+            direction.dot(&self.tangent),
+            direction.dot(&self.bitangent),
+            direction.dot(&self.normal),
+        );
+    }
+
+    pub fn to_world(&self, direction: &Vec3) -> Vec3 {
+        return self.tangent * direction.x
+            + self.bitangent * direction.y
+            + self.normal * direction.z;
+    }
+}
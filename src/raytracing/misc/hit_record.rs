@@ -1,12 +1,15 @@
-use nalgebra_glm::Vec3;
+use nalgebra_glm::{Vec2, Vec3};
 
 use crate::materials::material::MaterialRc;
+use crate::misc::ray::Ray;
 
 #[derive(Clone)]
 pub struct HitRecord {
     point: Vec3,
     normal: Vec3,
     distance: f32,
+    // Surface coordinates for texture lookups, v grows upwards in images
+    uv: Vec2,
     material: Option<MaterialRc>,
     is_front_face: bool,
 }
@@ -16,6 +19,7 @@ impl HitRecord {
         point: Vec3,
         normal: Vec3,
         distance: f32,
+        uv: Vec2,
         is_front_face: bool,
         material: MaterialRc,
     ) -> Self {
@@ -23,6 +27,7 @@ impl HitRecord {
             point,
             normal,
             distance,
+            uv,
             material: Option::Some(material),
             is_front_face,
         };
@@ -33,6 +38,7 @@ impl HitRecord {
             point: Vec3::new(0.0, 0.0, 0.0),
             normal: Vec3::new(0.0, 0.0, 0.0),
             distance: 0.0,
+            uv: Vec2::new(0.0, 0.0),
             material: Option::None,
             is_front_face: true,
         };
@@ -52,6 +58,10 @@ impl HitRecord {
         return self.distance;
     }
 
+    pub fn get_uv(&self) -> &Vec2 {
+        return &self.uv;
+    }
+
     pub fn get_material(&self) -> Option<MaterialRc> {
         return self.material.clone();
     }
@@ -72,6 +82,10 @@ impl HitRecord {
         self.distance = distance;
     }
 
+    pub fn set_uv(&mut self, uv: Vec2) {
+        self.uv = uv;
+    }
+
     pub fn set_material(&mut self, material: MaterialRc) {
         self.material = Option::Some(material);
     }
@@ -79,4 +93,19 @@ impl HitRecord {
     pub fn set_is_front_face(&mut self, is_front_face: bool) {
         self.is_front_face = is_front_face;
     }
+
+    /// Sets normal facing against the ray and whether the ray hit the outer side
+    ///
+    /// # Arguments
+    ///
+    /// * `ray`: Ray that hit the surface
+    /// * `outward_normal`: Unit normal pointing out of the surface
+    pub fn set_face_normal(&mut self, ray: &Ray, outward_normal: Vec3) {
+        self.is_front_face = ray.get_direction().dot(&outward_normal) < 0.0;
+        self.normal = if self.is_front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+    }
 }
@@ -1,9 +1,16 @@
 pub mod camera;
 pub mod color;
 pub mod default_rand_gen;
+pub mod distribution_1d;
+pub mod distribution_2d;
+pub mod frame;
 pub mod hit_record;
+pub mod polynomial;
 pub mod rand_gen;
 pub mod ray;
+pub mod render_settings;
 pub mod scene;
+pub mod seeded_rand_gen;
+pub mod surface_sample;
 pub mod tracer;
 pub mod utils;
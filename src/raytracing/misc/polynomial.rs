@@ -0,0 +1,120 @@
+const NEWTON_ITERATIONS: usize = 2;
+
+/// Real roots of `a * x² + b * x + c`, computed without cancellation
+///
+/// returns: Option<(f32, f32)> Smaller and larger root, None if there are no real roots
+pub fn solve_quadratic(a: f32, b: f32, c: f32) -> Option<(f32, f32)> {
+    if a == 0.0 {
+        if b == 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some((-c / b, -c / b));
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return Option::None;
+    }
+
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    let (one, two) = if q == 0.0 { (0.0, 0.0) } else { (q / a, c / q) };
+
+    return Option::Some((one.min(two), one.max(two)));
+}
+
+/// Real roots of `x⁴ + b * x³ + c * x² + d * x + e` by Ferrari's method,
+/// refined with Newton's method
+///
+/// returns: ([f64; 4], usize) Roots in no particular order and their count
+pub fn solve_quartic(b: f64, c: f64, d: f64, e: f64) -> ([f64; 4], usize) {
+    // Depressed quartic y⁴ + p * y² + q * y + r with x = y - b / 4
+    let shift = -b / 4.0;
+    let b2 = b * b;
+    let p = c - 3.0 * b2 / 8.0;
+    let q = d - b * c / 2.0 + b2 * b / 8.0;
+    let r = e - b * d / 4.0 + b2 * c / 16.0 - 3.0 * b2 * b2 / 256.0;
+    let mut roots = [0.0; 4];
+    let mut count = 0;
+
+    let mut push_quadratic = |linear: f64, constant: f64| {
+        let discriminant = linear * linear - 4.0 * constant;
+
+        if discriminant < 0.0 {
+            return;
+        }
+
+        let root = discriminant.sqrt();
+
+        roots[count] = (-linear - root) / 2.0;
+        roots[count + 1] = (-linear + root) / 2.0;
+        count += 2;
+    };
+
+    if q.abs() < 1e-12 {
+        // Biquadratic, roots are square roots of the quadratic ones
+        let discriminant = p * p - 4.0 * r;
+
+        if discriminant >= 0.0 {
+            let root = discriminant.sqrt();
+
+            for z in [(-p - root) / 2.0, (-p + root) / 2.0] {
+                if z >= 0.0 {
+                    push_quadratic(0.0, -z);
+                }
+            }
+        }
+    } else {
+        // Splits into two quadratics once m makes the rest a perfect square
+        let m = largest_cubic_root(p, p * p / 4.0 - r, -q * q / 8.0).max(0.0);
+        let s = (2.0 * m).sqrt();
+
+        if s > 0.0 {
+            push_quadratic(-s, p / 2.0 + m + q / (2.0 * s));
+            push_quadratic(s, p / 2.0 + m - q / (2.0 * s));
+        }
+    }
+
+    for root in roots.iter_mut().take(count) {
+        *root += shift;
+
+        for _ in 0..NEWTON_ITERATIONS {
+            let value = (((*root + b) * *root + c) * *root + d) * *root + e;
+            let derivative = ((4.0 * *root + 3.0 * b) * *root + 2.0 * c) * *root + d;
+
+            if derivative == 0.0 {
+                break;
+            }
+
+            *root -= value / derivative;
+        }
+    }
+
+    return (roots, count);
+}
+
+/// Largest real root of `x³ + a * x² + b * x + c`
+fn largest_cubic_root(a: f64, b: f64, c: f64) -> f64 {
+    // Depressed cubic t³ + p * t + q with x = t - a / 3
+    let shift = -a / 3.0;
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+    let discriminant = q * q / 4.0 + p * p * p / 27.0;
+
+    if discriminant > 0.0 {
+        let root = discriminant.sqrt();
+
+        return (-q / 2.0 + root).cbrt() + (-q / 2.0 - root).cbrt() + shift;
+    }
+
+    if p == 0.0 {
+        return shift;
+    }
+
+    // Three real roots, the largest one is at k = 0 of the trigonometric solution
+    let radius = 2.0 * (-p / 3.0).sqrt();
+    let angle = (3.0 * q / (p * radius)).clamp(-1.0, 1.0).acos() / 3.0;
+
+    return radius * angle.cos() + shift;
+}
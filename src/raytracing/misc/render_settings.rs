@@ -0,0 +1,129 @@
+use nalgebra_glm::{IVec2, Vec3};
+
+/// How a scene is rendered, carried with every job to the tracers
+#[derive(Clone, Copy, Debug)]
+pub struct RenderSettings {
+    resolution: IVec2,
+    max_depth: u32,
+    ray_epsilon: f32,
+    background: Vec3,
+    samples_per_batch: u64,
+    total_samples: Option<u64>,
+    russian_roulette_depth: Option<u32>,
+}
+
+impl RenderSettings {
+    pub fn new(resolution: IVec2) -> Self {
+        return Self {
+            resolution,
+            max_depth: 20,
+            ray_epsilon: 0.0001,
+            background: Vec3::zeros(),
+            samples_per_batch: 20,
+            total_samples: Option::None,
+            russian_roulette_depth: Option::Some(3),
+        };
+    }
+}
+
+impl RenderSettings {
+    pub fn resolution(mut self, resolution: IVec2) -> Self {
+        self.resolution = resolution;
+
+        return self;
+    }
+
+    /// Maximum number of bounces of every path
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+
+        return self;
+    }
+
+    /// Closest distance a ray can hit something at, keeps rays from hitting their own origin
+    pub fn ray_epsilon(mut self, ray_epsilon: f32) -> Self {
+        self.ray_epsilon = ray_epsilon;
+
+        return self;
+    }
+
+    /// Radiance of rays that leave the scene
+    pub fn background(mut self, background: Vec3) -> Self {
+        self.background = background;
+
+        return self;
+    }
+
+    /// Samples per pixel a worker traces before sending its image
+    pub fn samples_per_batch(mut self, samples_per_batch: u64) -> Self {
+        self.samples_per_batch = samples_per_batch;
+
+        return self;
+    }
+
+    /// Samples per pixel to stop at, summed over all workers. None renders until stopped
+    pub fn total_samples(mut self, total_samples: Option<u64>) -> Self {
+        self.total_samples = total_samples;
+
+        return self;
+    }
+
+    /// Bounce count after which paths are randomly stopped depending on how much light they carry.
+    /// None keeps every path until `max_depth`
+    pub fn russian_roulette_depth(mut self, russian_roulette_depth: Option<u32>) -> Self {
+        self.russian_roulette_depth = russian_roulette_depth;
+
+        return self;
+    }
+
+    pub fn get_resolution(&self) -> IVec2 {
+        return self.resolution;
+    }
+
+    pub fn get_max_depth(&self) -> u32 {
+        return self.max_depth;
+    }
+
+    pub fn get_ray_epsilon(&self) -> f32 {
+        return self.ray_epsilon;
+    }
+
+    pub fn get_background(&self) -> &Vec3 {
+        return &self.background;
+    }
+
+    pub fn get_samples_per_batch(&self) -> u64 {
+        return self.samples_per_batch;
+    }
+
+    pub fn get_total_samples(&self) -> Option<u64> {
+        return self.total_samples;
+    }
+
+    pub fn get_russian_roulette_depth(&self) -> Option<u32> {
+        return self.russian_roulette_depth;
+    }
+
+    /// Checks that the settings can be rendered with
+    pub fn validate(&self) -> Result<(), String> {
+        if self.resolution.x <= 0 || self.resolution.y <= 0 {
+            return Result::Err(format!(
+                "Resolution must be positive, got {}x{}",
+                self.resolution.x, self.resolution.y
+            ));
+        }
+
+        if self.ray_epsilon.is_nan() || self.ray_epsilon < 0.0 {
+            return Result::Err(format!(
+                "Ray epsilon must not be negative, got {}",
+                self.ray_epsilon
+            ));
+        }
+
+        if self.samples_per_batch == 0 {
+            return Result::Err("Samples per batch must be positive".to_string());
+        }
+
+        return Result::Ok(());
+    }
+}
@@ -1,19 +1,58 @@
+use crate::hittables::bvh::bvh_node::BvhNode;
+use crate::hittables::hittable::Hittable;
 use crate::hittables::hittable_list::HittableList;
+use crate::lights::light::Light;
 use crate::materials::material::MaterialRc;
 use crate::misc::camera::Camera;
+use crate::misc::rand_gen::RandGenRc;
 
 pub struct Scene {
     camera: Camera,
     materials: Vec<MaterialRc>,
-    hittable_list: HittableList,
+    world: Box<dyn Hittable>,
+    lights: Vec<Box<dyn Light>>,
+    rand: RandGenRc,
 }
 
 impl Scene {
-    pub fn new(camera: Camera, materials: Vec<MaterialRc>, hittable_list: HittableList) -> Self {
+    /// Creates scene without lights with a bounding volume hierarchy built over `hittable_list`
+    pub fn new(
+        camera: Camera,
+        materials: Vec<MaterialRc>,
+        hittable_list: HittableList,
+        rand: RandGenRc,
+    ) -> Self {
+        return Self::with_world(
+            camera,
+            materials,
+            BvhNode::from_list(hittable_list),
+            Vec::new(),
+            rand,
+        );
+    }
+
+    /// Creates scene with `world` used as is, without building any acceleration structure
+    ///
+    /// # Arguments
+    ///
+    /// * `camera`:
+    /// * `materials`:
+    /// * `world`: Everything rays can hit, lights included
+    /// * `lights`: Lights sampled directly at every bounce
+    /// * `rand`: Random generator for light sampling
+    pub fn with_world(
+        camera: Camera,
+        materials: Vec<MaterialRc>,
+        world: Box<dyn Hittable>,
+        lights: Vec<Box<dyn Light>>,
+        rand: RandGenRc,
+    ) -> Self {
         return Scene {
             camera,
             materials,
-            hittable_list,
+            world,
+            lights,
+            rand,
         };
     }
 }
@@ -23,10 +62,6 @@ impl Scene {
         return &mut self.camera;
     }
 
-    pub fn get_hittable_list_mut(&mut self) -> &mut HittableList {
-        return &mut self.hittable_list;
-    }
-
     pub fn get_materials_mut(&mut self) -> &mut Vec<MaterialRc> {
         return &mut self.materials;
     }
@@ -35,11 +70,19 @@ impl Scene {
         return &self.camera;
     }
 
-    pub fn get_hittable_list(&self) -> &HittableList {
-        return &self.hittable_list;
+    pub fn get_world(&self) -> &dyn Hittable {
+        return self.world.as_ref();
     }
 
     pub fn get_materials(&self) -> &Vec<MaterialRc> {
         return &self.materials;
     }
+
+    pub fn get_lights(&self) -> &[Box<dyn Light>] {
+        return &self.lights;
+    }
+
+    pub fn get_rand(&self) -> &RandGenRc {
+        return &self.rand;
+    }
 }
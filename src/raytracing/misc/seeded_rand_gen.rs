@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::misc::rand_gen::{RandGen, RandGenRc};
+
+/// Generator giving the same sequence for the same seed, for things which have to match
+/// on every worker, like procedural textures
+pub struct SeededRandGen {
+    rng: StdRng,
+}
+
+impl SeededRandGen {
+    pub fn new(seed: u32) -> Self {
+        return Self {
+            rng: StdRng::seed_from_u64(seed as u64),
+        };
+    }
+
+    pub fn new_rc(seed: u32) -> RandGenRc {
+        return Rc::new(RefCell::new(Box::new(Self::new(seed))));
+    }
+
+    /// Seeds from another generator, so a seeded scene generator fixes everything made from it
+    ///
+    /// # Arguments
+    ///
+    /// * `rand`: Generator the seed is drawn from
+    /// * `offset`: Added to the drawn seed, different offsets give different sequences
+    pub fn derived(rand: &mut dyn RandGen, offset: u32) -> Self {
+        // Uniform floats carry 24 random bits, so the seed is put together from two halves
+        let high = (rand.uniform() * 65536.0) as u32;
+        let low = (rand.uniform() * 65536.0) as u32;
+
+        return Self::new(((high << 16) | low).wrapping_add(offset));
+    }
+}
+
+impl RandGen for SeededRandGen {
+    fn unit_sphere(&mut self) -> Vec3 {
+        loop {
+            let vec = Vec3::from_fn(|_, _| self.rng.gen::<f32>() * 2.0 - 1.0);
+
+            if vec.magnitude() < 1.0 {
+                return vec;
+            }
+        }
+    }
+
+    fn unit_hemisphere(&mut self, normal: &Vec3) -> Vec3 {
+        let vec = self.unit_sphere();
+
+        return if Vec3::dot(&vec, normal) > 0.0 {
+            vec
+        } else {
+            -vec
+        };
+    }
+
+    fn unit_disk(&mut self) -> Vec2 {
+        loop {
+            let vec = Vec2::new(
+                self.rng.gen::<f32>() * 2.0 - 1.0,
+                self.rng.gen::<f32>() * 2.0 - 1.0,
+            );
+
+            if vec.magnitude() < 1.0 {
+                return vec;
+            }
+        }
+    }
+
+    fn uniform(&mut self) -> f32 {
+        return self.rng.gen();
+    }
+}
@@ -0,0 +1,118 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::hittable::Hittable;
+use crate::misc::hit_record::HitRecord;
+use crate::misc::ray::Ray;
+
+const MIN_COSINE: f32 = 1e-6;
+const PDF_T_MIN: f32 = 0.0001;
+
+/// Point sampled on the surface of a hittable as seen from some origin
+#[derive(Clone, Copy)]
+pub struct SurfaceSample {
+    point: Vec3,
+    normal: Vec3,
+    pdf: f32,
+}
+
+impl SurfaceSample {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `point`: Sampled point
+    /// * `normal`: Unit surface normal at `point`
+    /// * `pdf`: Density of the sample with respect to solid angle seen from the origin
+    pub fn new(point: Vec3, normal: Vec3, pdf: f32) -> Self {
+        return Self { point, normal, pdf };
+    }
+
+    /// Creates sample of a point chosen uniformly by area, converting its density to solid angle
+    ///
+    /// # Arguments
+    ///
+    /// * `origin`: Point the surface is seen from
+    /// * `point`: Sampled point
+    /// * `normal`: Unit surface normal at `point`
+    /// * `area`: Total area the point was chosen from
+    ///
+    /// returns: None if the point is seen edge-on or coincides with `origin`
+    pub fn from_area(origin: &Vec3, point: Vec3, normal: Vec3, area: f32) -> Option<Self> {
+        let to_point = point - origin;
+        let distance = to_point.magnitude();
+
+        if distance <= 0.0 {
+            return Option::None;
+        }
+
+        let pdf = area_to_solid_angle(distance, to_point.dot(&normal) / distance, area);
+
+        if pdf <= 0.0 {
+            return Option::None;
+        }
+
+        return Option::Some(Self::new(point, normal, pdf));
+    }
+}
+
+impl SurfaceSample {
+    pub fn get_point(&self) -> &Vec3 {
+        return &self.point;
+    }
+
+    pub fn get_normal(&self) -> &Vec3 {
+        return &self.normal;
+    }
+
+    pub fn get_pdf(&self) -> f32 {
+        return self.pdf;
+    }
+}
+
+/// Converts uniform density over `area` to density with respect to solid angle
+///
+/// # Arguments
+///
+/// * `distance`: Distance to the point
+/// * `cosine`: Cosine between direction to the point and its normal
+/// * `area`: Total area
+///
+/// returns: f32 Zero if the surface is seen edge-on
+pub fn area_to_solid_angle(distance: f32, cosine: f32, area: f32) -> f32 {
+    let cosine = cosine.abs();
+
+    if cosine < MIN_COSINE || area <= 0.0 {
+        return 0.0;
+    }
+
+    return distance * distance / (cosine * area);
+}
+
+/// Solid angle density of sampling `direction` on `hittable` uniformly by area
+///
+/// # Arguments
+///
+/// * `hittable`: Hittable whose points are chosen uniformly by area
+/// * `area`: Total area of `hittable`
+/// * `origin`: Point the surface is seen from
+/// * `direction`: Unit direction from `origin`
+///
+/// returns: f32 Zero if `direction` misses `hittable`
+pub fn area_pdf(hittable: &dyn Hittable, area: f32, origin: &Vec3, direction: &Vec3) -> f32 {
+    let mut record = HitRecord::default();
+
+    if !hittable.hit(
+        &Ray::new(*origin, *direction),
+        PDF_T_MIN,
+        f32::INFINITY,
+        &mut record,
+    ) {
+        return 0.0;
+    }
+
+    return area_to_solid_angle(
+        record.get_distance(),
+        direction.dot(record.get_normal()),
+        area,
+    );
+}
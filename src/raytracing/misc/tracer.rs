@@ -1,10 +1,11 @@
-use nalgebra_glm::{IVec2, Vec3};
+use nalgebra_glm::{IVec2, Vec2, Vec3};
 
-use crate::hittables::hittable::Hittable;
 use crate::image::default_image_buffer::DefaultImageBuffer;
 use crate::image::image_buffer::ImageBuffer;
+use crate::materials::material::MaterialRc;
 use crate::misc::hit_record::HitRecord;
 use crate::misc::ray::Ray;
+use crate::misc::render_settings::RenderSettings;
 use crate::misc::scene::Scene;
 use crate::thread_pool::trace_result::TraceResult;
 
@@ -12,21 +13,22 @@ pub struct Tracer {
     buffer: DefaultImageBuffer,
     total_samples: u64,
     scene: Scene,
+    settings: RenderSettings,
 }
 
 impl Tracer {
-    pub fn new(resolution: IVec2, scene: Scene) -> Self {
+    pub fn new(settings: RenderSettings, scene: Scene) -> Self {
         return Tracer {
-            buffer: DefaultImageBuffer::new(resolution).unwrap(),
+            buffer: DefaultImageBuffer::new(settings.get_resolution()).unwrap(),
             total_samples: 0,
             scene,
+            settings,
         };
     }
 }
 
 impl Tracer {
     pub fn trace(&mut self) {
-        let hittable_list = self.scene.get_hittable_list();
         let camera = self.scene.get_camera();
         let buffer = &mut self.buffer;
         let resolution = buffer.get_resolution();
@@ -36,66 +38,212 @@ impl Tracer {
                 let norm_x = x as f32 / resolution.x as f32;
                 let norm_y = y as f32 / resolution.y as f32;
 
-                let mut ray = camera.get_ray(norm_x, norm_y);
-                let mut absorbed = false;
-                let mut result_color = Vec3::new(1.0, 1.0, 1.0);
-                let t_min = 0.0001;
-                let t_max = f32::INFINITY;
-                const DEPTH: u32 = 20;
-                let world_color: Vec3 = Vec3::new(1.0, 1.0, 1.0) * 0.0;
-
-                for cur_depth in 0..DEPTH {
-                    let mut record = HitRecord::default();
-
-                    if hittable_list.hit(&ray, t_min, t_max, &mut record) {
-                        if let Some(material) = record.get_material() {
-                            let mut scattered_ray = Ray::default();
-                            let mut attenuation = Vec3::default();
-
-                            if material.borrow_mut().scatter(
-                                &ray,
-                                &record,
-                                &mut attenuation,
-                                &mut scattered_ray,
-                            ) {
-                                ray = scattered_ray;
-
-                                result_color.component_mul_assign(&attenuation);
-                            } else {
-                                result_color.component_mul_assign(&attenuation);
-                                absorbed = true;
-
-                                break;
-                            }
-                        } else {
-                            result_color.component_mul_assign(&world_color);
-                            absorbed = true;
-
-                            break;
-                        }
-                    } else {
-                        result_color.component_mul_assign(&world_color);
-                        absorbed = true;
+                let ray = camera.get_ray(norm_x, norm_y);
+                let result_color = Self::trace_ray(&self.scene, &self.settings, ray);
 
+                buffer.add(IVec2::new(x, y), result_color).unwrap();
+            }
+        }
+
+        self.total_samples += 1;
+    }
+
+    /// Follows one path, combining material and light sampling with multiple importance sampling
+    fn trace_ray(scene: &Scene, settings: &RenderSettings, ray: Ray) -> Vec3 {
+        let world = scene.get_world();
+        let mut ray = ray;
+        let mut throughput = Vec3::new(1.0, 1.0, 1.0);
+        let mut result_color = Vec3::zeros();
+        // Density of the last scattered direction, none if lights could not have picked it
+        let mut scatter_pdf: Option<f32> = Option::None;
+        let world_color = settings.get_background();
+
+        for cur_depth in 0..settings.get_max_depth() {
+            let mut record = HitRecord::default();
+
+            if !world.hit(&ray, settings.get_ray_epsilon(), f32::INFINITY, &mut record) {
+                result_color += throughput.component_mul(world_color);
+                result_color +=
+                    throughput.component_mul(&Self::background(scene, &ray, scatter_pdf));
+
+                break;
+            }
+
+            let material = match record.get_material() {
+                Some(material) => material,
+                None => {
+                    result_color += throughput.component_mul(world_color);
+
+                    break;
+                }
+            };
+
+            let emitted = material.borrow().emitted(&ray, &record);
+
+            if emitted.max() > 0.0 {
+                let weight = match scatter_pdf {
+                    Some(scatter_pdf) => power_heuristic(
+                        scatter_pdf,
+                        Self::light_pdf(scene, &ray, record.get_distance()),
+                    ),
+                    None => 1.0,
+                };
+
+                result_color += throughput.component_mul(&emitted) * weight;
+            }
+
+            let scatter_record = match material.borrow_mut().scatter(&ray, &record) {
+                Some(scatter_record) => scatter_record,
+                None => break,
+            };
+
+            let sample_lights = !material.borrow().is_specular() && !scene.get_lights().is_empty();
+
+            if sample_lights {
+                result_color += throughput.component_mul(&Self::sample_light(
+                    scene, settings, &ray, &record, &material,
+                ));
+            }
+
+            scatter_pdf = if sample_lights && !scatter_record.is_specular() {
+                Option::Some(scatter_record.get_pdf())
+            } else {
+                Option::None
+            };
+
+            throughput.component_mul_assign(scatter_record.get_attenuation());
+            ray = scatter_record.into_scattered();
+
+            // Degenerate densities would carry NaN into the pixel, comparisons below can't catch it
+            if !throughput.iter().all(|x| x.is_finite()) {
+                break;
+            }
+
+            if let Some(min_depth) = settings.get_russian_roulette_depth() {
+                if cur_depth + 1 >= min_depth {
+                    // Dim paths are likely to stop, survivors carry the light of the stopped ones
+                    let survival = throughput.max().min(1.0);
+
+                    if survival <= 0.0 || scene.get_rand().borrow_mut().uniform() >= survival {
                         break;
                     }
-                }
 
-                if !absorbed {
-                    result_color.component_mul_assign(&Vec3::default());
+                    throughput /= survival;
                 }
+            }
+        }
 
-                buffer.add(IVec2::new(x, y), result_color).unwrap();
+        return result_color;
+    }
+
+    /// Light of infinitely far lights along a ray that left the scene, each weighted against
+    /// sampling it directly
+    fn background(scene: &Scene, ray: &Ray, scatter_pdf: Option<f32>) -> Vec3 {
+        let lights = scene.get_lights();
+        let mut radiance = Vec3::zeros();
+
+        for light in lights {
+            let light_radiance = light.background(ray.get_direction());
+
+            if light_radiance.max() <= 0.0 {
+                continue;
             }
+
+            let weight = match scatter_pdf {
+                Some(scatter_pdf) => power_heuristic(
+                    scatter_pdf,
+                    light.pdf(ray.get_origin(), ray.get_direction(), f32::INFINITY)
+                        / lights.len() as f32,
+                ),
+                None => 1.0,
+            };
+
+            radiance += light_radiance * weight;
         }
 
-        self.total_samples += 1;
+        return radiance;
+    }
+
+    /// Light arriving directly from one randomly chosen light, weighted against material sampling
+    fn sample_light(
+        scene: &Scene,
+        settings: &RenderSettings,
+        ray: &Ray,
+        record: &HitRecord,
+        material: &MaterialRc,
+    ) -> Vec3 {
+        let lights = scene.get_lights();
+        let (choice, u) = {
+            let mut rand = scene.get_rand().borrow_mut();
+
+            (rand.uniform(), Vec2::new(rand.uniform(), rand.uniform()))
+        };
+        let index = ((choice * lights.len() as f32) as usize).min(lights.len() - 1);
+
+        let light_sample = match lights[index].sample(record.get_point(), &u) {
+            Some(light_sample) if light_sample.get_pdf() > 0.0 => light_sample,
+            _ => return Vec3::zeros(),
+        };
+
+        let direction = light_sample.get_direction();
+        let scattering = material.borrow().eval(ray, record, direction);
+
+        if scattering.max() <= 0.0 || light_sample.get_radiance().max() <= 0.0 {
+            return Vec3::zeros();
+        }
+
+        let shadow_ray = Ray::no_norm(*record.get_point(), *direction);
+        let mut shadow_record = HitRecord::default();
+
+        if scene.get_world().hit(
+            &shadow_ray,
+            settings.get_ray_epsilon(),
+            light_sample.get_distance() - settings.get_ray_epsilon(),
+            &mut shadow_record,
+        ) {
+            return Vec3::zeros();
+        }
+
+        let light_pdf = light_sample.get_pdf() / lights.len() as f32;
+        let weight = if lights[index].is_delta() {
+            1.0
+        } else {
+            power_heuristic(light_pdf, material.borrow().pdf(ray, record, direction))
+        };
+
+        return scattering.component_mul(light_sample.get_radiance()) * weight / light_pdf;
+    }
+
+    /// Density of picking the direction of `ray` by choosing a light and sampling it
+    ///
+    /// # Arguments
+    ///
+    /// * `scene`:
+    /// * `ray`: Ray from the lit point
+    /// * `distance`: Distance to the emitter the ray hit
+    fn light_pdf(scene: &Scene, ray: &Ray, distance: f32) -> f32 {
+        let lights = scene.get_lights();
+
+        if lights.is_empty() {
+            return 0.0;
+        }
+
+        let pdf_sum: f32 = lights
+            .iter()
+            .map(|light| light.pdf(ray.get_origin(), ray.get_direction(), distance))
+            .sum();
+
+        return pdf_sum / lights.len() as f32;
     }
 
     pub fn get_buffer(&self) -> &impl ImageBuffer {
         return &self.buffer;
     }
 
+    pub fn get_settings(&self) -> &RenderSettings {
+        return &self.settings;
+    }
+
     pub fn get_total_samples(&self) -> u64 {
         return self.total_samples;
     }
@@ -111,3 +259,36 @@ impl Tracer {
         return TraceResult::new(buffer, total_samples);
     }
 }
+
+/// Weight of a sample taken with density `pdf` when `other_pdf` could have produced it too
+fn power_heuristic(pdf: f32, other_pdf: f32) -> f32 {
+    let pdf_squared = pdf * pdf;
+    let sum = pdf_squared + other_pdf * other_pdf;
+
+    if sum <= 0.0 || !sum.is_finite() {
+        return if pdf >= other_pdf { 1.0 } else { 0.0 };
+    }
+
+    return pdf_squared / sum;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_heuristic_weights_sum_to_one() {
+        for (pdf, other_pdf) in [(1.0, 1.0), (0.3, 2.0), (5.0, 0.01), (0.0, 1.0)] {
+            let sum = power_heuristic(pdf, other_pdf) + power_heuristic(other_pdf, pdf);
+
+            assert!((sum - 1.0).abs() < 1e-6, "{} {}: {}", pdf, other_pdf, sum);
+        }
+    }
+
+    #[test]
+    fn power_heuristic_handles_degenerate_densities() {
+        assert_eq!(power_heuristic(0.0, 0.0), 1.0);
+        assert_eq!(power_heuristic(f32::INFINITY, 1.0), 1.0);
+        assert_eq!(power_heuristic(1.0, f32::INFINITY), 0.0);
+    }
+}
@@ -1,3 +1,77 @@
+use std::f32::consts::PI;
+
+use nalgebra_glm::{Vec2, Vec3};
+
 pub fn index_from_2d(x: usize, y: usize, x_max: usize) -> usize {
     return y * x_max + x;
 }
+
+/// Builds two unit vectors that together with `normal` form an orthonormal basis
+///
+/// # Arguments
+///
+/// * `normal`: Unit vector
+///
+/// returns: (tangent, bitangent)
+pub fn orthonormal_basis(normal: &Vec3) -> (Vec3, Vec3) {
+    let sign = 1.0_f32.copysign(normal.z);
+    let a = -1.0 / (sign + normal.z);
+    let b = normal.x * normal.y * a;
+    let tangent = Vec3::new(
+        1.0 + sign * normal.x * normal.x * a,
+        sign * b,
+        -sign * normal.x,
+    );
+    let bitangent = Vec3::new(b, sign + normal.y * normal.y * a, -normal.y);
+
+    return (tangent, bitangent);
+}
+
+/// One minus cosine of `angle` in radians, precise for tiny angles
+pub fn one_minus_cos(angle: f32) -> f32 {
+    let sin_half = (angle * 0.5).sin();
+
+    return 2.0 * sin_half * sin_half;
+}
+
+/// Picks a direction uniformly inside a cone
+///
+/// # Arguments
+///
+/// * `axis`: Unit axis of the cone
+/// * `cone`: One minus cosine of the angle between the axis and the cone side
+/// * `u`: Two uniform random numbers in [0, 1)
+///
+/// returns: Vec3 Unit direction, picked with density 1 / (2 * PI * cone)
+pub fn sample_cone(axis: &Vec3, cone: f32, u: &Vec2) -> Vec3 {
+    let (tangent, bitangent) = orthonormal_basis(axis);
+    let one_minus_cos = u.y * cone;
+    let cos_theta = 1.0 - one_minus_cos;
+    let sin_theta = (one_minus_cos * (2.0 - one_minus_cos)).max(0.0).sqrt();
+    let phi = 2.0 * PI * u.x;
+
+    return ((tangent * phi.cos() + bitangent * phi.sin()) * sin_theta + axis * cos_theta)
+        .normalize();
+}
+
+/// Direction of light refracted through surface with normal `normal`
+///
+/// # Arguments
+///
+/// * `outgoing`: Unit direction towards the viewer, on the side of `normal`
+/// * `normal`:
+/// * `eta`: Index of refraction on the other side divided by the one of `outgoing` side
+///
+/// returns: None for total internal reflection
+pub fn refract(outgoing: &Vec3, normal: &Vec3, eta: f32) -> Option<Vec3> {
+    let cos_i = outgoing.dot(normal);
+    let sin2_t = (1.0 - cos_i * cos_i).max(0.0) / (eta * eta);
+
+    if sin2_t >= 1.0 {
+        return Option::None;
+    }
+
+    let cos_t = (1.0 - sin2_t).sqrt();
+
+    return Option::Some(-outgoing / eta + normal * (cos_i / eta - cos_t));
+}
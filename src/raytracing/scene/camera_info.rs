@@ -2,6 +2,7 @@ use nalgebra_glm::Vec3;
 
 use crate::misc::camera::Camera;
 use crate::misc::rand_gen::RandGenRc;
+use crate::scene::statement::Statement;
 
 #[derive(Clone)]
 pub struct CameraInfo {
@@ -35,4 +36,21 @@ impl CameraInfo {
             rand,
         );
     }
+
+    pub fn get_aspect_ratio(&self) -> f32 {
+        return self.aspect_ratio;
+    }
+
+    pub fn get_v_fov(&self) -> f32 {
+        return self.v_fov;
+    }
+
+    pub fn to_statement(&self) -> Statement {
+        return Statement::new("camera", Option::None)
+            .with_vec3("origin", &self.origin)
+            .with_vec3("center", &self.center)
+            .with_vec3("up", &self.up)
+            .with_f32("v_fov", self.v_fov)
+            .with_f32("aspect_ratio", self.aspect_ratio);
+    }
 }
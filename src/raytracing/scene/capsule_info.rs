@@ -0,0 +1,85 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::capsule::Capsule;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct CapsuleInfo {
+    name: String,
+    material_name: String,
+    point_one: Vec3,
+    point_two: Vec3,
+    radius: f32,
+}
+
+impl CapsuleInfo {
+    pub fn new(
+        name: &str,
+        material_name: &str,
+        point_one: Vec3,
+        point_two: Vec3,
+        radius: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            point_one,
+            point_two,
+            radius,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        material_name: &str,
+        point_one: Vec3,
+        point_two: Vec3,
+        radius: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, point_one, point_two, radius));
+    }
+}
+
+impl HittableInfo for CapsuleInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Capsule::boxed(self.point_one, self.point_two, self.radius, material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Result::Err(format!("radius must be positive, got {}", self.radius));
+        }
+
+        let length = (self.point_two - self.point_one).magnitude();
+
+        if !(length > 0.0 && length.is_finite()) {
+            return Result::Err("point_one and point_two must differ".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("capsule"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("point_one", &self.point_one)
+            .with_vec3("point_two", &self.point_two)
+            .with_f32("radius", self.radius);
+    }
+}
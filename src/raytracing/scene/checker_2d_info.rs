@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::checker_2d::Checker2d;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct Checker2dInfo {
+    name: String,
+    even: ColorInfo,
+    odd: ColorInfo,
+    scale: f32,
+}
+
+impl Checker2dInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `even`: Color or texture at the origin
+    /// * `odd`: Color or texture of its neighbours
+    /// * `scale`: Number of squares along one unit of surface coordinates
+    pub fn new(name: &str, even: ColorInfo, odd: ColorInfo, scale: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            even,
+            odd,
+            scale,
+        };
+    }
+
+    pub fn boxed(name: &str, even: ColorInfo, odd: ColorInfo, scale: f32) -> Box<Self> {
+        return Box::new(Self::new(name, even, odd, scale));
+    }
+}
+
+impl TextureInfo for Checker2dInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return Checker2d::new_rc(
+            self.even.build(textures),
+            self.odd.build(textures),
+            self.scale,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("checker"))
+            .with_str("name", &self.name)
+            .with_color("even", &self.even)
+            .with_color("odd", &self.odd)
+            .with_f32("scale", self.scale);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return [&self.even, &self.odd]
+            .iter()
+            .filter_map(|x| x.get_texture_name())
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.scale > 0.0 && self.scale.is_finite()) {
+            return Result::Err(format!("scale must be positive, got {}", self.scale));
+        }
+
+        return Result::Ok(());
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::checker_3d::Checker3d;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct Checker3dInfo {
+    name: String,
+    even: ColorInfo,
+    odd: ColorInfo,
+    size: f32,
+}
+
+impl Checker3dInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `even`: Color or texture at the origin
+    /// * `odd`: Color or texture of its neighbours
+    /// * `size`: Edge length of one cube in world units
+    pub fn new(name: &str, even: ColorInfo, odd: ColorInfo, size: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            even,
+            odd,
+            size,
+        };
+    }
+
+    pub fn boxed(name: &str, even: ColorInfo, odd: ColorInfo, size: f32) -> Box<Self> {
+        return Box::new(Self::new(name, even, odd, size));
+    }
+}
+
+impl TextureInfo for Checker3dInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return Checker3d::new_rc(
+            self.even.build(textures),
+            self.odd.build(textures),
+            self.size,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("checker_3d"))
+            .with_str("name", &self.name)
+            .with_color("even", &self.even)
+            .with_color("odd", &self.odd)
+            .with_f32("size", self.size);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return [&self.even, &self.odd]
+            .iter()
+            .filter_map(|x| x.get_texture_name())
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.size > 0.0 && self.size.is_finite()) {
+            return Result::Err(format!("size must be positive, got {}", self.size));
+        }
+
+        return Result::Ok(());
+    }
+}
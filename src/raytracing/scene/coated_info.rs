@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::materials::coated::Coated;
+use crate::materials::ggx::Ggx;
+use crate::materials::material::MaterialRc;
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct CoatedInfo {
+    name: String,
+    base: String,
+    color: ColorInfo,
+    index_of_refraction: f32,
+    roughness: f32,
+}
+
+impl CoatedInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base`: Name of the material under the layer
+    /// * `color`: Color or name of a texture tinting light passing through the layer
+    /// * `index_of_refraction`: Of the layer
+    /// * `roughness`: Of the layer surface, from 0 for a polished one to 1
+    pub fn new(
+        name: &str,
+        base: &str,
+        color: ColorInfo,
+        index_of_refraction: f32,
+        roughness: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            base: base.to_string(),
+            color,
+            index_of_refraction,
+            roughness,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        base: &str,
+        color: ColorInfo,
+        index_of_refraction: f32,
+        roughness: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, base, color, index_of_refraction, roughness));
+    }
+}
+
+impl MaterialInfo for CoatedInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Coated::new_rc(
+            materials[&self.base].clone(),
+            self.color.build(textures),
+            self.index_of_refraction,
+            Ggx::from_roughness(self.roughness, 0.0),
+            rand,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("coated"))
+            .with_str("name", &self.name)
+            .with_str("base", &self.base)
+            .with_color("color", &self.color)
+            .with_f32("index_of_refraction", self.index_of_refraction)
+            .with_f32("roughness", self.roughness);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self.color.get_texture_name().into_iter().collect();
+    }
+
+    fn get_material_names(&self) -> Vec<&str> {
+        return vec![&self.base];
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.index_of_refraction >= 1.0 && self.index_of_refraction.is_finite()) {
+            return Result::Err(format!(
+                "index_of_refraction must be at least 1, got {}",
+                self.index_of_refraction
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.roughness) {
+            return Result::Err(format!(
+                "roughness must be from 0 to 1, got {}",
+                self.roughness
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
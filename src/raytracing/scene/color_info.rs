@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use nalgebra_glm::Vec3;
+
+use crate::textures::solid_color::SolidColor;
+use crate::textures::texture::TextureRc;
+
+/// Color parameter given either directly or by the name of a texture.
+/// Scene files write it as `r,g,b` or as the texture name
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColorInfo {
+    Constant(Vec3),
+    Texture(String),
+}
+
+impl ColorInfo {
+    pub fn parse(value: &str) -> Self {
+        let floats: Result<Vec<f32>, _> = value.split(',').map(|x| x.trim().parse()).collect();
+
+        return match floats {
+            Ok(floats) if floats.len() == 3 => {
+                ColorInfo::Constant(Vec3::new(floats[0], floats[1], floats[2]))
+            }
+            _ => ColorInfo::Texture(value.to_string()),
+        };
+    }
+
+    pub fn get_texture_name(&self) -> Option<&str> {
+        return match self {
+            ColorInfo::Constant(_) => Option::None,
+            ColorInfo::Texture(name) => Option::Some(name),
+        };
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `textures`: Already built textures by name, must contain the referenced one
+    pub fn build(&self, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return match self {
+            ColorInfo::Constant(color) => SolidColor::new_rc(*color),
+            ColorInfo::Texture(name) => textures[name].clone(),
+        };
+    }
+}
+
+impl From<Vec3> for ColorInfo {
+    fn from(color: Vec3) -> Self {
+        return ColorInfo::Constant(color);
+    }
+}
+
+impl From<&str> for ColorInfo {
+    fn from(texture_name: &str) -> Self {
+        return ColorInfo::Texture(texture_name.to_string());
+    }
+}
+
+impl Display for ColorInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ColorInfo::Constant(color) => write!(f, "{},{},{}", color.x, color.y, color.z),
+            ColorInfo::Texture(name) => write!(f, "{}", name),
+        };
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use nalgebra_glm::Vec3;
+
+use crate::materials::conductor::Conductor;
+use crate::materials::ggx::Ggx;
+use crate::materials::material::MaterialRc;
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct ConductorInfo {
+    name: String,
+    eta: Vec3,
+    k: Vec3,
+    roughness: f32,
+    anisotropy: f32,
+}
+
+impl ConductorInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `eta`: Real part of the index of refraction per channel
+    /// * `k`: Extinction coefficient per channel
+    /// * `roughness`: From 0 for a perfect mirror to 1
+    /// * `anisotropy`: From 0 for equal roughness in every direction to 1 for rougher along world x
+    pub fn new(name: &str, eta: Vec3, k: Vec3, roughness: f32, anisotropy: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            eta,
+            k,
+            roughness,
+            anisotropy,
+        };
+    }
+
+    pub fn boxed(name: &str, eta: Vec3, k: Vec3, roughness: f32, anisotropy: f32) -> Box<Self> {
+        return Box::new(Self::new(name, eta, k, roughness, anisotropy));
+    }
+}
+
+impl MaterialInfo for ConductorInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Conductor::new_rc(
+            self.eta,
+            self.k,
+            Ggx::from_roughness(self.roughness, self.anisotropy),
+            rand,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("conductor"))
+            .with_str("name", &self.name)
+            .with_vec3("eta", &self.eta)
+            .with_vec3("k", &self.k)
+            .with_f32("roughness", self.roughness)
+            .with_f32("anisotropy", self.anisotropy);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.roughness) {
+            return Result::Err(format!(
+                "roughness must be from 0 to 1, got {}",
+                self.roughness
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.anisotropy) {
+            return Result::Err(format!(
+                "anisotropy must be from 0 to 1, got {}",
+                self.anisotropy
+            ));
+        }
+
+        if !self.eta.iter().all(|x| *x > 0.0 && x.is_finite()) {
+            return Result::Err(format!(
+                "eta must be positive, got {},{},{}",
+                self.eta.x, self.eta.y, self.eta.z
+            ));
+        }
+
+        if !self.k.iter().all(|x| *x >= 0.0 && x.is_finite()) {
+            return Result::Err(format!(
+                "k must not be negative, got {},{},{}",
+                self.k.x, self.k.y, self.k.z
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
@@ -0,0 +1,90 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::cone::Cone;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct ConeInfo {
+    name: String,
+    material_name: String,
+    base: Vec3,
+    apex: Vec3,
+    radius: f32,
+    capped: bool,
+}
+
+impl ConeInfo {
+    pub fn new(
+        name: &str,
+        material_name: &str,
+        base: Vec3,
+        apex: Vec3,
+        radius: f32,
+        capped: bool,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            base,
+            apex,
+            radius,
+            capped,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        material_name: &str,
+        base: Vec3,
+        apex: Vec3,
+        radius: f32,
+        capped: bool,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, base, apex, radius, capped));
+    }
+}
+
+impl HittableInfo for ConeInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Cone::boxed(self.base, self.apex, self.radius, self.capped, material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Result::Err(format!("radius must be positive, got {}", self.radius));
+        }
+
+        let height = (self.apex - self.base).magnitude();
+
+        if !(height > 0.0 && height.is_finite()) {
+            return Result::Err("base and apex must differ".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn is_closed(&self) -> bool {
+        return self.capped;
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("cone"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("base", &self.base)
+            .with_vec3("apex", &self.apex)
+            .with_f32("radius", self.radius)
+            .with_bool("capped", self.capped);
+    }
+}
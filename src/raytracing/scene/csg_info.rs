@@ -0,0 +1,56 @@
+use crate::hittables::csg::Csg;
+use crate::hittables::csg_operation::CsgOperation;
+use crate::hittables::hittable::Hittable;
+use crate::scene::statement::Statement;
+
+/// Solid made of two closed hittables or other solids, which are not rendered on their own
+#[derive(Clone)]
+pub struct CsgInfo {
+    name: String,
+    operation: CsgOperation,
+    first_name: String,
+    second_name: String,
+}
+
+impl CsgInfo {
+    pub fn new(name: &str, operation: CsgOperation, first_name: &str, second_name: &str) -> Self {
+        return Self {
+            name: name.to_string(),
+            operation,
+            first_name: first_name.to_string(),
+            second_name: second_name.to_string(),
+        };
+    }
+}
+
+impl CsgInfo {
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_operation(&self) -> CsgOperation {
+        return self.operation;
+    }
+
+    pub fn get_operand_names(&self) -> [&str; 2] {
+        return [&self.first_name, &self.second_name];
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `first`: Built hittable or solid named by the first of `get_operand_names`
+    /// * `second`: Built hittable or solid named by the second of `get_operand_names`
+    pub fn build(&self, first: Box<dyn Hittable>, second: Box<dyn Hittable>) -> Box<dyn Hittable> {
+        return Csg::boxed(self.operation, first, second);
+    }
+
+    pub fn to_statement(&self) -> Statement {
+        return Statement::new("csg", Option::None)
+            .with_str("name", &self.name)
+            .with_str("operation", self.operation.get_name())
+            .with_str("first", &self.first_name)
+            .with_str("second", &self.second_name);
+    }
+}
@@ -0,0 +1,69 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::cuboid::Cuboid;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct CuboidInfo {
+    name: String,
+    material_name: String,
+    minimum: Vec3,
+    maximum: Vec3,
+}
+
+impl CuboidInfo {
+    pub fn new(name: &str, material_name: &str, minimum: Vec3, maximum: Vec3) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            minimum,
+            maximum,
+        };
+    }
+
+    pub fn boxed(name: &str, material_name: &str, minimum: Vec3, maximum: Vec3) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, minimum, maximum));
+    }
+}
+
+impl HittableInfo for CuboidInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Cuboid::boxed(self.minimum, self.maximum, material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let size = self.maximum - self.minimum;
+
+        if !size.iter().all(|x| *x > 0.0 && x.is_finite()) {
+            return Result::Err(format!(
+                "maximum must be larger than minimum on every axis, got size {},{},{}",
+                size.x, size.y, size.z
+            ));
+        }
+
+        return Result::Ok(());
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("box"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("minimum", &self.minimum)
+            .with_vec3("maximum", &self.maximum);
+    }
+}
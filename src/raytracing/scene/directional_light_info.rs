@@ -0,0 +1,73 @@
+use nalgebra_glm::Vec3;
+
+use crate::lights::directional_light::DirectionalLight;
+use crate::lights::light::Light;
+use crate::scene::light_info::{validate_color_power, validate_direction, LightInfo};
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct DirectionalLightInfo {
+    name: String,
+    direction: Vec3,
+    color: Vec3,
+    power: f32,
+    angle: f32,
+}
+
+impl DirectionalLightInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `direction`: Direction towards the light
+    /// * `color`:
+    /// * `power`: Power per unit area arriving at a surface facing the light
+    /// * `angle`: Angular diameter of the light in degrees, 0 for perfectly sharp shadows
+    pub fn new(name: &str, direction: Vec3, color: Vec3, power: f32, angle: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            direction,
+            color,
+            power,
+            angle,
+        };
+    }
+
+    pub fn boxed(name: &str, direction: Vec3, color: Vec3, power: f32, angle: f32) -> Box<Self> {
+        return Box::new(Self::new(name, direction, color, power, angle));
+    }
+}
+
+impl LightInfo for DirectionalLightInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self) -> Box<dyn Light> {
+        return DirectionalLight::boxed(self.direction, self.color, self.power, self.angle);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("light", Option::Some("directional"))
+            .with_str("name", &self.name)
+            .with_vec3("direction", &self.direction)
+            .with_vec3("color", &self.color)
+            .with_f32("power", self.power)
+            .with_f32("angle", self.angle);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validate_color_power(&self.color, self.power)?;
+        validate_direction("direction", &self.direction)?;
+
+        if !(0.0..180.0).contains(&self.angle) {
+            return Result::Err(format!(
+                "angle must be from 0 to 180 degrees, got {}",
+                self.angle
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
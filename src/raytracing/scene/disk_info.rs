@@ -0,0 +1,75 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::disk::Disk;
+use crate::hittables::hittable::Hittable;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct DiskInfo {
+    name: String,
+    material_name: String,
+    center: Vec3,
+    normal: Vec3,
+    radius: f32,
+}
+
+impl DiskInfo {
+    pub fn new(name: &str, material_name: &str, center: Vec3, normal: Vec3, radius: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            center,
+            normal,
+            radius,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        material_name: &str,
+        center: Vec3,
+        normal: Vec3,
+        radius: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, center, normal, radius));
+    }
+}
+
+impl HittableInfo for DiskInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Disk::boxed(self.center, self.normal, self.radius, material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Result::Err(format!("radius must be positive, got {}", self.radius));
+        }
+
+        let length = self.normal.magnitude();
+
+        if !(length > 0.0 && length.is_finite()) {
+            return Result::Err("normal must not be zero".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("disk"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("center", &self.center)
+            .with_vec3("normal", &self.normal)
+            .with_f32("radius", self.radius);
+    }
+}
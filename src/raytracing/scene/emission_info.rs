@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use nalgebra_glm::Vec3;
 
 use crate::materials::emission::Emission;
 use crate::materials::material::MaterialRc;
 use crate::misc::rand_gen::RandGenRc;
 use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
 
 #[derive(Clone)]
 pub struct EmissionInfo {
@@ -31,7 +35,23 @@ impl MaterialInfo for EmissionInfo {
         return &self.name;
     }
 
-    fn build(&self, rand: RandGenRc) -> MaterialRc {
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
         return Emission::new_rc(self.color, self.power);
     }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("emission"))
+            .with_str("name", &self.name)
+            .with_vec3("color", &self.color)
+            .with_f32("power", self.power);
+    }
+
+    fn is_emissive(&self) -> bool {
+        return self.power > 0.0 && self.color.max() > 0.0;
+    }
 }
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::lights::environment_light::EnvironmentLight;
+use crate::lights::environment_map::EnvironmentMap;
+use crate::lights::light::Light;
+use crate::scene::light_info::LightInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct EnvironmentInfo {
+    name: String,
+    path: String,
+    map: Arc<EnvironmentMap>,
+    rotation: f32,
+    intensity: f32,
+}
+
+impl EnvironmentInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `path`: Path of the map as written in the scene file, relative to its directory
+    /// * `map`: Loaded map shared by every worker
+    /// * `rotation`: Rotation around z axis in degrees
+    /// * `intensity`: Multiplier of the map radiance
+    pub fn new(
+        name: &str,
+        path: &str,
+        map: Arc<EnvironmentMap>,
+        rotation: f32,
+        intensity: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            path: path.to_string(),
+            map,
+            rotation,
+            intensity,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        path: &str,
+        map: Arc<EnvironmentMap>,
+        rotation: f32,
+        intensity: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, path, map, rotation, intensity));
+    }
+}
+
+impl LightInfo for EnvironmentInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self) -> Box<dyn Light> {
+        return EnvironmentLight::boxed(self.map.clone(), self.rotation, self.intensity);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("light", Option::Some("environment"))
+            .with_str("name", &self.name)
+            .with_str("path", &self.path)
+            .with_f32("rotation", self.rotation)
+            .with_f32("intensity", self.intensity);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.intensity >= 0.0 && self.intensity.is_finite()) {
+            return Result::Err(format!(
+                "intensity must not be negative, got {}",
+                self.intensity
+            ));
+        }
+
+        if !self.rotation.is_finite() {
+            return Result::Err(format!("rotation must be finite, got {}", self.rotation));
+        }
+
+        return Result::Ok(());
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::scene::statement::Statement;
+
+/// Triangle geometry shared by instances, not rendered on its own
+#[derive(Clone)]
+pub struct GeometryInfo {
+    name: String,
+    geometry: Arc<MeshGeometry>,
+}
+
+impl GeometryInfo {
+    pub fn new(name: &str, geometry: Arc<MeshGeometry>) -> Self {
+        return Self {
+            name: name.to_string(),
+            geometry,
+        };
+    }
+}
+
+impl GeometryInfo {
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_geometry(&self) -> &Arc<MeshGeometry> {
+        return &self.geometry;
+    }
+
+    /// Checks the geometry can be built into something hittable
+    ///
+    /// returns: String with the reason it is degenerate or nothing otherwise
+    pub fn validate(&self) -> Result<(), String> {
+        if self.geometry.get_triangles().is_empty() {
+            return Result::Err("geometry has no triangles".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    pub fn to_statement(&self) -> Statement {
+        return Statement::new("geometry", Option::None)
+            .with_str("name", &self.name)
+            .with_geometry(&self.geometry);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::hittables::hittable::Hittable;
 use crate::materials::material::MaterialRc;
+use crate::scene::statement::Statement;
 
 pub trait HittableInfoClone {
     fn clone_box(&self) -> Box<dyn HittableInfo>;
@@ -9,6 +10,19 @@ pub trait HittableInfo: HittableInfoClone {
     fn get_name(&self) -> &str;
     fn get_material_name(&self) -> &str;
     fn build(&self, material: MaterialRc) -> Box<dyn Hittable>;
+    fn to_statement(&self) -> Statement;
+
+    /// Checks the geometry can be built into something hittable
+    ///
+    /// returns: String with the reason it is degenerate or nothing otherwise
+    fn validate(&self) -> Result<(), String> {
+        return Result::Ok(());
+    }
+
+    /// Whether the built hittable encloses a volume, so it can be used by solid operations
+    fn is_closed(&self) -> bool {
+        return false;
+    }
 }
 
 impl<T> HittableInfoClone for T
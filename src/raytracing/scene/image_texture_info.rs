@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::image::linear_image::LinearImage;
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::image_texture::ImageTexture;
+use crate::textures::texture::TextureRc;
+use crate::textures::texture_filter::TextureFilter;
+use crate::textures::wrap_mode::WrapMode;
+
+#[derive(Clone)]
+pub struct ImageTextureInfo {
+    name: String,
+    path: String,
+    image: Arc<LinearImage>,
+    wrap_mode: WrapMode,
+    filter: TextureFilter,
+}
+
+impl ImageTextureInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `path`: Path of the image as written in the scene file, relative to its directory
+    /// * `image`: Loaded image shared by every worker
+    /// * `wrap_mode`:
+    /// * `filter`:
+    pub fn new(
+        name: &str,
+        path: &str,
+        image: Arc<LinearImage>,
+        wrap_mode: WrapMode,
+        filter: TextureFilter,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            path: path.to_string(),
+            image,
+            wrap_mode,
+            filter,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        path: &str,
+        image: Arc<LinearImage>,
+        wrap_mode: WrapMode,
+        filter: TextureFilter,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, path, image, wrap_mode, filter));
+    }
+}
+
+impl TextureInfo for ImageTextureInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return ImageTexture::new_rc(self.image.clone(), self.wrap_mode, self.filter);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("image"))
+            .with_str("name", &self.name)
+            .with_str("path", &self.path)
+            .with_str("wrap", self.wrap_mode.get_name())
+            .with_str("filter", self.filter.get_name());
+    }
+}
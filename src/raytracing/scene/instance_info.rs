@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use nalgebra_glm::Mat4;
+
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::hittables::transformed::Transformed;
+use crate::hittables::triangle_mesh::TriangleMesh;
+use crate::materials::material::MaterialRc;
+use crate::scene::statement::Statement;
+
+/// Copy of shared geometry with its own material, placed by the transform of its name
+#[derive(Clone)]
+pub struct InstanceInfo {
+    name: String,
+    material_name: String,
+    geometry_name: String,
+}
+
+impl InstanceInfo {
+    pub fn new(name: &str, material_name: &str, geometry_name: &str) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            geometry_name: geometry_name.to_string(),
+        };
+    }
+}
+
+impl InstanceInfo {
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    pub fn get_geometry_name(&self) -> &str {
+        return &self.geometry_name;
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry`: Geometry named by `get_geometry_name`, shared with other instances
+    /// * `material`:
+    /// * `matrix`: From geometry coordinates to the world ones
+    pub fn build(
+        &self,
+        geometry: Arc<MeshGeometry>,
+        material: MaterialRc,
+        matrix: Mat4,
+    ) -> Transformed<TriangleMesh> {
+        return Transformed::new(TriangleMesh::new(geometry, material), matrix);
+    }
+
+    pub fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("instance"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_str("geometry", &self.geometry_name);
+    }
+}
@@ -1,25 +1,28 @@
-use nalgebra_glm::Vec3;
+use std::collections::HashMap;
 
 use crate::materials::lambertian::Lambertian;
 use crate::materials::material::MaterialRc;
 use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
 use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
 
 #[derive(Clone)]
 pub struct LambertianInfo {
     name: String,
-    color: Vec3,
+    color: ColorInfo,
 }
 
 impl LambertianInfo {
-    pub fn new(name: &str, color: Vec3) -> Self {
+    pub fn new(name: &str, color: ColorInfo) -> Self {
         return Self {
             name: name.to_string(),
             color,
         };
     }
 
-    pub fn boxed(name: &str, color: Vec3) -> Box<Self> {
+    pub fn boxed(name: &str, color: ColorInfo) -> Box<Self> {
         return Box::new(Self::new(name, color));
     }
 }
@@ -29,7 +32,22 @@ impl MaterialInfo for LambertianInfo {
         return &self.name;
     }
 
-    fn build(&self, rand: RandGenRc) -> MaterialRc {
-        return Lambertian::new_rc(self.color, rand);
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Lambertian::new_rc(self.color.build(textures), rand);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("lambertian"))
+            .with_str("name", &self.name)
+            .with_color("color", &self.color);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self.color.get_texture_name().into_iter().collect();
     }
 }
@@ -0,0 +1,61 @@
+use nalgebra_glm::Vec3;
+
+use crate::lights::light::Light;
+use crate::scene::statement::Statement;
+
+pub trait LightInfoClone {
+    fn clone_box(&self) -> Box<dyn LightInfo>;
+}
+
+/// Light that is not a hittable, like an environment map
+pub trait LightInfo: LightInfoClone {
+    fn get_name(&self) -> &str;
+    fn build(&self) -> Box<dyn Light>;
+    fn to_statement(&self) -> Statement;
+
+    /// Checks the light can be built
+    ///
+    /// returns: String with the reason it is invalid or nothing otherwise
+    fn validate(&self) -> Result<(), String> {
+        return Result::Ok(());
+    }
+}
+
+impl<T> LightInfoClone for T
+where
+    T: LightInfo + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn LightInfo> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn LightInfo> {
+    fn clone(&self) -> Self {
+        return self.clone_box();
+    }
+}
+
+/// Checks color and power of a light can not take light away
+pub fn validate_color_power(color: &Vec3, power: f32) -> Result<(), String> {
+    if color.iter().any(|x| !(*x >= 0.0 && x.is_finite())) {
+        return Result::Err("color components must not be negative".to_string());
+    }
+
+    if !(power >= 0.0 && power.is_finite()) {
+        return Result::Err(format!("power must not be negative, got {}", power));
+    }
+
+    return Result::Ok(());
+}
+
+/// Checks `direction` can be normalized
+pub fn validate_direction(key: &str, direction: &Vec3) -> Result<(), String> {
+    let length = direction.magnitude();
+
+    if !(length > 0.0 && length.is_finite()) {
+        return Result::Err(format!("{} must be a non-zero vector", key));
+    }
+
+    return Result::Ok(());
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::seeded_rand_gen::SeededRandGen;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::marble::Marble;
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct MarbleInfo {
+    name: String,
+    base: ColorInfo,
+    vein: ColorInfo,
+    scale: f32,
+    turbulence: f32,
+    seed: u32,
+}
+
+impl MarbleInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base`: Color or texture between the veins
+    /// * `vein`: Color or texture of the veins
+    /// * `scale`: Number of veins along one world unit of x
+    /// * `turbulence`: How far the veins are bent, in distances between them
+    /// * `seed`: Added to the seed drawn from the scene generator
+    pub fn new(
+        name: &str,
+        base: ColorInfo,
+        vein: ColorInfo,
+        scale: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            base,
+            vein,
+            scale,
+            turbulence,
+            seed,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        base: ColorInfo,
+        vein: ColorInfo,
+        scale: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, base, vein, scale, turbulence, seed));
+    }
+}
+
+impl TextureInfo for MarbleInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        let perlin = Perlin::new(&mut SeededRandGen::derived(
+            &mut **rand.borrow_mut(),
+            self.seed,
+        ));
+
+        return Marble::new_rc(
+            perlin,
+            self.base.build(textures),
+            self.vein.build(textures),
+            self.scale,
+            self.turbulence,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("marble"))
+            .with_str("name", &self.name)
+            .with_color("base", &self.base)
+            .with_color("vein", &self.vein)
+            .with_f32("scale", self.scale)
+            .with_f32("turbulence", self.turbulence)
+            .with_u32("seed", self.seed);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return [&self.base, &self.vein]
+            .iter()
+            .filter_map(|x| x.get_texture_name())
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.scale > 0.0 && self.scale.is_finite()) {
+            return Result::Err(format!("scale must be positive, got {}", self.scale));
+        }
+
+        if !(self.turbulence >= 0.0 && self.turbulence.is_finite()) {
+            return Result::Err(format!(
+                "turbulence must not be negative, got {}",
+                self.turbulence
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
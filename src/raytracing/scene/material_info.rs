@@ -1,9 +1,48 @@
+use std::collections::HashMap;
+
 use crate::materials::material::MaterialRc;
 use crate::misc::rand_gen::RandGenRc;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
 
 pub trait MaterialInfo: MaterialInfoClone {
     fn get_name(&self) -> &str;
-    fn build(&self, rand: RandGenRc) -> MaterialRc;
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `rand`:
+    /// * `textures`: Every texture of the scene by name
+    /// * `materials`: Already built materials by name, containing those from `get_material_names`
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc;
+    fn to_statement(&self) -> Statement;
+
+    /// Names of textures used by the material
+    fn get_texture_names(&self) -> Vec<&str> {
+        return Vec::new();
+    }
+
+    /// Names of materials this one is made of
+    fn get_material_names(&self) -> Vec<&str> {
+        return Vec::new();
+    }
+
+    /// Whether hittables with this material are sampled as lights
+    fn is_emissive(&self) -> bool {
+        return false;
+    }
+
+    /// Checks parameters which would make the material meaningless
+    ///
+    /// returns: String with text what is wrong
+    fn validate(&self) -> Result<(), String> {
+        return Result::Ok(());
+    }
 }
 
 pub trait MaterialInfoClone {
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::hittables::hittable::Hittable;
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::hittables::triangle_mesh::TriangleMesh;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct MeshInfo {
+    name: String,
+    material_name: String,
+    geometry: Arc<MeshGeometry>,
+}
+
+impl MeshInfo {
+    pub fn new(name: &str, material_name: &str, geometry: Arc<MeshGeometry>) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            geometry,
+        };
+    }
+
+    pub fn boxed(name: &str, material_name: &str, geometry: Arc<MeshGeometry>) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, geometry));
+    }
+}
+
+impl HittableInfo for MeshInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return TriangleMesh::boxed(self.geometry.clone(), material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.geometry.get_triangles().is_empty() {
+            return Result::Err("mesh has no triangles".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("mesh"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_geometry(&self.geometry);
+    }
+}
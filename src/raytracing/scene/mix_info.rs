@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use crate::materials::material::MaterialRc;
+use crate::materials::mix::Mix;
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::material_info::MaterialInfo;
+use crate::scene::scalar_info::ScalarInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct MixInfo {
+    name: String,
+    first: String,
+    second: String,
+    factor: ScalarInfo,
+}
+
+impl MixInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `first`: Name of a material
+    /// * `second`: Name of a material
+    /// * `factor`: Share of `second` from 0 to 1
+    pub fn new(name: &str, first: &str, second: &str, factor: ScalarInfo) -> Self {
+        return Self {
+            name: name.to_string(),
+            first: first.to_string(),
+            second: second.to_string(),
+            factor,
+        };
+    }
+
+    pub fn boxed(name: &str, first: &str, second: &str, factor: ScalarInfo) -> Box<Self> {
+        return Box::new(Self::new(name, first, second, factor));
+    }
+}
+
+impl MaterialInfo for MixInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Mix::new_rc(
+            materials[&self.first].clone(),
+            materials[&self.second].clone(),
+            self.factor.build(textures),
+            rand,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("mix"))
+            .with_str("name", &self.name)
+            .with_str("first", &self.first)
+            .with_str("second", &self.second)
+            .with_scalar("factor", &self.factor);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self.factor.get_texture_name().into_iter().collect();
+    }
+
+    fn get_material_names(&self) -> Vec<&str> {
+        return vec![&self.first, &self.second];
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let Some(factor) = self.factor.get_constant() {
+            if !(0.0..=1.0).contains(&factor) {
+                return Result::Err(format!("factor must be from 0 to 1, got {}", factor));
+            }
+        }
+
+        return Result::Ok(());
+    }
+}
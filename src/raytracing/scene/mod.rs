@@ -1,13 +1,49 @@
 pub mod camera_info;
+pub mod capsule_info;
+pub mod checker_2d_info;
+pub mod checker_3d_info;
+pub mod coated_info;
+pub mod color_info;
+pub mod conductor_info;
+pub mod cone_info;
+pub mod csg_info;
+pub mod cuboid_info;
+pub mod cylinder_info;
+pub mod directional_light_info;
+pub mod disk_info;
 pub mod emission_info;
+pub mod environment_info;
+pub mod geometry_info;
 pub mod hittable_info;
+pub mod image_texture_info;
+pub mod instance_info;
 pub mod lambertian_info;
+pub mod light_info;
+pub mod marble_info;
 pub mod material_info;
+pub mod mesh_info;
+pub mod mix_info;
+pub mod noise_info;
+pub mod point_light_info;
+pub mod principled_info;
+pub mod quad_info;
 pub mod reflective_info;
 pub mod refractive_info;
+pub mod scalar_info;
+pub mod scene_error;
 pub mod scene_info;
+pub mod scene_problem;
+pub mod scene_reader;
+pub mod scene_writer;
+pub mod sky_info;
+pub mod solid_color_info;
 pub mod sphere_info;
+pub mod spot_light_info;
+pub mod statement;
+pub mod texture_info;
+pub mod torus_info;
 pub mod triangle_info;
+pub mod wood_info;
 pub mod xy_rect_info;
 pub mod xz_rect_info;
 pub mod yz_rect_info;
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::seeded_rand_gen::SeededRandGen;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::noise_texture::NoiseTexture;
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::TextureRc;
+
+const MAX_OCTAVES: u32 = 16;
+
+#[derive(Clone)]
+pub struct NoiseInfo {
+    name: String,
+    low: ColorInfo,
+    high: ColorInfo,
+    scale: f32,
+    octaves: u32,
+    seed: u32,
+}
+
+impl NoiseInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `low`: Color or texture where the noise is lowest
+    /// * `high`: Color or texture where the noise is highest
+    /// * `scale`: Frequency of the first octave in world units
+    /// * `octaves`: Number of noise layers, 1 for plain Perlin noise
+    /// * `seed`: Added to the seed drawn from the scene generator
+    pub fn new(
+        name: &str,
+        low: ColorInfo,
+        high: ColorInfo,
+        scale: f32,
+        octaves: u32,
+        seed: u32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            low,
+            high,
+            scale,
+            octaves,
+            seed,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        low: ColorInfo,
+        high: ColorInfo,
+        scale: f32,
+        octaves: u32,
+        seed: u32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, low, high, scale, octaves, seed));
+    }
+}
+
+impl TextureInfo for NoiseInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        let perlin = Perlin::new(&mut SeededRandGen::derived(
+            &mut **rand.borrow_mut(),
+            self.seed,
+        ));
+
+        return NoiseTexture::new_rc(
+            perlin,
+            self.low.build(textures),
+            self.high.build(textures),
+            self.scale,
+            self.octaves,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("noise"))
+            .with_str("name", &self.name)
+            .with_color("low", &self.low)
+            .with_color("high", &self.high)
+            .with_f32("scale", self.scale)
+            .with_u32("octaves", self.octaves)
+            .with_u32("seed", self.seed);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return [&self.low, &self.high]
+            .iter()
+            .filter_map(|x| x.get_texture_name())
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.scale > 0.0 && self.scale.is_finite()) {
+            return Result::Err(format!("scale must be positive, got {}", self.scale));
+        }
+
+        if !(1..=MAX_OCTAVES).contains(&self.octaves) {
+            return Result::Err(format!(
+                "octaves must be from 1 to {}, got {}",
+                MAX_OCTAVES, self.octaves
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
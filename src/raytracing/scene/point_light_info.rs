@@ -0,0 +1,51 @@
+use nalgebra_glm::Vec3;
+
+use crate::lights::light::Light;
+use crate::lights::point_light::PointLight;
+use crate::scene::light_info::{validate_color_power, LightInfo};
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct PointLightInfo {
+    name: String,
+    position: Vec3,
+    color: Vec3,
+    power: f32,
+}
+
+impl PointLightInfo {
+    pub fn new(name: &str, position: Vec3, color: Vec3, power: f32) -> Self {
+        return Self {
+            name: name.to_string(),
+            position,
+            color,
+            power,
+        };
+    }
+
+    pub fn boxed(name: &str, position: Vec3, color: Vec3, power: f32) -> Box<Self> {
+        return Box::new(Self::new(name, position, color, power));
+    }
+}
+
+impl LightInfo for PointLightInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self) -> Box<dyn Light> {
+        return PointLight::boxed(self.position, self.color, self.power);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("light", Option::Some("point"))
+            .with_str("name", &self.name)
+            .with_vec3("position", &self.position)
+            .with_vec3("color", &self.color)
+            .with_f32("power", self.power);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        return validate_color_power(&self.color, self.power);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::materials::material::MaterialRc;
+use crate::materials::principled::Principled;
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::material_info::MaterialInfo;
+use crate::scene::scalar_info::ScalarInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct PrincipledInfo {
+    name: String,
+    base_color: ColorInfo,
+    metallic: ScalarInfo,
+    roughness: ScalarInfo,
+    specular: ScalarInfo,
+    clearcoat: ScalarInfo,
+    sheen: ScalarInfo,
+    transmission: ScalarInfo,
+}
+
+impl PrincipledInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base_color`: Diffuse color, reflection color of metals and transmission tint
+    /// * `metallic`: From 0 for dielectrics to 1 for metals
+    /// * `roughness`: From 0 for mirror-like reflection to 1
+    /// * `specular`: Reflectance of dielectrics at normal incidence, 0.5 is 4%
+    /// * `clearcoat`: Strength of the clear coat layer
+    /// * `sheen`: Strength of the grazing reflection of cloth
+    /// * `transmission`: Part of dielectric light passing through the surface
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        base_color: ColorInfo,
+        metallic: ScalarInfo,
+        roughness: ScalarInfo,
+        specular: ScalarInfo,
+        clearcoat: ScalarInfo,
+        sheen: ScalarInfo,
+        transmission: ScalarInfo,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            base_color,
+            metallic,
+            roughness,
+            specular,
+            clearcoat,
+            sheen,
+            transmission,
+        };
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn boxed(
+        name: &str,
+        base_color: ColorInfo,
+        metallic: ScalarInfo,
+        roughness: ScalarInfo,
+        specular: ScalarInfo,
+        clearcoat: ScalarInfo,
+        sheen: ScalarInfo,
+        transmission: ScalarInfo,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            name,
+            base_color,
+            metallic,
+            roughness,
+            specular,
+            clearcoat,
+            sheen,
+            transmission,
+        ));
+    }
+}
+
+impl PrincipledInfo {
+    /// Number parameters with their names in scene files
+    fn get_scalars(&self) -> [(&str, &ScalarInfo); 6] {
+        return [
+            ("metallic", &self.metallic),
+            ("roughness", &self.roughness),
+            ("specular", &self.specular),
+            ("clearcoat", &self.clearcoat),
+            ("sheen", &self.sheen),
+            ("transmission", &self.transmission),
+        ];
+    }
+}
+
+impl MaterialInfo for PrincipledInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Principled::new_rc(
+            self.base_color.build(textures),
+            self.metallic.build(textures),
+            self.roughness.build(textures),
+            self.specular.build(textures),
+            self.clearcoat.build(textures),
+            self.sheen.build(textures),
+            self.transmission.build(textures),
+            rand,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return self.get_scalars().iter().fold(
+            Statement::new("material", Option::Some("principled"))
+                .with_str("name", &self.name)
+                .with_color("base_color", &self.base_color),
+            |statement, (key, value)| statement.with_scalar(key, value),
+        );
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self
+            .base_color
+            .get_texture_name()
+            .into_iter()
+            .chain(
+                self.get_scalars()
+                    .into_iter()
+                    .filter_map(|(_, x)| x.get_texture_name()),
+            )
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for (key, value) in self.get_scalars() {
+            if let Some(value) = value.get_constant() {
+                if !(0.0..=1.0).contains(&value) {
+                    return Result::Err(format!("{} must be from 0 to 1, got {}", key, value));
+                }
+            }
+        }
+
+        return Result::Ok(());
+    }
+}
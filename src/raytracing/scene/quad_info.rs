@@ -0,0 +1,77 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::hittable::Hittable;
+use crate::hittables::quad::Quad;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct QuadInfo {
+    name: String,
+    material_name: String,
+    origin: Vec3,
+    edge_one: Vec3,
+    edge_two: Vec3,
+}
+
+impl QuadInfo {
+    pub fn new(
+        name: &str,
+        material_name: &str,
+        origin: Vec3,
+        edge_one: Vec3,
+        edge_two: Vec3,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            origin,
+            edge_one,
+            edge_two,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        material_name: &str,
+        origin: Vec3,
+        edge_one: Vec3,
+        edge_two: Vec3,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, material_name, origin, edge_one, edge_two));
+    }
+}
+
+impl HittableInfo for QuadInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Quad::boxed(self.origin, self.edge_one, self.edge_two, material);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let area = self.edge_one.cross(&self.edge_two).magnitude();
+
+        if !(area > 0.0 && area.is_finite()) {
+            return Result::Err("edges must not be zero or parallel".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("quad"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("origin", &self.origin)
+            .with_vec3("edge_one", &self.edge_one)
+            .with_vec3("edge_two", &self.edge_two);
+    }
+}
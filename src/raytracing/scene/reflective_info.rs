@@ -1,28 +1,31 @@
-use nalgebra_glm::Vec3;
+use std::collections::HashMap;
 
 use crate::materials::material::MaterialRc;
 use crate::materials::reflective::Reflective;
 use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
 use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
 
 #[derive(Clone)]
 pub struct ReflectiveInfo {
     name: String,
-    color: Vec3,
-    reflectiveness: f32,
+    color: ColorInfo,
+    exponent: f32,
 }
 
 impl ReflectiveInfo {
-    pub fn new(name: &str, color: Vec3, reflectiveness: f32) -> Self {
+    pub fn new(name: &str, color: ColorInfo, exponent: f32) -> Self {
         return Self {
             name: name.to_string(),
             color,
-            reflectiveness,
+            exponent,
         };
     }
 
-    pub fn boxed(name: &str, color: Vec3, reflectiveness: f32) -> Box<Self> {
-        return Box::new(Self::new(name, color, reflectiveness));
+    pub fn boxed(name: &str, color: ColorInfo, exponent: f32) -> Box<Self> {
+        return Box::new(Self::new(name, color, exponent));
     }
 }
 
@@ -31,7 +34,23 @@ impl MaterialInfo for ReflectiveInfo {
         return &self.name;
     }
 
-    fn build(&self, rand: RandGenRc) -> MaterialRc {
-        return Reflective::new_rc(self.color, self.reflectiveness, rand);
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Reflective::new_rc(self.color.build(textures), self.exponent, rand);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("reflective"))
+            .with_str("name", &self.name)
+            .with_color("color", &self.color)
+            .with_f32("exponent", self.exponent);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self.color.get_texture_name().into_iter().collect();
     }
 }
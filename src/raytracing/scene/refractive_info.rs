@@ -1,28 +1,65 @@
+use std::collections::HashMap;
+
 use nalgebra_glm::Vec3;
 
+use crate::materials::ggx::Ggx;
 use crate::materials::material::MaterialRc;
 use crate::materials::refractive::Refractive;
 use crate::misc::rand_gen::RandGenRc;
+use crate::scene::color_info::ColorInfo;
 use crate::scene::material_info::MaterialInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
 
 #[derive(Clone)]
 pub struct RefractiveInfo {
     name: String,
-    color: Vec3,
+    color: ColorInfo,
     index_of_refraction: f32,
+    roughness: f32,
+    absorption: Vec3,
 }
 
 impl RefractiveInfo {
-    pub fn new(name: &str, color: Vec3, index_of_refraction: f32) -> Self {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `color`: Color or name of a texture tinting light passing through the surface
+    /// * `index_of_refraction`:
+    /// * `roughness`: From 0 for clear glass to 1
+    /// * `absorption`: Part of light absorbed per unit of distance travelled inside, per channel
+    pub fn new(
+        name: &str,
+        color: ColorInfo,
+        index_of_refraction: f32,
+        roughness: f32,
+        absorption: Vec3,
+    ) -> Self {
         return Self {
             name: name.to_string(),
             color,
             index_of_refraction,
+            roughness,
+            absorption,
         };
     }
 
-    pub fn boxed(name: &str, color: Vec3, index_of_refraction: f32) -> Box<Self> {
-        return Box::new(Self::new(name, color, index_of_refraction));
+    pub fn boxed(
+        name: &str,
+        color: ColorInfo,
+        index_of_refraction: f32,
+        roughness: f32,
+        absorption: Vec3,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            name,
+            color,
+            index_of_refraction,
+            roughness,
+            absorption,
+        ));
     }
 }
 
@@ -31,7 +68,56 @@ impl MaterialInfo for RefractiveInfo {
         return &self.name;
     }
 
-    fn build(&self, rand: RandGenRc) -> MaterialRc {
-        return Refractive::new_rc(self.color, self.index_of_refraction, rand);
+    fn build(
+        &self,
+        rand: RandGenRc,
+        textures: &HashMap<String, TextureRc>,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> MaterialRc {
+        return Refractive::new_rc(
+            self.color.build(textures),
+            self.index_of_refraction,
+            Ggx::from_roughness(self.roughness, 0.0),
+            self.absorption,
+            rand,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("material", Option::Some("refractive"))
+            .with_str("name", &self.name)
+            .with_color("color", &self.color)
+            .with_f32("index_of_refraction", self.index_of_refraction)
+            .with_f32("roughness", self.roughness)
+            .with_vec3("absorption", &self.absorption);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return self.color.get_texture_name().into_iter().collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.index_of_refraction > 0.0 && self.index_of_refraction.is_finite()) {
+            return Result::Err(format!(
+                "index_of_refraction must be positive, got {}",
+                self.index_of_refraction
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.roughness) {
+            return Result::Err(format!(
+                "roughness must be from 0 to 1, got {}",
+                self.roughness
+            ));
+        }
+
+        if !self.absorption.iter().all(|x| *x >= 0.0 && x.is_finite()) {
+            return Result::Err(format!(
+                "absorption must not be negative, got {},{},{}",
+                self.absorption.x, self.absorption.y, self.absorption.z
+            ));
+        }
+
+        return Result::Ok(());
     }
 }
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use nalgebra_glm::Vec3;
+
+use crate::textures::solid_color::SolidColor;
+use crate::textures::texture::TextureRc;
+
+/// Number parameter given either directly or by the name of a texture read as the mean
+/// of its channels. Scene files write it as a number or as the texture name
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScalarInfo {
+    Constant(f32),
+    Texture(String),
+}
+
+impl ScalarInfo {
+    pub fn parse(value: &str) -> Self {
+        return match value.trim().parse() {
+            Ok(value) => ScalarInfo::Constant(value),
+            _ => ScalarInfo::Texture(value.to_string()),
+        };
+    }
+
+    pub fn get_texture_name(&self) -> Option<&str> {
+        return match self {
+            ScalarInfo::Constant(_) => Option::None,
+            ScalarInfo::Texture(name) => Option::Some(name),
+        };
+    }
+
+    /// Value known before the scene is built, None for textures
+    pub fn get_constant(&self) -> Option<f32> {
+        return match self {
+            ScalarInfo::Constant(value) => Option::Some(*value),
+            ScalarInfo::Texture(_) => Option::None,
+        };
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `textures`: Already built textures by name, must contain the referenced one
+    pub fn build(&self, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return match self {
+            ScalarInfo::Constant(value) => SolidColor::new_rc(Vec3::from_element(*value)),
+            ScalarInfo::Texture(name) => textures[name].clone(),
+        };
+    }
+}
+
+impl From<f32> for ScalarInfo {
+    fn from(value: f32) -> Self {
+        return ScalarInfo::Constant(value);
+    }
+}
+
+impl From<&str> for ScalarInfo {
+    fn from(texture_name: &str) -> Self {
+        return ScalarInfo::Texture(texture_name.to_string());
+    }
+}
+
+impl Display for ScalarInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            ScalarInfo::Constant(value) => write!(f, "{}", value),
+            ScalarInfo::Texture(name) => write!(f, "{}", name),
+        };
+    }
+}
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::scene::scene_problem::SceneProblem;
+
+/// Every problem found while validating a `SceneInfo`
+#[derive(Clone, Debug)]
+pub struct SceneError {
+    problems: Vec<SceneProblem>,
+}
+
+impl SceneError {
+    pub fn new(problems: Vec<SceneProblem>) -> Self {
+        return Self { problems };
+    }
+}
+
+impl SceneError {
+    pub fn get_problems(&self) -> &[SceneProblem] {
+        return &self.problems;
+    }
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Scene has {} problem(s):", self.problems.len())?;
+
+        for problem in &self.problems {
+            write!(f, "\n  {}", problem)?;
+        }
+
+        return Result::Ok(());
+    }
+}
+
+impl Error for SceneError {}
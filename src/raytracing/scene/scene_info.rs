@@ -1,29 +1,85 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use nalgebra_glm::Vec3;
+use nalgebra_glm::{Mat4, Vec3};
 
+use crate::hittables::bvh::acceleration::Acceleration;
+use crate::hittables::bvh::instance_bvh::InstanceBvh;
+use crate::hittables::csg_operation::CsgOperation;
+use crate::hittables::hittable::Hittable;
 use crate::hittables::hittable_list::HittableList;
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::hittables::transformed::{validate_transform, Transformed};
+use crate::image::linear_image::LinearImage;
+use crate::lights::area_light::AreaLight;
+use crate::lights::environment_map::EnvironmentMap;
+use crate::lights::light::Light;
+use crate::materials::material::MaterialRc;
+use crate::materials::metal::Metal;
 use crate::misc::rand_gen::RandGenRc;
 use crate::misc::scene::Scene;
 use crate::scene::camera_info::CameraInfo;
+use crate::scene::capsule_info::CapsuleInfo;
+use crate::scene::checker_2d_info::Checker2dInfo;
+use crate::scene::checker_3d_info::Checker3dInfo;
+use crate::scene::coated_info::CoatedInfo;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::conductor_info::ConductorInfo;
+use crate::scene::cone_info::ConeInfo;
+use crate::scene::csg_info::CsgInfo;
+use crate::scene::cuboid_info::CuboidInfo;
+use crate::scene::cylinder_info::CylinderInfo;
+use crate::scene::directional_light_info::DirectionalLightInfo;
+use crate::scene::disk_info::DiskInfo;
 use crate::scene::emission_info::EmissionInfo;
+use crate::scene::environment_info::EnvironmentInfo;
+use crate::scene::geometry_info::GeometryInfo;
 use crate::scene::hittable_info::HittableInfo;
+use crate::scene::image_texture_info::ImageTextureInfo;
+use crate::scene::instance_info::InstanceInfo;
 use crate::scene::lambertian_info::LambertianInfo;
+use crate::scene::light_info::LightInfo;
+use crate::scene::marble_info::MarbleInfo;
 use crate::scene::material_info::MaterialInfo;
+use crate::scene::mesh_info::MeshInfo;
+use crate::scene::mix_info::MixInfo;
+use crate::scene::noise_info::NoiseInfo;
+use crate::scene::point_light_info::PointLightInfo;
+use crate::scene::principled_info::PrincipledInfo;
+use crate::scene::quad_info::QuadInfo;
 use crate::scene::reflective_info::ReflectiveInfo;
 use crate::scene::refractive_info::RefractiveInfo;
+use crate::scene::scalar_info::ScalarInfo;
+use crate::scene::scene_error::SceneError;
+use crate::scene::scene_problem::SceneProblem;
+use crate::scene::sky_info::SkyInfo;
+use crate::scene::solid_color_info::SolidColorInfo;
 use crate::scene::sphere_info::SphereInfo;
+use crate::scene::spot_light_info::SpotLightInfo;
+use crate::scene::texture_info::TextureInfo;
+use crate::scene::torus_info::TorusInfo;
 use crate::scene::triangle_info::TriangleInfo;
+use crate::scene::wood_info::WoodInfo;
 use crate::scene::xy_rect_info::XyRectInfo;
 use crate::scene::xz_rect_info::XzRectInfo;
 use crate::scene::yz_rect_info::YzRectInfo;
+use crate::textures::texture_filter::TextureFilter;
+use crate::textures::wrap_mode::WrapMode;
 
 #[derive(Clone)]
 pub struct SceneInfo {
     camera: Option<CameraInfo>,
     materials: HashMap<String, Box<dyn MaterialInfo>>,
     hittables: Vec<Box<dyn HittableInfo>>,
+    geometries: HashMap<String, GeometryInfo>,
+    instances: Vec<InstanceInfo>,
+    csgs: Vec<CsgInfo>,
+    // By hittable name, from its own coordinates to the world ones
+    transforms: HashMap<String, Mat4>,
+    lights: Vec<Box<dyn LightInfo>>,
+    // In declaration order, textures are made only of textures declared before them
+    textures: Vec<Box<dyn TextureInfo>>,
+    acceleration: Acceleration,
     rand_producer: Arc<Box<dyn Fn() -> RandGenRc>>,
 }
 
@@ -38,6 +94,13 @@ impl SceneInfo {
             camera: Option::None,
             materials: HashMap::new(),
             hittables: Vec::new(),
+            geometries: HashMap::new(),
+            instances: Vec::new(),
+            csgs: Vec::new(),
+            transforms: HashMap::new(),
+            lights: Vec::new(),
+            textures: Vec::new(),
+            acceleration: Acceleration::default(),
             rand_producer: Arc::new(Box::new(rand_producer)),
         };
     }
@@ -57,6 +120,12 @@ impl SceneInfo {
         return self;
     }
 
+    pub fn acceleration(mut self, acceleration: Acceleration) -> Self {
+        self.acceleration = acceleration;
+
+        return self;
+    }
+
     pub fn sphere(mut self, name: &str, material_name: &str, center: Vec3, radius: f32) -> Self {
         self.hittables
             .push(SphereInfo::boxed(name, material_name, center, radius));
@@ -115,6 +184,143 @@ impl SceneInfo {
         return self;
     }
 
+    /// Adds parallelogram facing along `edge_one × edge_two`
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `material_name`:
+    /// * `origin`: Corner both edges start from
+    /// * `edge_one`: Edge along which u grows
+    /// * `edge_two`: Edge along which v grows
+    pub fn quad(
+        mut self,
+        name: &str,
+        material_name: &str,
+        origin: Vec3,
+        edge_one: Vec3,
+        edge_two: Vec3,
+    ) -> Self {
+        self.hittables.push(QuadInfo::boxed(
+            name,
+            material_name,
+            origin,
+            edge_one,
+            edge_two,
+        ));
+
+        return self;
+    }
+
+    /// Adds flat circle facing along `normal`
+    pub fn disk(
+        mut self,
+        name: &str,
+        material_name: &str,
+        center: Vec3,
+        normal: Vec3,
+        radius: f32,
+    ) -> Self {
+        self.hittables
+            .push(DiskInfo::boxed(name, material_name, center, normal, radius));
+
+        return self;
+    }
+
+    /// Adds axis-aligned box between two opposite corners, transform it to rotate
+    pub fn cuboid(mut self, name: &str, material_name: &str, minimum: Vec3, maximum: Vec3) -> Self {
+        self.hittables
+            .push(CuboidInfo::boxed(name, material_name, minimum, maximum));
+
+        return self;
+    }
+
+    /// Adds cylinder around the segment from `base` to `top`, `capped` closes its ends
+    pub fn cylinder(
+        mut self,
+        name: &str,
+        material_name: &str,
+        base: Vec3,
+        top: Vec3,
+        radius: f32,
+        capped: bool,
+    ) -> Self {
+        self.hittables.push(CylinderInfo::boxed(
+            name,
+            material_name,
+            base,
+            top,
+            radius,
+            capped,
+        ));
+
+        return self;
+    }
+
+    /// Adds cone narrowing from a disk at `base` to `apex`, `capped` closes the base
+    pub fn cone(
+        mut self,
+        name: &str,
+        material_name: &str,
+        base: Vec3,
+        apex: Vec3,
+        radius: f32,
+        capped: bool,
+    ) -> Self {
+        self.hittables.push(ConeInfo::boxed(
+            name,
+            material_name,
+            base,
+            apex,
+            radius,
+            capped,
+        ));
+
+        return self;
+    }
+
+    /// Adds ring around `axis`, the tube of `minor_radius` runs `major_radius` away from the center
+    pub fn torus(
+        mut self,
+        name: &str,
+        material_name: &str,
+        center: Vec3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+    ) -> Self {
+        self.hittables.push(TorusInfo::boxed(
+            name,
+            material_name,
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+        ));
+
+        return self;
+    }
+
+    /// Adds points within `radius` of the segment between two points
+    pub fn capsule(
+        mut self,
+        name: &str,
+        material_name: &str,
+        point_one: Vec3,
+        point_two: Vec3,
+        radius: f32,
+    ) -> Self {
+        self.hittables.push(CapsuleInfo::boxed(
+            name,
+            material_name,
+            point_one,
+            point_two,
+            radius,
+        ));
+
+        return self;
+    }
+
     pub fn triangle(
         mut self,
         name: &str,
@@ -134,6 +340,251 @@ impl SceneInfo {
         return self;
     }
 
+    pub fn mesh(mut self, name: &str, material_name: &str, geometry: Arc<MeshGeometry>) -> Self {
+        self.hittables
+            .push(MeshInfo::boxed(name, material_name, geometry));
+
+        return self;
+    }
+
+    /// Adds triangle geometry which is not rendered on its own, but shared by instances
+    pub fn geometry(mut self, name: &str, geometry: Arc<MeshGeometry>) -> Self {
+        self.geometries
+            .insert(name.to_string(), GeometryInfo::new(name, geometry));
+
+        return self;
+    }
+
+    /// Adds copy of shared geometry, which is placed by transforming it by `name`.
+    /// Copies cost the same memory no matter how big the geometry is
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `material_name`: Material of this copy only
+    /// * `geometry_name`: Name of geometry, which may be added later
+    pub fn instance(mut self, name: &str, material_name: &str, geometry_name: &str) -> Self {
+        self.instances
+            .push(InstanceInfo::new(name, material_name, geometry_name));
+
+        return self;
+    }
+
+    /// Adds solid made of two closed hittables or other solids, which are then only rendered
+    /// as its parts. Emissive parts glow when hit, but are not sampled as lights
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `operation`:
+    /// * `first_name`: Name of a hittable or solid, which may be added later
+    /// * `second_name`: Name of a hittable or solid, which may be added later
+    pub fn csg(
+        mut self,
+        name: &str,
+        operation: CsgOperation,
+        first_name: &str,
+        second_name: &str,
+    ) -> Self {
+        self.csgs
+            .push(CsgInfo::new(name, operation, first_name, second_name));
+
+        return self;
+    }
+
+    /// Applies affine transform to a hittable after the ones already applied to it
+    ///
+    /// # Arguments
+    ///
+    /// * `hittable_name`: Name of a hittable, which may be added later
+    /// * `matrix`: From its current coordinates to the new ones, last row must be 0,0,0,1
+    pub fn transform(mut self, hittable_name: &str, matrix: &Mat4) -> Self {
+        let transform = self
+            .transforms
+            .entry(hittable_name.to_string())
+            .or_insert_with(Mat4::identity);
+
+        *transform = matrix * *transform;
+
+        return self;
+    }
+
+    pub fn translate(self, hittable_name: &str, offset: Vec3) -> Self {
+        return self.transform(hittable_name, &nalgebra_glm::translation(&offset));
+    }
+
+    /// Rotates a hittable around an axis going through the origin
+    ///
+    /// # Arguments
+    ///
+    /// * `hittable_name`:
+    /// * `axis`: Direction of the axis, rotation is counterclockwise when looking against it
+    /// * `angle`: In degrees
+    pub fn rotate(self, hittable_name: &str, axis: Vec3, angle: f32) -> Self {
+        return self.transform(
+            hittable_name,
+            &nalgebra_glm::rotation(angle.to_radians(), &axis),
+        );
+    }
+
+    /// Scales a hittable relative to the origin, negative factors mirror it
+    pub fn scale(self, hittable_name: &str, factors: Vec3) -> Self {
+        return self.transform(hittable_name, &nalgebra_glm::scaling(&factors));
+    }
+
+    /// Adds environment map lighting rays that leave the scene
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `path`: Path of the map as written in the scene file, relative to its directory
+    /// * `map`: Loaded map, see `hdr_decoder::load`
+    /// * `rotation`: Rotation around z axis in degrees
+    /// * `intensity`: Multiplier of the map radiance
+    pub fn environment(
+        mut self,
+        name: &str,
+        path: &str,
+        map: Arc<EnvironmentMap>,
+        rotation: f32,
+        intensity: f32,
+    ) -> Self {
+        self.lights
+            .push(EnvironmentInfo::boxed(name, path, map, rotation, intensity));
+
+        return self;
+    }
+
+    /// Adds daylight sky lighting rays that leave the scene
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `sun_direction`: Direction towards the sun, z up. Must be above the horizon
+    /// * `turbidity`: Haziness of the atmosphere, from 2 for very clear to 10 for hazy
+    /// * `ground_albedo`: Color of the ground below the horizon
+    /// * `sun_size`: Angular diameter of the sun disk in degrees, 0.53 for the real sun
+    ///   or 0 for no disk
+    /// * `intensity`: Multiplier of the radiance in kcd/m²
+    pub fn sky(
+        mut self,
+        name: &str,
+        sun_direction: Vec3,
+        turbidity: f32,
+        ground_albedo: Vec3,
+        sun_size: f32,
+        intensity: f32,
+    ) -> Self {
+        self.lights.push(SkyInfo::boxed(
+            name,
+            sun_direction,
+            turbidity,
+            ground_albedo,
+            sun_size,
+            intensity,
+        ));
+
+        return self;
+    }
+
+    /// Adds light shining from a point equally in every direction
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `position`:
+    /// * `color`:
+    /// * `power`: Total power spread over the whole sphere of directions
+    pub fn point_light(mut self, name: &str, position: Vec3, color: Vec3, power: f32) -> Self {
+        self.lights
+            .push(PointLightInfo::boxed(name, position, color, power));
+
+        return self;
+    }
+
+    /// Adds light shining from a point inside a cone
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `position`:
+    /// * `direction`: Direction the light shines in
+    /// * `color`:
+    /// * `power`: Power of a point light with the same brightness inside the cone
+    /// * `angle`: Angle between opposite sides of the cone in degrees
+    /// * `softness`: Part of the cone from 0 to 1 over which light fades
+    #[allow(clippy::too_many_arguments)]
+    pub fn spot_light(
+        mut self,
+        name: &str,
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+        softness: f32,
+    ) -> Self {
+        self.lights.push(SpotLightInfo::boxed(
+            name, position, direction, color, power, angle, softness,
+        ));
+
+        return self;
+    }
+
+    /// Adds infinitely far light arriving from one direction
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `direction`: Direction towards the light
+    /// * `color`:
+    /// * `power`: Power per unit area arriving at a surface facing the light
+    /// * `angle`: Angular diameter of the light in degrees, 0 for perfectly sharp shadows
+    pub fn directional_light(
+        mut self,
+        name: &str,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+    ) -> Self {
+        self.lights.push(DirectionalLightInfo::boxed(
+            name, direction, color, power, angle,
+        ));
+
+        return self;
+    }
+
+    /// Adds rough metal described by its complex index of refraction
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `eta`: Real part of the index of refraction per channel
+    /// * `k`: Extinction coefficient per channel
+    /// * `roughness`: From 0 for a perfect mirror to 1
+    /// * `anisotropy`: From 0 for equal roughness in every direction to 1 for rougher along world x
+    pub fn conductor(
+        mut self,
+        name: &str,
+        eta: Vec3,
+        k: Vec3,
+        roughness: f32,
+        anisotropy: f32,
+    ) -> Self {
+        self.materials.insert(
+            name.to_string(),
+            ConductorInfo::boxed(name, eta, k, roughness, anisotropy),
+        );
+
+        return self;
+    }
+
+    /// Adds conductor with measured index of refraction of `metal`
+    pub fn metal(self, name: &str, metal: Metal, roughness: f32, anisotropy: f32) -> Self {
+        return self.conductor(name, metal.get_eta(), metal.get_k(), roughness, anisotropy);
+    }
+
     pub fn emission(mut self, name: &str, color: Vec3, power: f32) -> Self {
         self.materials
             .insert(name.to_string(), EmissionInfo::boxed(name, color, power));
@@ -141,50 +592,792 @@ impl SceneInfo {
         return self;
     }
 
-    pub fn lambertian(mut self, name: &str, color: Vec3) -> Self {
+    /// Adds diffuse material
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `color`: Color or name of a texture
+    pub fn lambertian(mut self, name: &str, color: impl Into<ColorInfo>) -> Self {
         self.materials
-            .insert(name.to_string(), LambertianInfo::boxed(name, color));
+            .insert(name.to_string(), LambertianInfo::boxed(name, color.into()));
+
+        return self;
+    }
+
+    /// Adds glossy mirror material
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `color`: Color or name of a texture
+    /// * `exponent`: Phong exponent of the reflection lobe, e.g. 1000 for a near mirror
+    pub fn reflective(mut self, name: &str, color: impl Into<ColorInfo>, exponent: f32) -> Self {
+        self.materials.insert(
+            name.to_string(),
+            ReflectiveInfo::boxed(name, color.into(), exponent),
+        );
 
         return self;
     }
 
-    pub fn reflective(mut self, name: &str, color: Vec3, reflectiveness: f32) -> Self {
+    /// Adds Disney-style material, every parameter except the color is from 0 to 1
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base_color`: Color or name of a texture, also the color of metals and tint of glass
+    /// * `metallic`: Number or name of a texture, from 0 for dielectrics to 1 for metals
+    /// * `roughness`: From 0 for mirror-like reflection to 1
+    /// * `specular`: Reflectance of dielectrics at normal incidence, 0.5 is 4%
+    /// * `clearcoat`: Strength of the clear coat layer
+    /// * `sheen`: Strength of the grazing reflection of cloth
+    /// * `transmission`: Part of dielectric light passing through the surface
+    #[allow(clippy::too_many_arguments)]
+    pub fn principled(
+        mut self,
+        name: &str,
+        base_color: impl Into<ColorInfo>,
+        metallic: impl Into<ScalarInfo>,
+        roughness: impl Into<ScalarInfo>,
+        specular: impl Into<ScalarInfo>,
+        clearcoat: impl Into<ScalarInfo>,
+        sheen: impl Into<ScalarInfo>,
+        transmission: impl Into<ScalarInfo>,
+    ) -> Self {
+        self.materials.insert(
+            name.to_string(),
+            PrincipledInfo::boxed(
+                name,
+                base_color.into(),
+                metallic.into(),
+                roughness.into(),
+                specular.into(),
+                clearcoat.into(),
+                sheen.into(),
+                transmission.into(),
+            ),
+        );
+
+        return self;
+    }
+
+    /// Adds blend of two materials, they may be declared later
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `first`: Name of a material
+    /// * `second`: Name of a material
+    /// * `factor`: Number or name of a texture, share of `second` from 0 to 1
+    pub fn mix(
+        mut self,
+        name: &str,
+        first: &str,
+        second: &str,
+        factor: impl Into<ScalarInfo>,
+    ) -> Self {
+        self.materials.insert(
+            name.to_string(),
+            MixInfo::boxed(name, first, second, factor.into()),
+        );
+
+        return self;
+    }
+
+    /// Adds varnish-like dielectric layer over another material, which may be declared later
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base`: Name of the material under the layer
+    /// * `color`: Color or name of a texture tinting light passing through the layer
+    /// * `index_of_refraction`: Of the layer
+    /// * `roughness`: Of the layer surface, from 0 for a polished one to 1
+    pub fn coated(
+        mut self,
+        name: &str,
+        base: &str,
+        color: impl Into<ColorInfo>,
+        index_of_refraction: f32,
+        roughness: f32,
+    ) -> Self {
         self.materials.insert(
             name.to_string(),
-            ReflectiveInfo::boxed(name, color, reflectiveness),
+            CoatedInfo::boxed(name, base, color.into(), index_of_refraction, roughness),
         );
 
         return self;
     }
 
-    pub fn refractive(mut self, name: &str, color: Vec3, index_of_refraction: f32) -> Self {
+    /// Adds clear glass-like material
+    pub fn refractive(
+        self,
+        name: &str,
+        color: impl Into<ColorInfo>,
+        index_of_refraction: f32,
+    ) -> Self {
+        return self.dielectric(name, color, index_of_refraction, 0.0, Vec3::zeros());
+    }
+
+    /// Adds glass-like material which may be frosted and absorb light inside
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `color`: Color or name of a texture tinting light passing through the surface
+    /// * `index_of_refraction`:
+    /// * `roughness`: From 0 for clear glass to 1
+    /// * `absorption`: Part of light absorbed per unit of distance travelled inside, per channel
+    pub fn dielectric(
+        mut self,
+        name: &str,
+        color: impl Into<ColorInfo>,
+        index_of_refraction: f32,
+        roughness: f32,
+        absorption: Vec3,
+    ) -> Self {
         self.materials.insert(
             name.to_string(),
-            RefractiveInfo::boxed(name, color, index_of_refraction),
+            RefractiveInfo::boxed(
+                name,
+                color.into(),
+                index_of_refraction,
+                roughness,
+                absorption,
+            ),
         );
 
         return self;
     }
 
-    pub fn build(self) -> Scene {
+    pub fn solid_texture(mut self, name: &str, color: Vec3) -> Self {
+        self.textures.push(SolidColorInfo::boxed(name, color));
+
+        return self;
+    }
+
+    /// Adds checkerboard in surface coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `even`: Color or name of an earlier texture at the origin
+    /// * `odd`: Color or name of an earlier texture for its neighbours
+    /// * `scale`: Number of squares along one unit of surface coordinates
+    pub fn checker(
+        mut self,
+        name: &str,
+        even: impl Into<ColorInfo>,
+        odd: impl Into<ColorInfo>,
+        scale: f32,
+    ) -> Self {
+        self.textures
+            .push(Checker2dInfo::boxed(name, even.into(), odd.into(), scale));
+
+        return self;
+    }
+
+    /// Adds checkerboard of cubes in world space
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `even`: Color or name of an earlier texture at the origin
+    /// * `odd`: Color or name of an earlier texture for its neighbours
+    /// * `size`: Edge length of one cube in world units
+    pub fn checker_3d(
+        mut self,
+        name: &str,
+        even: impl Into<ColorInfo>,
+        odd: impl Into<ColorInfo>,
+        size: f32,
+    ) -> Self {
+        self.textures
+            .push(Checker3dInfo::boxed(name, even.into(), odd.into(), size));
+
+        return self;
+    }
+
+    /// Adds image stretched over surface coordinates from 0 to 1
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `path`: Path of the image as written in the scene file, relative to its directory
+    /// * `image`: Loaded image, see `image_loader::load`
+    /// * `wrap_mode`: How the image continues outside of [0, 1]²
+    /// * `filter`: How colors between pixel centers are found
+    pub fn image_texture(
+        mut self,
+        name: &str,
+        path: &str,
+        image: Arc<LinearImage>,
+        wrap_mode: WrapMode,
+        filter: TextureFilter,
+    ) -> Self {
+        self.textures.push(ImageTextureInfo::boxed(
+            name, path, image, wrap_mode, filter,
+        ));
+
+        return self;
+    }
+
+    /// Adds blend of two colors by fractal Perlin noise in world space
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `low`: Color or name of an earlier texture where the noise is lowest
+    /// * `high`: Color or name of an earlier texture where the noise is highest
+    /// * `scale`: Frequency of the first octave in world units
+    /// * `octaves`: Number of noise layers from 1 to 16, 1 for plain Perlin noise
+    /// * `seed`: Added to the seed drawn from the scene generator, a seeded one repeats the noise
+    pub fn noise(
+        mut self,
+        name: &str,
+        low: impl Into<ColorInfo>,
+        high: impl Into<ColorInfo>,
+        scale: f32,
+        octaves: u32,
+        seed: u32,
+    ) -> Self {
+        self.textures.push(NoiseInfo::boxed(
+            name,
+            low.into(),
+            high.into(),
+            scale,
+            octaves,
+            seed,
+        ));
+
+        return self;
+    }
+
+    /// Adds marble with veins across the x axis
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `base`: Color or name of an earlier texture between the veins
+    /// * `vein`: Color or name of an earlier texture of the veins
+    /// * `scale`: Number of veins along one world unit of x
+    /// * `turbulence`: How far the veins are bent, in distances between them
+    /// * `seed`: Added to the seed drawn from the scene generator, a seeded one repeats the pattern
+    pub fn marble(
+        mut self,
+        name: &str,
+        base: impl Into<ColorInfo>,
+        vein: impl Into<ColorInfo>,
+        scale: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Self {
+        self.textures.push(MarbleInfo::boxed(
+            name,
+            base.into(),
+            vein.into(),
+            scale,
+            turbulence,
+            seed,
+        ));
+
+        return self;
+    }
+
+    /// Adds wood with growth rings around the z axis
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `light`: Color or name of an earlier texture of the wood inside rings
+    /// * `dark`: Color or name of an earlier texture of the ring borders
+    /// * `rings`: Number of rings along one world unit of radius
+    /// * `turbulence`: How much the rings are distorted, in ring widths
+    /// * `seed`: Added to the seed drawn from the scene generator, a seeded one repeats the pattern
+    pub fn wood(
+        mut self,
+        name: &str,
+        light: impl Into<ColorInfo>,
+        dark: impl Into<ColorInfo>,
+        rings: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Self {
+        self.textures.push(WoodInfo::boxed(
+            name,
+            light.into(),
+            dark.into(),
+            rings,
+            turbulence,
+            seed,
+        ));
+
+        return self;
+    }
+
+    pub fn get_camera(&self) -> Option<&CameraInfo> {
+        return self.camera.as_ref();
+    }
+
+    pub fn get_materials(&self) -> &HashMap<String, Box<dyn MaterialInfo>> {
+        return &self.materials;
+    }
+
+    pub fn get_hittables(&self) -> &[Box<dyn HittableInfo>] {
+        return &self.hittables;
+    }
+
+    pub fn get_geometries(&self) -> &HashMap<String, GeometryInfo> {
+        return &self.geometries;
+    }
+
+    pub fn get_instances(&self) -> &[InstanceInfo] {
+        return &self.instances;
+    }
+
+    pub fn get_csgs(&self) -> &[CsgInfo] {
+        return &self.csgs;
+    }
+
+    pub fn get_transforms(&self) -> &HashMap<String, Mat4> {
+        return &self.transforms;
+    }
+
+    pub fn get_lights(&self) -> &[Box<dyn LightInfo>] {
+        return &self.lights;
+    }
+
+    pub fn get_textures(&self) -> &[Box<dyn TextureInfo>] {
+        return &self.textures;
+    }
+
+    pub fn get_acceleration(&self) -> Acceleration {
+        return self.acceleration;
+    }
+
+    /// Checks the whole scene and collects every problem instead of stopping at the first one
+    pub fn validate(&self) -> Result<(), SceneError> {
+        let mut problems = Vec::new();
+
+        match &self.camera {
+            Some(camera) => {
+                if camera.get_aspect_ratio().is_nan() || camera.get_aspect_ratio() <= 0.0 {
+                    problems.push(SceneProblem::NonPositiveAspectRatio(
+                        camera.get_aspect_ratio(),
+                    ));
+                }
+
+                if !(camera.get_v_fov() > 0.0 && camera.get_v_fov() < 180.0) {
+                    problems.push(SceneProblem::InvalidFieldOfView(camera.get_v_fov()));
+                }
+            }
+            None => problems.push(SceneProblem::MissingCamera),
+        }
+
+        let mut texture_names = HashSet::new();
+        let mut texture_duplicates = HashSet::new();
+
+        for texture in &self.textures {
+            let name = texture.get_name();
+
+            for texture_name in texture.get_texture_names() {
+                if !texture_names.contains(texture_name) {
+                    problems.push(SceneProblem::UnknownTexture {
+                        user_name: name.to_string(),
+                        texture_name: texture_name.to_string(),
+                    });
+                }
+            }
+
+            if !texture_names.insert(name) && texture_duplicates.insert(name) {
+                problems.push(SceneProblem::DuplicateTextureName(name.to_string()));
+            }
+
+            if let Err(reason) = texture.validate() {
+                problems.push(SceneProblem::InvalidTexture {
+                    texture_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        let mut material_names: Vec<&String> = self.materials.keys().collect();
+
+        // Sorted to report problems in the same order every time
+        material_names.sort();
+
+        for name in material_names {
+            for texture_name in self.materials[name].get_texture_names() {
+                if !texture_names.contains(texture_name) {
+                    problems.push(SceneProblem::UnknownTexture {
+                        user_name: name.to_string(),
+                        texture_name: texture_name.to_string(),
+                    });
+                }
+            }
+
+            for component_name in self.materials[name].get_material_names() {
+                if !self.materials.contains_key(component_name) {
+                    problems.push(SceneProblem::UnknownComponentMaterial {
+                        material_name: name.to_string(),
+                        component_name: component_name.to_string(),
+                    });
+                }
+            }
+
+            if let Err(reason) = self.materials[name].validate() {
+                problems.push(SceneProblem::InvalidMaterial {
+                    material_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        let (_, cyclic) = self.get_material_order();
+
+        for name in cyclic {
+            problems.push(SceneProblem::MaterialCycle(name.to_string()));
+        }
+
+        let mut geometry_names: Vec<&String> = self.geometries.keys().collect();
+
+        geometry_names.sort();
+
+        for name in geometry_names {
+            if let Err(reason) = self.geometries[name].validate() {
+                problems.push(SceneProblem::InvalidGeometry {
+                    geometry_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        let mut names = HashSet::new();
+        let mut duplicates = HashSet::new();
+
+        for hittable in &self.hittables {
+            let name = hittable.get_name();
+
+            if !names.insert(name) && duplicates.insert(name) {
+                problems.push(SceneProblem::DuplicateHittableName(name.to_string()));
+            }
+
+            if !self.materials.contains_key(hittable.get_material_name()) {
+                problems.push(SceneProblem::UnknownMaterial {
+                    hittable_name: name.to_string(),
+                    material_name: hittable.get_material_name().to_string(),
+                });
+            }
+
+            if let Err(reason) = hittable.validate() {
+                problems.push(SceneProblem::DegenerateGeometry {
+                    hittable_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        for instance in &self.instances {
+            let name = instance.get_name();
+
+            if !names.insert(name) && duplicates.insert(name) {
+                problems.push(SceneProblem::DuplicateHittableName(name.to_string()));
+            }
+
+            if !self.materials.contains_key(instance.get_material_name()) {
+                problems.push(SceneProblem::UnknownMaterial {
+                    hittable_name: name.to_string(),
+                    material_name: instance.get_material_name().to_string(),
+                });
+            }
+
+            if !self.geometries.contains_key(instance.get_geometry_name()) {
+                problems.push(SceneProblem::UnknownGeometry {
+                    hittable_name: name.to_string(),
+                    geometry_name: instance.get_geometry_name().to_string(),
+                });
+            }
+        }
+
+        let csg_names: HashSet<&str> = self.csgs.iter().map(|x| x.get_name()).collect();
+
+        for csg in &self.csgs {
+            let name = csg.get_name();
+
+            if !names.insert(name) && duplicates.insert(name) {
+                problems.push(SceneProblem::DuplicateHittableName(name.to_string()));
+            }
+
+            for operand_name in csg.get_operand_names() {
+                if csg_names.contains(operand_name) {
+                    continue;
+                }
+
+                let is_closed = match self.hittables.iter().find(|x| x.get_name() == operand_name) {
+                    Some(hittable) => hittable.is_closed(),
+                    None if self.instances.iter().any(|x| x.get_name() == operand_name) => false,
+                    None => {
+                        problems.push(SceneProblem::UnknownOperand {
+                            hittable_name: name.to_string(),
+                            operand_name: operand_name.to_string(),
+                        });
+
+                        continue;
+                    }
+                };
+
+                if !is_closed {
+                    problems.push(SceneProblem::OpenOperand {
+                        hittable_name: name.to_string(),
+                        operand_name: operand_name.to_string(),
+                    });
+                }
+            }
+        }
+
+        for name in self.get_csg_cycles() {
+            problems.push(SceneProblem::CsgCycle(name.to_string()));
+        }
+
+        let mut transform_names: Vec<&String> = self.transforms.keys().collect();
+
+        transform_names.sort();
+
+        for name in transform_names {
+            if !names.contains(name.as_str()) {
+                problems.push(SceneProblem::UnknownTransformedHittable(name.to_string()));
+            }
+
+            if let Err(reason) = validate_transform(&self.transforms[name]) {
+                problems.push(SceneProblem::InvalidTransform {
+                    hittable_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        let mut light_names = HashSet::new();
+        let mut light_duplicates = HashSet::new();
+
+        for light in &self.lights {
+            let name = light.get_name();
+
+            if !light_names.insert(name) && light_duplicates.insert(name) {
+                problems.push(SceneProblem::DuplicateLightName(name.to_string()));
+            }
+
+            if let Err(reason) = light.validate() {
+                problems.push(SceneProblem::InvalidLight {
+                    light_name: name.to_string(),
+                    reason,
+                });
+            }
+        }
+
+        if !problems.is_empty() {
+            return Result::Err(SceneError::new(problems));
+        }
+
+        return Result::Ok(());
+    }
+
+    /// Orders materials so that every one comes after the materials it is made of
+    ///
+    /// returns: (ordered names, sorted names of materials made of themselves or of such materials)
+    fn get_material_order(&self) -> (Vec<&str>, Vec<&str>) {
+        let mut remaining: Vec<&str> = self.materials.keys().map(|x| x.as_str()).collect();
+        let mut ordered = Vec::with_capacity(remaining.len());
+        let mut placed = HashSet::new();
+
+        remaining.sort();
+
+        loop {
+            // Unknown components are reported separately, so they do not block ordering
+            let (ready, blocked): (Vec<&str>, Vec<&str>) = remaining.iter().partition(|name| {
+                self.materials[**name]
+                    .get_material_names()
+                    .iter()
+                    .all(|x| placed.contains(x) || !self.materials.contains_key(*x))
+            });
+
+            if ready.is_empty() {
+                return (ordered, blocked);
+            }
+
+            placed.extend(ready.iter().copied());
+            ordered.extend(ready);
+            remaining = blocked;
+        }
+    }
+
+    /// Sorted names of solids made of themselves or of such solids
+    fn get_csg_cycles(&self) -> Vec<&str> {
+        let mut remaining: Vec<&CsgInfo> = self.csgs.iter().collect();
+        let mut placed = HashSet::new();
+
+        loop {
+            // Operands which are not solids are reported separately, so they do not block
+            let (ready, blocked): (Vec<&CsgInfo>, Vec<&CsgInfo>) =
+                remaining.iter().partition(|csg| {
+                    csg.get_operand_names().iter().all(|x| {
+                        placed.contains(x) || !self.csgs.iter().any(|y| y.get_name() == *x)
+                    })
+                });
+
+            if ready.is_empty() {
+                let mut cyclic: Vec<&str> = blocked.iter().map(|x| x.get_name()).collect();
+
+                cyclic.sort();
+
+                return cyclic;
+            }
+
+            placed.extend(ready.iter().map(|x| x.get_name()));
+            remaining = blocked;
+        }
+    }
+
+    /// Builds solid or hittable with its transform, validation guarantees it exists and
+    /// that solids are not made of themselves
+    fn build_operand(
+        &self,
+        name: &str,
+        materials: &HashMap<String, MaterialRc>,
+    ) -> Box<dyn Hittable> {
+        let csg = match self.csgs.iter().find(|x| x.get_name() == name) {
+            Some(csg) => csg,
+            None => {
+                let hittable = self
+                    .hittables
+                    .iter()
+                    .find(|x| x.get_name() == name)
+                    .unwrap();
+
+                return self.build_hittable(
+                    hittable.as_ref(),
+                    materials[hittable.get_material_name()].clone(),
+                );
+            }
+        };
+
+        let [first_name, second_name] = csg.get_operand_names();
+        let built = csg.build(
+            self.build_operand(first_name, materials),
+            self.build_operand(second_name, materials),
+        );
+
+        return match self.transforms.get(name) {
+            Some(matrix) => Transformed::boxed(built, *matrix),
+            None => built,
+        };
+    }
+
+    fn build_hittable(
+        &self,
+        hittable: &dyn HittableInfo,
+        material: MaterialRc,
+    ) -> Box<dyn Hittable> {
+        let built = hittable.build(material);
+
+        return match self.transforms.get(hittable.get_name()) {
+            Some(matrix) => Transformed::boxed(built, *matrix),
+            None => built,
+        };
+    }
+
+    pub fn try_build(self) -> Result<Scene, SceneError> {
+        self.validate()?;
+
         let rand = (self.rand_producer)();
-        let camera = self.camera.expect("Camera is required").build(rand.clone());
-        let mut materials = Vec::with_capacity(self.materials.len());
+        let camera = self.camera.as_ref().unwrap().build(rand.clone());
+        let mut materials = HashMap::with_capacity(self.materials.len());
         let mut hittables = Vec::with_capacity(self.hittables.len());
-        self.materials
-            .values()
-            .for_each(|x| materials.push(x.build(rand.clone())));
-        for h in &self.hittables {
-            let material_name = h.get_material_name();
-            let material = self
-                .materials
-                .get(material_name)
-                .unwrap_or_else(|| panic!("Cannot find material with name \"{}\"", material_name));
+        let mut lights: Vec<Box<dyn Light>> = Vec::new();
 
-            hittables.push(h.build(material.build(rand.clone())));
+        let mut textures = HashMap::with_capacity(self.textures.len());
+
+        for texture in &self.textures {
+            let built = texture.build(rand.clone(), &textures);
+
+            textures.insert(texture.get_name().to_string(), built);
+        }
+
+        // Validation guarantees every material can be ordered
+        for name in self.get_material_order().0 {
+            let built = self.materials[name].build(rand.clone(), &textures, &materials);
+
+            materials.insert(name.to_string(), built);
+        }
+
+        // Solids own the parts they are made of, those are not rendered on their own
+        let operand_names: HashSet<&str> = self
+            .csgs
+            .iter()
+            .flat_map(|x| x.get_operand_names())
+            .collect();
+
+        for hittable in &self.hittables {
+            if operand_names.contains(hittable.get_name()) {
+                continue;
+            }
+
+            let material = materials[hittable.get_material_name()].clone();
+
+            // Lights get their own copy, the world one ends up inside the acceleration structure
+            if self.materials[hittable.get_material_name()].is_emissive() {
+                lights.push(AreaLight::boxed(
+                    self.build_hittable(hittable.as_ref(), material.clone()),
+                    material.clone(),
+                ));
+            }
+
+            hittables.push(self.build_hittable(hittable.as_ref(), material));
         }
 
-        return Scene::new(camera, materials, HittableList::new(hittables));
+        let mut instances = Vec::with_capacity(self.instances.len());
+
+        for instance in &self.instances {
+            let material = materials[instance.get_material_name()].clone();
+            let geometry = self.geometries[instance.get_geometry_name()].get_geometry();
+            let matrix = self
+                .transforms
+                .get(instance.get_name())
+                .copied()
+                .unwrap_or_else(Mat4::identity);
+
+            if self.materials[instance.get_material_name()].is_emissive() {
+                lights.push(AreaLight::boxed(
+                    Box::new(instance.build(geometry.clone(), material.clone(), matrix)),
+                    material.clone(),
+                ));
+            }
+
+            instances.push(instance.build(geometry.clone(), material, matrix));
+        }
+
+        // Instances get their own top level hierarchy over the ones of their geometries
+        if !instances.is_empty() {
+            hittables.push(InstanceBvh::boxed(instances));
+        }
+
+        for csg in &self.csgs {
+            if !operand_names.contains(csg.get_name()) {
+                hittables.push(self.build_operand(csg.get_name(), &materials));
+            }
+        }
+
+        for light in &self.lights {
+            lights.push(light.build());
+        }
+
+        return Result::Ok(Scene::with_world(
+            camera,
+            materials.into_values().collect(),
+            self.acceleration.build(HittableList::new(hittables)),
+            lights,
+            rand,
+        ));
     }
 }
 
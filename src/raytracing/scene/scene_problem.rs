@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+/// Single reason why a `SceneInfo` cannot be built
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneProblem {
+    MissingCamera,
+    NonPositiveAspectRatio(f32),
+    InvalidFieldOfView(f32),
+    UnknownMaterial {
+        hittable_name: String,
+        material_name: String,
+    },
+    InvalidMaterial {
+        material_name: String,
+        reason: String,
+    },
+    UnknownComponentMaterial {
+        material_name: String,
+        component_name: String,
+    },
+    MaterialCycle(String),
+    DuplicateHittableName(String),
+    DegenerateGeometry {
+        hittable_name: String,
+        reason: String,
+    },
+    UnknownGeometry {
+        hittable_name: String,
+        geometry_name: String,
+    },
+    InvalidGeometry {
+        geometry_name: String,
+        reason: String,
+    },
+    UnknownOperand {
+        hittable_name: String,
+        operand_name: String,
+    },
+    OpenOperand {
+        hittable_name: String,
+        operand_name: String,
+    },
+    CsgCycle(String),
+    UnknownTransformedHittable(String),
+    InvalidTransform {
+        hittable_name: String,
+        reason: String,
+    },
+    DuplicateLightName(String),
+    InvalidLight {
+        light_name: String,
+        reason: String,
+    },
+    DuplicateTextureName(String),
+    UnknownTexture {
+        user_name: String,
+        texture_name: String,
+    },
+    InvalidTexture {
+        texture_name: String,
+        reason: String,
+    },
+}
+
+impl Display for SceneProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            SceneProblem::MissingCamera => write!(f, "Camera is required"),
+            SceneProblem::NonPositiveAspectRatio(aspect_ratio) => {
+                write!(
+                    f,
+                    "Camera aspect ratio must be positive, got {}",
+                    aspect_ratio
+                )
+            }
+            SceneProblem::InvalidFieldOfView(v_fov) => write!(
+                f,
+                "Camera vertical field of view must be between 0 and 180 degrees, got {}",
+                v_fov
+            ),
+            SceneProblem::UnknownMaterial {
+                hittable_name,
+                material_name,
+            } => write!(
+                f,
+                "Hittable \"{}\" refers to unknown material \"{}\"",
+                hittable_name, material_name
+            ),
+            SceneProblem::InvalidMaterial {
+                material_name,
+                reason,
+            } => write!(f, "Material \"{}\" is invalid: {}", material_name, reason),
+            SceneProblem::UnknownComponentMaterial {
+                material_name,
+                component_name,
+            } => write!(
+                f,
+                "Material \"{}\" is made of unknown material \"{}\"",
+                material_name, component_name
+            ),
+            SceneProblem::MaterialCycle(name) => {
+                write!(f, "Material \"{}\" is made of itself", name)
+            }
+            SceneProblem::DuplicateHittableName(name) => {
+                write!(f, "Hittable name \"{}\" is used more than once", name)
+            }
+            SceneProblem::DegenerateGeometry {
+                hittable_name,
+                reason,
+            } => write!(
+                f,
+                "Hittable \"{}\" is degenerate: {}",
+                hittable_name, reason
+            ),
+            SceneProblem::UnknownGeometry {
+                hittable_name,
+                geometry_name,
+            } => write!(
+                f,
+                "Hittable \"{}\" refers to unknown geometry \"{}\"",
+                hittable_name, geometry_name
+            ),
+            SceneProblem::InvalidGeometry {
+                geometry_name,
+                reason,
+            } => write!(f, "Geometry \"{}\" is invalid: {}", geometry_name, reason),
+            SceneProblem::UnknownOperand {
+                hittable_name,
+                operand_name,
+            } => write!(
+                f,
+                "Solid \"{}\" refers to unknown hittable \"{}\"",
+                hittable_name, operand_name
+            ),
+            SceneProblem::OpenOperand {
+                hittable_name,
+                operand_name,
+            } => write!(
+                f,
+                "Solid \"{}\" is made of \"{}\", which does not enclose a volume",
+                hittable_name, operand_name
+            ),
+            SceneProblem::CsgCycle(name) => {
+                write!(f, "Solid \"{}\" is made of itself", name)
+            }
+            SceneProblem::UnknownTransformedHittable(name) => {
+                write!(f, "Transform refers to unknown hittable \"{}\"", name)
+            }
+            SceneProblem::InvalidTransform {
+                hittable_name,
+                reason,
+            } => write!(
+                f,
+                "Transform of hittable \"{}\" is invalid: {}",
+                hittable_name, reason
+            ),
+            SceneProblem::DuplicateLightName(name) => {
+                write!(f, "Light name \"{}\" is used more than once", name)
+            }
+            SceneProblem::InvalidLight { light_name, reason } => {
+                write!(f, "Light \"{}\" is invalid: {}", light_name, reason)
+            }
+            SceneProblem::DuplicateTextureName(name) => {
+                write!(f, "Texture name \"{}\" is used more than once", name)
+            }
+            SceneProblem::UnknownTexture {
+                user_name,
+                texture_name,
+            } => write!(
+                f,
+                "\"{}\" refers to texture \"{}\" which is not declared before it",
+                user_name, texture_name
+            ),
+            SceneProblem::InvalidTexture {
+                texture_name,
+                reason,
+            } => write!(f, "Texture \"{}\" is invalid: {}", texture_name, reason),
+        };
+    }
+}
@@ -0,0 +1,703 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use nalgebra_glm::Vec3;
+
+use crate::hittables::bvh::acceleration::Acceleration;
+use crate::hittables::csg_operation::CsgOperation;
+use crate::import::obj_importer::ObjImporter;
+use crate::import::{hdr_decoder, image_loader};
+use crate::lights::environment_map::EnvironmentMap;
+use crate::materials::metal::Metal;
+use crate::scene::scene_info::SceneInfo;
+use crate::scene::statement::Statement;
+use crate::textures::texture_filter::TextureFilter;
+use crate::textures::wrap_mode::WrapMode;
+
+/// Reads text scene files, see `SceneWriter` for the other direction.
+///
+/// Every non-empty line not starting with `#` is one statement:
+/// ```text
+/// camera origin=0,2.4,0 center=0,0,0 up=-1,0,0 v_fov=70 aspect_ratio=1.3333
+/// acceleration flat_bvh
+/// texture checker name=tiles even=1,1,1 odd=0.2,0.2,0.2 scale=8
+/// texture image name=wood path=textures/wood.png wrap=repeat filter=bilinear
+/// material lambertian name=white color=1,1,1
+/// material lambertian name=floor color=tiles
+/// material conductor name=gold metal=gold roughness=0.2 anisotropy=0
+/// material principled name=paint base_color=0.8,0.1,0.1 metallic=0 roughness=0.4 specular=0.5 clearcoat=1 sheen=0 transmission=0
+/// material coated name=varnished base=floor color=1,0.9,0.7 index_of_refraction=1.5 roughness=0.1
+/// material mix name=worn first=gold second=white factor=tiles
+/// material refractive name=frosted color=1,1,1 index_of_refraction=1.5 roughness=0.3 absorption=0.2,0.1,0.1
+/// hittable sphere name=ball material=white center=0,0,-0.5 radius=0.5
+/// hittable quad name=wall material=white origin=-1,-1,0 edge_one=2,0,0 edge_two=0,2,1
+/// hittable disk name=lamp material=white center=0,0,2 normal=0,0,-1 radius=0.3
+/// hittable box name=block material=white minimum=-0.3,-0.3,-1 maximum=0.3,0.3,-0.2
+/// hittable cylinder name=pillar material=white base=0.6,0.6,-1 top=0.6,0.6,0 radius=0.1 capped=true
+/// hittable cone name=spike material=white base=-0.6,0.6,-1 apex=-0.6,0.6,-0.4 radius=0.15 capped=false
+/// hittable torus name=ring material=white center=0,0,0 axis=0,1,0 major_radius=0.4 minor_radius=0.05
+/// hittable capsule name=pill material=white point_one=-0.2,0,-0.5 point_two=0.2,0,-0.5 radius=0.1
+/// geometry name=leaf positions=0,0,0;1,0,0;0,1,0 triangles=0,1,2
+/// hittable instance name=leaf_1 material=white geometry=leaf
+/// csg name=hollow operation=difference first=block second=ball
+/// transform hittable=ball scale=1,1,2 rotate=0,0,1 angle=45 translate=0,1,0
+/// transform hittable=ball matrix=1,0,0,0;0,1,0,0;0,0,1,0.5
+/// obj path=models/teapot.obj name=teapot
+/// light environment name=sky path=sky.hdr rotation=90 intensity=1
+/// light point name=bulb position=0,0,2 color=1,0.9,0.8 power=50
+/// light sky name=day sun_direction=1,1,1 turbidity=3 ground_albedo=0.3,0.3,0.3 sun_size=0.53 intensity=0.1
+/// ```
+pub struct SceneReader {
+    path: String,
+}
+
+impl SceneReader {
+    pub fn new(path: &str) -> Result<Self, String> {
+        if path.is_empty() {
+            return Result::Err("Path is empty".to_string());
+        }
+
+        return Result::Ok(Self {
+            path: path.to_string(),
+        });
+    }
+}
+
+impl SceneReader {
+    pub fn read(&self, scene_info: SceneInfo) -> Result<SceneInfo, String> {
+        let source = fs::read_to_string(&self.path)
+            .map_err(|x| format!("Error reading file: {}. {}", self.path, x))?;
+
+        return self.read_str(&source, scene_info);
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: Scene file contents
+    /// * `scene_info`: Scene to add everything to
+    ///
+    /// returns: String with text what went wrong or scene otherwise
+    pub fn read_str(&self, source: &str, scene_info: SceneInfo) -> Result<SceneInfo, String> {
+        let mut scene_info = scene_info;
+
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            scene_info = Statement::parse(line)
+                .and_then(|statement| self.apply(&statement, scene_info))
+                .map_err(|x| format!("{}:{}: {}", self.path, index + 1, x))?;
+        }
+
+        return Result::Ok(scene_info);
+    }
+
+    fn apply(&self, statement: &Statement, scene_info: SceneInfo) -> Result<SceneInfo, String> {
+        return match (statement.get_keyword(), statement.get_kind()) {
+            ("camera", None) => {
+                statement.check_keys(&["origin", "center", "up", "v_fov", "aspect_ratio"])?;
+
+                Result::Ok(scene_info.camera(
+                    statement.get_vec3("origin")?,
+                    statement.get_vec3("center")?,
+                    statement.get_vec3("up")?,
+                    statement.get_f32("v_fov")?,
+                    statement.get_f32("aspect_ratio")?,
+                ))
+            }
+            ("acceleration", Some(kind)) => {
+                statement.check_keys(&[])?;
+
+                let acceleration = Acceleration::from_name(kind)
+                    .ok_or_else(|| format!("Unknown acceleration \"{}\"", kind))?;
+
+                Result::Ok(scene_info.acceleration(acceleration))
+            }
+            ("obj", None) => {
+                statement.check_keys(&["path", "name"])?;
+
+                let path = self.resolve(statement.get_str("path")?);
+
+                ObjImporter::new(&path, statement.get_str("name")?)?.import(scene_info)
+            }
+            ("geometry", None) => {
+                statement.check_keys(&["name", "positions", "normals", "uvs", "triangles"])?;
+
+                Result::Ok(scene_info.geometry(
+                    statement.get_str("name")?,
+                    Arc::new(statement.get_geometry()?),
+                ))
+            }
+            ("csg", None) => {
+                statement.check_keys(&["name", "operation", "first", "second"])?;
+
+                let operation = statement.get_str("operation")?;
+                let operation = CsgOperation::from_name(operation)
+                    .ok_or_else(|| format!("Unknown solid operation \"{}\"", operation))?;
+
+                Result::Ok(scene_info.csg(
+                    statement.get_str("name")?,
+                    operation,
+                    statement.get_str("first")?,
+                    statement.get_str("second")?,
+                ))
+            }
+            ("material", Some(kind)) => Self::apply_material(statement, kind, scene_info),
+            ("hittable", Some(kind)) => Self::apply_hittable(statement, kind, scene_info),
+            ("transform", None) => Self::apply_transform(statement, scene_info),
+            ("light", Some(kind)) => self.apply_light(statement, kind, scene_info),
+            ("texture", Some(kind)) => self.apply_texture(statement, kind, scene_info),
+            (keyword, _) => Result::Err(format!("Unknown statement \"{}\"", keyword)),
+        };
+    }
+
+    fn apply_material(
+        statement: &Statement,
+        kind: &str,
+        scene_info: SceneInfo,
+    ) -> Result<SceneInfo, String> {
+        let name = statement.get_str("name")?;
+
+        return match kind {
+            "coated" => {
+                statement.check_keys(&[
+                    "name",
+                    "base",
+                    "color",
+                    "index_of_refraction",
+                    "roughness",
+                ])?;
+
+                Result::Ok(scene_info.coated(
+                    name,
+                    statement.get_str("base")?,
+                    statement.get_color("color")?,
+                    statement.get_f32("index_of_refraction")?,
+                    statement.get_f32("roughness")?,
+                ))
+            }
+            "conductor" => {
+                statement.check_keys(&["name", "metal", "eta", "k", "roughness", "anisotropy"])?;
+
+                let roughness = statement.get_f32("roughness")?;
+                let anisotropy = statement.get_f32("anisotropy")?;
+
+                // Preset name stands in for both parts of the index of refraction
+                Result::Ok(match statement.get("metal") {
+                    Some(metal) => {
+                        if statement.get("eta").is_some() || statement.get("k").is_some() {
+                            return Result::Err(
+                                "Parameter \"metal\" cannot be used together with \"eta\" and \"k\""
+                                    .to_string(),
+                            );
+                        }
+
+                        let metal = Metal::from_name(metal)
+                            .ok_or_else(|| format!("Unknown metal \"{}\"", metal))?;
+
+                        scene_info.metal(name, metal, roughness, anisotropy)
+                    }
+                    None => scene_info.conductor(
+                        name,
+                        statement.get_vec3("eta")?,
+                        statement.get_vec3("k")?,
+                        roughness,
+                        anisotropy,
+                    ),
+                })
+            }
+            "emission" => {
+                statement.check_keys(&["name", "color", "power"])?;
+
+                Result::Ok(scene_info.emission(
+                    name,
+                    statement.get_vec3("color")?,
+                    statement.get_f32("power")?,
+                ))
+            }
+            "lambertian" => {
+                statement.check_keys(&["name", "color"])?;
+
+                Result::Ok(scene_info.lambertian(name, statement.get_color("color")?))
+            }
+            "mix" => {
+                statement.check_keys(&["name", "first", "second", "factor"])?;
+
+                Result::Ok(scene_info.mix(
+                    name,
+                    statement.get_str("first")?,
+                    statement.get_str("second")?,
+                    statement.get_scalar("factor")?,
+                ))
+            }
+            "principled" => {
+                let scalars = [
+                    "metallic",
+                    "roughness",
+                    "specular",
+                    "clearcoat",
+                    "sheen",
+                    "transmission",
+                ];
+
+                statement.check_keys(&[&["name", "base_color"], &scalars[..]].concat())?;
+
+                let [metallic, roughness, specular, clearcoat, sheen, transmission] =
+                    scalars.map(|x| statement.get_scalar(x));
+
+                Result::Ok(scene_info.principled(
+                    name,
+                    statement.get_color("base_color")?,
+                    metallic?,
+                    roughness?,
+                    specular?,
+                    clearcoat?,
+                    sheen?,
+                    transmission?,
+                ))
+            }
+            "reflective" => {
+                statement.check_keys(&["name", "color", "exponent"])?;
+
+                Result::Ok(scene_info.reflective(
+                    name,
+                    statement.get_color("color")?,
+                    statement.get_f32("exponent")?,
+                ))
+            }
+            "refractive" => {
+                statement.check_keys(&[
+                    "name",
+                    "color",
+                    "index_of_refraction",
+                    "roughness",
+                    "absorption",
+                ])?;
+
+                // Clear glass needs neither, as in scenes written before they existed
+                let roughness = match statement.get("roughness") {
+                    Some(_) => statement.get_f32("roughness")?,
+                    None => 0.0,
+                };
+                let absorption = match statement.get("absorption") {
+                    Some(_) => statement.get_vec3("absorption")?,
+                    None => Vec3::zeros(),
+                };
+
+                Result::Ok(scene_info.dielectric(
+                    name,
+                    statement.get_color("color")?,
+                    statement.get_f32("index_of_refraction")?,
+                    roughness,
+                    absorption,
+                ))
+            }
+            _ => Result::Err(format!("Unknown material \"{}\"", kind)),
+        };
+    }
+
+    fn apply_hittable(
+        statement: &Statement,
+        kind: &str,
+        scene_info: SceneInfo,
+    ) -> Result<SceneInfo, String> {
+        let name = statement.get_str("name")?;
+        let material = statement.get_str("material")?;
+
+        return match kind {
+            "sphere" => {
+                statement.check_keys(&["name", "material", "center", "radius"])?;
+
+                Result::Ok(scene_info.sphere(
+                    name,
+                    material,
+                    statement.get_vec3("center")?,
+                    statement.get_f32("radius")?,
+                ))
+            }
+            "xy_square" | "xz_square" | "yz_square" => {
+                statement.check_keys(&["name", "material", "position", "edge_length"])?;
+
+                let position = statement.get_vec3("position")?;
+                let edge_length = statement.get_f32("edge_length")?;
+
+                Result::Ok(match kind {
+                    "xy_square" => scene_info.xy_square(name, material, position, edge_length),
+                    "xz_square" => scene_info.xz_square(name, material, position, edge_length),
+                    _ => scene_info.yz_square(name, material, position, edge_length),
+                })
+            }
+            "quad" => {
+                statement.check_keys(&["name", "material", "origin", "edge_one", "edge_two"])?;
+
+                Result::Ok(scene_info.quad(
+                    name,
+                    material,
+                    statement.get_vec3("origin")?,
+                    statement.get_vec3("edge_one")?,
+                    statement.get_vec3("edge_two")?,
+                ))
+            }
+            "disk" => {
+                statement.check_keys(&["name", "material", "center", "normal", "radius"])?;
+
+                Result::Ok(scene_info.disk(
+                    name,
+                    material,
+                    statement.get_vec3("center")?,
+                    statement.get_vec3("normal")?,
+                    statement.get_f32("radius")?,
+                ))
+            }
+            "box" => {
+                statement.check_keys(&["name", "material", "minimum", "maximum"])?;
+
+                Result::Ok(scene_info.cuboid(
+                    name,
+                    material,
+                    statement.get_vec3("minimum")?,
+                    statement.get_vec3("maximum")?,
+                ))
+            }
+            "cylinder" => {
+                statement.check_keys(&["name", "material", "base", "top", "radius", "capped"])?;
+
+                Result::Ok(scene_info.cylinder(
+                    name,
+                    material,
+                    statement.get_vec3("base")?,
+                    statement.get_vec3("top")?,
+                    statement.get_f32("radius")?,
+                    statement.get_bool("capped")?,
+                ))
+            }
+            "cone" => {
+                statement.check_keys(&["name", "material", "base", "apex", "radius", "capped"])?;
+
+                Result::Ok(scene_info.cone(
+                    name,
+                    material,
+                    statement.get_vec3("base")?,
+                    statement.get_vec3("apex")?,
+                    statement.get_f32("radius")?,
+                    statement.get_bool("capped")?,
+                ))
+            }
+            "torus" => {
+                statement.check_keys(&[
+                    "name",
+                    "material",
+                    "center",
+                    "axis",
+                    "major_radius",
+                    "minor_radius",
+                ])?;
+
+                Result::Ok(scene_info.torus(
+                    name,
+                    material,
+                    statement.get_vec3("center")?,
+                    statement.get_vec3("axis")?,
+                    statement.get_f32("major_radius")?,
+                    statement.get_f32("minor_radius")?,
+                ))
+            }
+            "capsule" => {
+                statement.check_keys(&["name", "material", "point_one", "point_two", "radius"])?;
+
+                Result::Ok(scene_info.capsule(
+                    name,
+                    material,
+                    statement.get_vec3("point_one")?,
+                    statement.get_vec3("point_two")?,
+                    statement.get_f32("radius")?,
+                ))
+            }
+            "triangle" => {
+                statement.check_keys(&[
+                    "name",
+                    "material",
+                    "point_one",
+                    "point_two",
+                    "point_three",
+                ])?;
+
+                Result::Ok(scene_info.triangle(
+                    name,
+                    material,
+                    statement.get_vec3("point_one")?,
+                    statement.get_vec3("point_two")?,
+                    statement.get_vec3("point_three")?,
+                ))
+            }
+            "mesh" => {
+                statement.check_keys(&[
+                    "name",
+                    "material",
+                    "positions",
+                    "normals",
+                    "uvs",
+                    "triangles",
+                ])?;
+
+                let geometry = statement.get_geometry()?;
+
+                Result::Ok(scene_info.mesh(name, material, Arc::new(geometry)))
+            }
+            "instance" => {
+                statement.check_keys(&["name", "material", "geometry"])?;
+
+                Result::Ok(scene_info.instance(name, material, statement.get_str("geometry")?))
+            }
+            _ => Result::Err(format!("Unknown hittable \"{}\"", kind)),
+        };
+    }
+
+    /// Either whole `matrix` or any of scaling, rotation and translation, applied in this order
+    fn apply_transform(statement: &Statement, scene_info: SceneInfo) -> Result<SceneInfo, String> {
+        statement.check_keys(&[
+            "hittable",
+            "matrix",
+            "scale",
+            "rotate",
+            "angle",
+            "translate",
+        ])?;
+
+        let name = statement.get_str("hittable")?;
+
+        if statement.get("matrix").is_some() {
+            if ["scale", "rotate", "angle", "translate"]
+                .iter()
+                .any(|x| statement.get(x).is_some())
+            {
+                return Result::Err(
+                    "Parameter \"matrix\" cannot be used together with other transforms"
+                        .to_string(),
+                );
+            }
+
+            return Result::Ok(scene_info.transform(name, &statement.get_mat4("matrix")?));
+        }
+
+        let mut scene_info = scene_info;
+
+        if statement.get("scale").is_some() {
+            scene_info = scene_info.scale(name, statement.get_vec3("scale")?);
+        }
+
+        if statement.get("rotate").is_some() || statement.get("angle").is_some() {
+            scene_info = scene_info.rotate(
+                name,
+                statement.get_vec3("rotate")?,
+                statement.get_f32("angle")?,
+            );
+        }
+
+        if statement.get("translate").is_some() {
+            scene_info = scene_info.translate(name, statement.get_vec3("translate")?);
+        }
+
+        return Result::Ok(scene_info);
+    }
+
+    fn apply_light(
+        &self,
+        statement: &Statement,
+        kind: &str,
+        scene_info: SceneInfo,
+    ) -> Result<SceneInfo, String> {
+        let name = statement.get_str("name")?;
+
+        return match kind {
+            "environment" => {
+                statement.check_keys(&["name", "path", "rotation", "intensity"])?;
+
+                let path = statement.get_str("path")?;
+                let map = EnvironmentMap::new(hdr_decoder::load(&self.resolve(path))?);
+
+                Result::Ok(scene_info.environment(
+                    name,
+                    path,
+                    Arc::new(map),
+                    statement.get_f32("rotation")?,
+                    statement.get_f32("intensity")?,
+                ))
+            }
+            "sky" => {
+                statement.check_keys(&[
+                    "name",
+                    "sun_direction",
+                    "turbidity",
+                    "ground_albedo",
+                    "sun_size",
+                    "intensity",
+                ])?;
+
+                Result::Ok(scene_info.sky(
+                    name,
+                    statement.get_vec3("sun_direction")?,
+                    statement.get_f32("turbidity")?,
+                    statement.get_vec3("ground_albedo")?,
+                    statement.get_f32("sun_size")?,
+                    statement.get_f32("intensity")?,
+                ))
+            }
+            "point" => {
+                statement.check_keys(&["name", "position", "color", "power"])?;
+
+                Result::Ok(scene_info.point_light(
+                    name,
+                    statement.get_vec3("position")?,
+                    statement.get_vec3("color")?,
+                    statement.get_f32("power")?,
+                ))
+            }
+            "spot" => {
+                statement.check_keys(&[
+                    "name",
+                    "position",
+                    "direction",
+                    "color",
+                    "power",
+                    "angle",
+                    "softness",
+                ])?;
+
+                Result::Ok(scene_info.spot_light(
+                    name,
+                    statement.get_vec3("position")?,
+                    statement.get_vec3("direction")?,
+                    statement.get_vec3("color")?,
+                    statement.get_f32("power")?,
+                    statement.get_f32("angle")?,
+                    statement.get_f32("softness")?,
+                ))
+            }
+            "directional" => {
+                statement.check_keys(&["name", "direction", "color", "power", "angle"])?;
+
+                Result::Ok(scene_info.directional_light(
+                    name,
+                    statement.get_vec3("direction")?,
+                    statement.get_vec3("color")?,
+                    statement.get_f32("power")?,
+                    statement.get_f32("angle")?,
+                ))
+            }
+            _ => Result::Err(format!("Unknown light \"{}\"", kind)),
+        };
+    }
+
+    fn apply_texture(
+        &self,
+        statement: &Statement,
+        kind: &str,
+        scene_info: SceneInfo,
+    ) -> Result<SceneInfo, String> {
+        let name = statement.get_str("name")?;
+
+        return match kind {
+            "solid" => {
+                statement.check_keys(&["name", "color"])?;
+
+                Result::Ok(scene_info.solid_texture(name, statement.get_vec3("color")?))
+            }
+            "checker" => {
+                statement.check_keys(&["name", "even", "odd", "scale"])?;
+
+                Result::Ok(scene_info.checker(
+                    name,
+                    statement.get_color("even")?,
+                    statement.get_color("odd")?,
+                    statement.get_f32("scale")?,
+                ))
+            }
+            "checker_3d" => {
+                statement.check_keys(&["name", "even", "odd", "size"])?;
+
+                Result::Ok(scene_info.checker_3d(
+                    name,
+                    statement.get_color("even")?,
+                    statement.get_color("odd")?,
+                    statement.get_f32("size")?,
+                ))
+            }
+            "image" => {
+                statement.check_keys(&["name", "path", "wrap", "filter"])?;
+
+                let path = statement.get_str("path")?;
+                let wrap = statement.get_str("wrap")?;
+                let wrap_mode = WrapMode::from_name(wrap)
+                    .ok_or_else(|| format!("Unknown wrap mode \"{}\"", wrap))?;
+                let filter = statement.get_str("filter")?;
+                let filter = TextureFilter::from_name(filter)
+                    .ok_or_else(|| format!("Unknown texture filter \"{}\"", filter))?;
+                let image = image_loader::load(&self.resolve(path))?;
+
+                Result::Ok(scene_info.image_texture(name, path, Arc::new(image), wrap_mode, filter))
+            }
+            "noise" => {
+                statement.check_keys(&["name", "low", "high", "scale", "octaves", "seed"])?;
+
+                Result::Ok(scene_info.noise(
+                    name,
+                    statement.get_color("low")?,
+                    statement.get_color("high")?,
+                    statement.get_f32("scale")?,
+                    statement.get_u32("octaves")?,
+                    Self::read_seed(statement)?,
+                ))
+            }
+            "marble" => {
+                statement.check_keys(&["name", "base", "vein", "scale", "turbulence", "seed"])?;
+
+                Result::Ok(scene_info.marble(
+                    name,
+                    statement.get_color("base")?,
+                    statement.get_color("vein")?,
+                    statement.get_f32("scale")?,
+                    statement.get_f32("turbulence")?,
+                    Self::read_seed(statement)?,
+                ))
+            }
+            "wood" => {
+                statement.check_keys(&["name", "light", "dark", "rings", "turbulence", "seed"])?;
+
+                Result::Ok(scene_info.wood(
+                    name,
+                    statement.get_color("light")?,
+                    statement.get_color("dark")?,
+                    statement.get_f32("rings")?,
+                    statement.get_f32("turbulence")?,
+                    Self::read_seed(statement)?,
+                ))
+            }
+            _ => Result::Err(format!("Unknown texture \"{}\"", kind)),
+        };
+    }
+
+    /// Offset to the seed procedural textures draw from the scene generator, none by default
+    fn read_seed(statement: &Statement) -> Result<u32, String> {
+        return match statement.get("seed") {
+            Some(_) => statement.get_u32("seed"),
+            None => Result::Ok(0),
+        };
+    }
+
+    /// Paths inside the scene file are relative to its directory. Only files being loaded use
+    /// the result, infos keep paths as written so that saved scenes refer to the same files
+    fn resolve(&self, path: &str) -> String {
+        let directory = Path::new(&self.path)
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+
+        return directory.join(path).to_string_lossy().to_string();
+    }
+}
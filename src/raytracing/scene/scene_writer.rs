@@ -0,0 +1,210 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use crate::scene::scene_info::SceneInfo;
+use crate::scene::statement::Statement;
+
+/// Writes text scene files readable by `SceneReader`.
+/// OBJ imports are written as the meshes they produced.
+/// File paths are written as they were read, relative to the directory of the scene file.
+pub struct SceneWriter {
+    path: String,
+}
+
+impl SceneWriter {
+    pub fn new(path: &str) -> Result<Self, String> {
+        if path.is_empty() {
+            return Result::Err("Path is empty".to_string());
+        }
+
+        return Result::Ok(Self {
+            path: path.to_string(),
+        });
+    }
+}
+
+impl SceneWriter {
+    pub fn write(&self, scene_info: &SceneInfo) -> Result<(), String> {
+        let file = File::create(self.path.clone())
+            .map_err(|x| format!("Error creating file: {}. {}", self.path, x))?;
+        let mut buffered_writer = BufWriter::new(file);
+
+        for statement in Self::statements(scene_info) {
+            writeln!(buffered_writer, "{}", statement)
+                .map_err(|x| format!("Error writing to file: {}. {}", self.path, x))?;
+        }
+
+        return Result::Ok(());
+    }
+
+    pub fn write_string(scene_info: &SceneInfo) -> String {
+        let lines: Vec<String> = Self::statements(scene_info)
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+
+        return lines.join("\n") + "\n";
+    }
+
+    /// Statements in reading order: camera, acceleration, textures, materials sorted by name,
+    /// geometries sorted by name, hittables, instances, solids, transforms sorted by hittable name,
+    /// lights
+    pub fn statements(scene_info: &SceneInfo) -> Vec<Statement> {
+        let mut statements = Vec::new();
+
+        if let Some(camera) = scene_info.get_camera() {
+            statements.push(camera.to_statement());
+        }
+
+        statements.push(Statement::new(
+            "acceleration",
+            Option::Some(scene_info.get_acceleration().get_name()),
+        ));
+
+        for texture in scene_info.get_textures() {
+            statements.push(texture.to_statement());
+        }
+
+        let mut material_names: Vec<&String> = scene_info.get_materials().keys().collect();
+
+        material_names.sort();
+
+        for name in material_names {
+            statements.push(scene_info.get_materials()[name].to_statement());
+        }
+
+        let mut geometry_names: Vec<&String> = scene_info.get_geometries().keys().collect();
+
+        geometry_names.sort();
+
+        for name in geometry_names {
+            statements.push(scene_info.get_geometries()[name].to_statement());
+        }
+
+        for hittable in scene_info.get_hittables() {
+            statements.push(hittable.to_statement());
+        }
+
+        for instance in scene_info.get_instances() {
+            statements.push(instance.to_statement());
+        }
+
+        for csg in scene_info.get_csgs() {
+            statements.push(csg.to_statement());
+        }
+
+        let mut transform_names: Vec<&String> = scene_info.get_transforms().keys().collect();
+
+        transform_names.sort();
+
+        for name in transform_names {
+            statements.push(
+                Statement::new("transform", Option::None)
+                    .with_str("hittable", name)
+                    .with_mat4("matrix", &scene_info.get_transforms()[name]),
+            );
+        }
+
+        for light in scene_info.get_lights() {
+            statements.push(light.to_statement());
+        }
+
+        return statements;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::*;
+    use crate::misc::default_rand_gen::DefaultRandGen;
+    use crate::scene::scene_reader::SceneReader;
+
+    const SCENE: &str = r#"
+        camera origin=0,2.4,0 center=0,0,0 up=-1,0,0 v_fov=70 aspect_ratio=1.3333334
+        acceleration flat_bvh
+        texture checker name=tiles even=1,1,1 odd=0.2,0.2,0.2 scale=8
+        texture noise name=clouds low=0,0,0 high=tiles scale=4 octaves=3
+        texture marble name=stone base=1,1,1 vein=0.1,0.1,0.1 scale=2 turbulence=1 seed=3
+        material lambertian name=white color=1,1,1
+        material lambertian name=floor color=tiles
+        material emission name=glow color=1,0.9,0.8 power=2
+        material reflective name=mirror color=1,1,1 exponent=1000
+        material conductor name=gold metal=gold roughness=0.2 anisotropy=0
+        material principled name=paint base_color=stone metallic=0 roughness=0.4 specular=0.5 clearcoat=1 sheen=0 transmission=0
+        material coated name=varnished base=floor color=1,0.9,0.7 index_of_refraction=1.5 roughness=0.1
+        material mix name=worn first=gold second=white factor=clouds
+        material refractive name=frosted color=1,1,1 index_of_refraction=1.5 roughness=0.3 absorption=0.2,0.1,0.1
+        hittable sphere name=ball material=frosted center=0,0,-0.5 radius=0.5
+        hittable quad name=wall material=worn origin=-1,-1,0 edge_one=2,0,0 edge_two=0,2,1
+        hittable disk name=lamp material=glow center=0,0,2 normal=0,0,-1 radius=0.3
+        hittable box name=block material=paint minimum=-0.3,-0.3,-1 maximum=0.3,0.3,-0.2
+        hittable cylinder name=pillar material=mirror base=0.6,0.6,-1 top=0.6,0.6,0 radius=0.1 capped=true
+        hittable cone name=spike material=varnished base=-0.6,0.6,-1 apex=-0.6,0.6,-0.4 radius=0.15 capped=false
+        hittable torus name=ring material=white center=0,0,0 axis=0,1,0 major_radius=0.4 minor_radius=0.05
+        hittable capsule name=pill material=white point_one=-0.2,0,-0.5 point_two=0.2,0,-0.5 radius=0.1
+        geometry name=leaf positions=0,0,0;1,0,0;0,1,0 triangles=0,1,2
+        hittable instance name=leaf_1 material=white geometry=leaf
+        csg name=hollow operation=difference first=block second=ball
+        transform hittable=ball scale=1,1,2 rotate=0,0,1 angle=45 translate=0,1,0
+        transform hittable=ring matrix=1,0,0,0;0,1,0,0;0,0,1,0.5
+        light point name=bulb position=0,0,2 color=1,0.9,0.8 power=50
+        light sky name=day sun_direction=1,1,1 turbidity=3 ground_albedo=0.3,0.3,0.3 sun_size=0.53 intensity=0.1
+    "#;
+
+    fn read(reader: &SceneReader, source: &str) -> Result<SceneInfo, String> {
+        return reader.read_str(source, SceneInfo::new(DefaultRandGen::new_rc));
+    }
+
+    /// Written scene reads back to one that is written the same way
+    fn assert_round_trip(reader: &SceneReader, source: &str) {
+        let written = SceneWriter::write_string(&read(reader, source).unwrap());
+        let rewritten = SceneWriter::write_string(&read(reader, &written).unwrap());
+
+        assert_eq!(rewritten, written);
+    }
+
+    #[test]
+    fn every_statement_round_trips() {
+        let reader = SceneReader::new("scene.scene").unwrap();
+
+        assert_round_trip(&reader, SCENE);
+        assert!(read(&reader, SCENE).unwrap().try_build().is_ok());
+    }
+
+    #[test]
+    fn shipped_scene_round_trips() {
+        let reader = SceneReader::new("resources/scenes/cornell_box.scene").unwrap();
+
+        assert_round_trip(
+            &reader,
+            include_str!("../../../resources/scenes/cornell_box.scene"),
+        );
+    }
+
+    #[test]
+    fn paths_stay_relative_to_scene_file() {
+        let directory = env::temp_dir().join(format!("scene_writer_paths_{}", process::id()));
+        let mut hdr = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n\n-Y 1 +X 1\n".to_vec();
+
+        hdr.extend_from_slice(&[128, 128, 128, 129]);
+        fs::create_dir_all(directory.join("maps")).unwrap();
+        fs::write(directory.join("maps").join("sky.hdr"), hdr).unwrap();
+
+        let reader = SceneReader::new(directory.join("scene.scene").to_str().unwrap()).unwrap();
+        let source = "light environment name=sky path=maps/sky.hdr rotation=0 intensity=1";
+        let written = read(&reader, source).map(|x| SceneWriter::write_string(&x));
+        let rewritten = written
+            .clone()
+            .and_then(|x| read(&reader, &x))
+            .map(|x| SceneWriter::write_string(&x));
+
+        fs::remove_dir_all(&directory).unwrap();
+
+        let written = written.unwrap();
+
+        assert!(written.contains(" path=maps/sky.hdr "), "{}", written);
+        assert_eq!(rewritten.unwrap(), written);
+    }
+}
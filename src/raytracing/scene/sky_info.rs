@@ -0,0 +1,129 @@
+use nalgebra_glm::Vec3;
+
+use crate::lights::light::Light;
+use crate::lights::sky_light::SkyLight;
+use crate::scene::light_info::{validate_direction, LightInfo};
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct SkyInfo {
+    name: String,
+    sun_direction: Vec3,
+    turbidity: f32,
+    ground_albedo: Vec3,
+    sun_size: f32,
+    intensity: f32,
+}
+
+impl SkyInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `sun_direction`: Direction towards the sun, z up
+    /// * `turbidity`: Haziness of the atmosphere, from 2 for very clear to 10 for hazy
+    /// * `ground_albedo`: Color of the ground below the horizon
+    /// * `sun_size`: Angular diameter of the sun disk in degrees, 0 for no disk
+    /// * `intensity`: Multiplier of the radiance in kcd/m²
+    pub fn new(
+        name: &str,
+        sun_direction: Vec3,
+        turbidity: f32,
+        ground_albedo: Vec3,
+        sun_size: f32,
+        intensity: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            sun_direction,
+            turbidity,
+            ground_albedo,
+            sun_size,
+            intensity,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        sun_direction: Vec3,
+        turbidity: f32,
+        ground_albedo: Vec3,
+        sun_size: f32,
+        intensity: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            name,
+            sun_direction,
+            turbidity,
+            ground_albedo,
+            sun_size,
+            intensity,
+        ));
+    }
+}
+
+impl LightInfo for SkyInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self) -> Box<dyn Light> {
+        return SkyLight::boxed(
+            &self.sun_direction,
+            self.turbidity,
+            &self.ground_albedo,
+            self.sun_size,
+            self.intensity,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("light", Option::Some("sky"))
+            .with_str("name", &self.name)
+            .with_vec3("sun_direction", &self.sun_direction)
+            .with_f32("turbidity", self.turbidity)
+            .with_vec3("ground_albedo", &self.ground_albedo)
+            .with_f32("sun_size", self.sun_size)
+            .with_f32("intensity", self.intensity);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validate_direction("sun_direction", &self.sun_direction)?;
+
+        // The model only describes daylight
+        if self.sun_direction.z <= 0.0 {
+            return Result::Err(format!(
+                "sun must be above the horizon, got sun_direction z {}",
+                self.sun_direction.z
+            ));
+        }
+
+        if !(1.7..=10.0).contains(&self.turbidity) {
+            return Result::Err(format!(
+                "turbidity must be from 1.7 to 10, got {}",
+                self.turbidity
+            ));
+        }
+
+        if self.ground_albedo.iter().any(|x| !(0.0..=1.0).contains(x)) {
+            return Result::Err("ground_albedo components must be from 0 to 1".to_string());
+        }
+
+        if !(0.0..90.0).contains(&self.sun_size) {
+            return Result::Err(format!(
+                "sun_size must be from 0 to 90 degrees, got {}",
+                self.sun_size
+            ));
+        }
+
+        if !(self.intensity >= 0.0 && self.intensity.is_finite()) {
+            return Result::Err(format!(
+                "intensity must not be negative, got {}",
+                self.intensity
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
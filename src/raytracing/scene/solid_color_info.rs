@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use nalgebra_glm::Vec3;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::solid_color::SolidColor;
+use crate::textures::texture::TextureRc;
+
+#[derive(Clone)]
+pub struct SolidColorInfo {
+    name: String,
+    color: Vec3,
+}
+
+impl SolidColorInfo {
+    pub fn new(name: &str, color: Vec3) -> Self {
+        return Self {
+            name: name.to_string(),
+            color,
+        };
+    }
+
+    pub fn boxed(name: &str, color: Vec3) -> Box<Self> {
+        return Box::new(Self::new(name, color));
+    }
+}
+
+impl TextureInfo for SolidColorInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        return SolidColor::new_rc(self.color);
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("solid"))
+            .with_str("name", &self.name)
+            .with_vec3("color", &self.color);
+    }
+}
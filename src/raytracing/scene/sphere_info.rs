@@ -4,6 +4,7 @@ use crate::hittables::hittable::Hittable;
 use crate::hittables::sphere::Sphere;
 use crate::materials::material::MaterialRc;
 use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
 
 #[derive(Clone)]
 pub struct SphereInfo {
@@ -40,4 +41,24 @@ impl HittableInfo for SphereInfo {
     fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
         return Sphere::boxed(self.center, self.radius, material);
     }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.radius > 0.0 && self.radius.is_finite()) {
+            return Result::Err(format!("radius must be positive, got {}", self.radius));
+        }
+
+        return Result::Ok(());
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("sphere"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("center", &self.center)
+            .with_f32("radius", self.radius);
+    }
 }
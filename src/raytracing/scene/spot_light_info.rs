@@ -0,0 +1,113 @@
+use nalgebra_glm::Vec3;
+
+use crate::lights::light::Light;
+use crate::lights::spot_light::SpotLight;
+use crate::scene::light_info::{validate_color_power, validate_direction, LightInfo};
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct SpotLightInfo {
+    name: String,
+    position: Vec3,
+    direction: Vec3,
+    color: Vec3,
+    power: f32,
+    angle: f32,
+    softness: f32,
+}
+
+impl SpotLightInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `position`:
+    /// * `direction`: Direction the light shines in
+    /// * `color`:
+    /// * `power`: Power of a point light with the same brightness inside the cone
+    /// * `angle`: Angle between opposite sides of the cone in degrees
+    /// * `softness`: Part of the cone from 0 to 1 over which light fades
+    pub fn new(
+        name: &str,
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+        softness: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            position,
+            direction,
+            color,
+            power,
+            angle,
+            softness,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        position: Vec3,
+        direction: Vec3,
+        color: Vec3,
+        power: f32,
+        angle: f32,
+        softness: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            name, position, direction, color, power, angle, softness,
+        ));
+    }
+}
+
+impl LightInfo for SpotLightInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self) -> Box<dyn Light> {
+        return SpotLight::boxed(
+            self.position,
+            self.direction,
+            self.color,
+            self.power,
+            self.angle,
+            self.softness,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("light", Option::Some("spot"))
+            .with_str("name", &self.name)
+            .with_vec3("position", &self.position)
+            .with_vec3("direction", &self.direction)
+            .with_vec3("color", &self.color)
+            .with_f32("power", self.power)
+            .with_f32("angle", self.angle)
+            .with_f32("softness", self.softness);
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validate_color_power(&self.color, self.power)?;
+        validate_direction("direction", &self.direction)?;
+
+        if !(self.angle > 0.0 && self.angle <= 180.0) {
+            return Result::Err(format!(
+                "angle must be above 0 and at most 180 degrees, got {}",
+                self.angle
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.softness) {
+            return Result::Err(format!(
+                "softness must be from 0 to 1, got {}",
+                self.softness
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
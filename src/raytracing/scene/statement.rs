@@ -0,0 +1,428 @@
+use std::fmt::{Display, Formatter};
+
+use nalgebra_glm::{Mat4, Vec2, Vec3};
+
+use crate::hittables::mesh_geometry::MeshGeometry;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::scalar_info::ScalarInfo;
+
+/// One line of a scene file: `keyword [kind] key=value key="quoted value" ...`
+#[derive(Clone)]
+pub struct Statement {
+    keyword: String,
+    kind: Option<String>,
+    parameters: Vec<(String, String)>,
+}
+
+impl Statement {
+    pub fn new(keyword: &str, kind: Option<&str>) -> Self {
+        return Self {
+            keyword: keyword.to_string(),
+            kind: kind.map(|x| x.to_string()),
+            parameters: Vec::new(),
+        };
+    }
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `line`: Line without comments, must not be empty
+    ///
+    /// returns: String with text what went wrong or statement otherwise
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut tokens = tokenize(line)?.into_iter();
+        let keyword = tokens.next().ok_or("Empty statement")?;
+
+        if keyword.contains('=') {
+            return Result::Err(format!("Expected keyword, got \"{}\"", keyword));
+        }
+
+        let mut statement = Self::new(&keyword, Option::None);
+
+        for (i, token) in tokens.enumerate() {
+            match token.split_once('=') {
+                Some((key, value)) => {
+                    if statement.get(key).is_some() {
+                        return Result::Err(format!("Parameter \"{}\" is given twice", key));
+                    }
+
+                    statement
+                        .parameters
+                        .push((key.to_string(), unquote(value)?));
+                }
+                None if i == 0 => statement.kind = Option::Some(token),
+                None => {
+                    return Result::Err(format!("Expected key=value, got \"{}\"", token));
+                }
+            }
+        }
+
+        return Result::Ok(statement);
+    }
+}
+
+impl Statement {
+    pub fn get_keyword(&self) -> &str {
+        return &self.keyword;
+    }
+
+    pub fn get_kind(&self) -> Option<&str> {
+        return self.kind.as_deref();
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        return self
+            .parameters
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str());
+    }
+
+    /// Fails on the first parameter which is not in `keys`, to catch typos
+    pub fn check_keys(&self, keys: &[&str]) -> Result<(), String> {
+        for (key, _) in &self.parameters {
+            if !keys.contains(&key.as_str()) {
+                return Result::Err(format!(
+                    "Unknown parameter \"{}\", expected one of: {}",
+                    key,
+                    keys.join(", ")
+                ));
+            }
+        }
+
+        return Result::Ok(());
+    }
+
+    pub fn get_str(&self, key: &str) -> Result<&str, String> {
+        return self
+            .get(key)
+            .ok_or_else(|| format!("Missing parameter \"{}\"", key));
+    }
+
+    pub fn get_f32(&self, key: &str) -> Result<f32, String> {
+        let floats = parse_floats(self.get_str(key)?, key)?;
+
+        if floats.len() != 1 {
+            return Result::Err(format!("Parameter \"{}\" must be a number", key));
+        }
+
+        return Result::Ok(floats[0]);
+    }
+
+    pub fn get_u32(&self, key: &str) -> Result<u32, String> {
+        let value = self.get_str(key)?;
+
+        return value.parse::<u32>().map_err(|_| {
+            format!(
+                "Parameter \"{}\" must be a whole number, got \"{}\"",
+                key, value
+            )
+        });
+    }
+
+    pub fn get_bool(&self, key: &str) -> Result<bool, String> {
+        let value = self.get_str(key)?;
+
+        return value.parse::<bool>().map_err(|_| {
+            format!(
+                "Parameter \"{}\" must be true or false, got \"{}\"",
+                key, value
+            )
+        });
+    }
+
+    pub fn get_vec3(&self, key: &str) -> Result<Vec3, String> {
+        let floats = parse_floats(self.get_str(key)?, key)?;
+
+        if floats.len() != 3 {
+            return Result::Err(format!("Parameter \"{}\" must be x,y,z", key));
+        }
+
+        return Result::Ok(Vec3::new(floats[0], floats[1], floats[2]));
+    }
+
+    /// Affine transform as its top three rows `a,b,c,d;e,f,g,h;i,j,k,l`,
+    /// the last row is always 0,0,0,1
+    pub fn get_mat4(&self, key: &str) -> Result<Mat4, String> {
+        let rows = self.get_list(key, 4)?;
+
+        if rows.len() != 3 {
+            return Result::Err(format!("Parameter \"{}\" must have 3 rows", key));
+        }
+
+        let mut matrix = Mat4::identity();
+
+        for (i, row) in rows.iter().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                matrix[(i, j)] = *value;
+            }
+        }
+
+        return Result::Ok(matrix);
+    }
+
+    /// Color as `r,g,b` or name of a texture
+    pub fn get_color(&self, key: &str) -> Result<ColorInfo, String> {
+        return Result::Ok(ColorInfo::parse(self.get_str(key)?));
+    }
+
+    /// Number or name of a texture
+    pub fn get_scalar(&self, key: &str) -> Result<ScalarInfo, String> {
+        return Result::Ok(ScalarInfo::parse(self.get_str(key)?));
+    }
+
+    pub fn get_vec2_list(&self, key: &str) -> Result<Vec<Vec2>, String> {
+        return self
+            .get_list(key, 2)?
+            .iter()
+            .map(|x| Result::Ok(Vec2::new(x[0], x[1])))
+            .collect();
+    }
+
+    pub fn get_vec3_list(&self, key: &str) -> Result<Vec<Vec3>, String> {
+        return self
+            .get_list(key, 3)?
+            .iter()
+            .map(|x| Result::Ok(Vec3::new(x[0], x[1], x[2])))
+            .collect();
+    }
+
+    pub fn get_triangle_list(&self, key: &str) -> Result<Vec<[u32; 3]>, String> {
+        let mut triangles = Vec::new();
+
+        for item in self.get_str(key)?.split(';') {
+            let indices: Vec<u32> = item
+                .split(',')
+                .map(|x| x.trim().parse::<u32>())
+                .collect::<Result<_, _>>()
+                .map_err(|_| format!("Cannot parse \"{}\" in parameter \"{}\"", item, key))?;
+
+            if indices.len() != 3 {
+                return Result::Err(format!("Parameter \"{}\" must be a;b;c list", key));
+            }
+
+            triangles.push([indices[0], indices[1], indices[2]]);
+        }
+
+        return Result::Ok(triangles);
+    }
+
+    /// Mesh geometry from `positions`, `triangles` and optional `normals` and `uvs`
+    pub fn get_geometry(&self) -> Result<MeshGeometry, String> {
+        let normals = match self.get("normals") {
+            Some(_) => Option::Some(self.get_vec3_list("normals")?),
+            None => Option::None,
+        };
+        let uvs = match self.get("uvs") {
+            Some(_) => Option::Some(self.get_vec2_list("uvs")?),
+            None => Option::None,
+        };
+
+        return MeshGeometry::new(
+            self.get_vec3_list("positions")?,
+            normals,
+            uvs,
+            self.get_triangle_list("triangles")?,
+        );
+    }
+
+    fn get_list(&self, key: &str, size: usize) -> Result<Vec<Vec<f32>>, String> {
+        let mut result = Vec::new();
+
+        for item in self.get_str(key)?.split(';') {
+            let floats = parse_floats(item, key)?;
+
+            if floats.len() != size {
+                return Result::Err(format!(
+                    "Every item of \"{}\" must have {} components",
+                    key, size
+                ));
+            }
+
+            result.push(floats);
+        }
+
+        return Result::Ok(result);
+    }
+}
+
+impl Statement {
+    pub fn with_str(mut self, key: &str, value: &str) -> Self {
+        self.parameters.push((key.to_string(), value.to_string()));
+
+        return self;
+    }
+
+    pub fn with_f32(self, key: &str, value: f32) -> Self {
+        return self.with_str(key, &value.to_string());
+    }
+
+    pub fn with_u32(self, key: &str, value: u32) -> Self {
+        return self.with_str(key, &value.to_string());
+    }
+
+    pub fn with_bool(self, key: &str, value: bool) -> Self {
+        return self.with_str(key, &value.to_string());
+    }
+
+    pub fn with_vec3(self, key: &str, value: &Vec3) -> Self {
+        return self.with_str(key, &format!("{},{},{}", value.x, value.y, value.z));
+    }
+
+    /// Writes top three rows of an affine transform, see `get_mat4`
+    pub fn with_mat4(self, key: &str, value: &Mat4) -> Self {
+        let rows: Vec<String> = (0..3)
+            .map(|i| {
+                format!(
+                    "{},{},{},{}",
+                    value[(i, 0)],
+                    value[(i, 1)],
+                    value[(i, 2)],
+                    value[(i, 3)]
+                )
+            })
+            .collect();
+
+        return self.with_str(key, &rows.join(";"));
+    }
+
+    pub fn with_color(self, key: &str, value: &ColorInfo) -> Self {
+        return self.with_str(key, &value.to_string());
+    }
+
+    pub fn with_scalar(self, key: &str, value: &ScalarInfo) -> Self {
+        return self.with_str(key, &value.to_string());
+    }
+
+    pub fn with_vec2_list(self, key: &str, values: &[Vec2]) -> Self {
+        let items: Vec<String> = values.iter().map(|x| format!("{},{}", x.x, x.y)).collect();
+
+        return self.with_str(key, &items.join(";"));
+    }
+
+    pub fn with_vec3_list(self, key: &str, values: &[Vec3]) -> Self {
+        let items: Vec<String> = values
+            .iter()
+            .map(|x| format!("{},{},{}", x.x, x.y, x.z))
+            .collect();
+
+        return self.with_str(key, &items.join(";"));
+    }
+
+    /// Writes geometry readable by `get_geometry`
+    pub fn with_geometry(self, geometry: &MeshGeometry) -> Self {
+        let mut statement = self.with_vec3_list("positions", geometry.get_positions());
+
+        if let Some(normals) = geometry.get_normals() {
+            statement = statement.with_vec3_list("normals", normals);
+        }
+
+        if let Some(uvs) = geometry.get_uvs() {
+            statement = statement.with_vec2_list("uvs", uvs);
+        }
+
+        return statement.with_triangle_list("triangles", geometry.get_triangles());
+    }
+
+    pub fn with_triangle_list(self, key: &str, values: &[[u32; 3]]) -> Self {
+        let items: Vec<String> = values
+            .iter()
+            .map(|x| format!("{},{},{}", x[0], x[1], x[2]))
+            .collect();
+
+        return self.with_str(key, &items.join(";"));
+    }
+}
+
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.keyword)?;
+
+        if let Some(kind) = &self.kind {
+            write!(f, " {}", kind)?;
+        }
+
+        for (key, value) in &self.parameters {
+            write!(f, " {}={}", key, quote(value))?;
+        }
+
+        return Result::Ok(());
+    }
+}
+
+fn parse_floats(value: &str, key: &str) -> Result<Vec<f32>, String> {
+    return value
+        .split(',')
+        .map(|x| x.trim().parse::<f32>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("Cannot parse \"{}\" in parameter \"{}\"", value, key));
+}
+
+fn quote(value: &str) -> String {
+    if !value.is_empty() && !value.contains(|c: char| c.is_whitespace() || c == '"') {
+        return value.to_string();
+    }
+
+    return format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+}
+
+fn unquote(value: &str) -> Result<String, String> {
+    if !value.starts_with('"') {
+        return Result::Ok(value.to_string());
+    }
+
+    if value.len() < 2 || !value.ends_with('"') {
+        return Result::Err(format!("Malformed quoted value {}", value));
+    }
+
+    let mut result = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            result.push(chars.next().ok_or("Unfinished escape sequence")?);
+        } else {
+            result.push(c);
+        }
+    }
+
+    return Result::Ok(result);
+}
+
+/// Splits on whitespace outside of double quotes, quotes are kept in the tokens
+fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            current.push(c);
+
+            if c == '\\' {
+                current.push(chars.next().ok_or("Unfinished escape sequence")?);
+            } else if c == '"' {
+                in_quotes = false;
+            }
+        } else if c.is_whitespace() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else {
+            in_quotes = c == '"';
+            current.push(c);
+        }
+    }
+
+    if in_quotes {
+        return Result::Err("Unclosed quote".to_string());
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    return Result::Ok(tokens);
+}
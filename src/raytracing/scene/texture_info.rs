@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::scene::statement::Statement;
+use crate::textures::texture::TextureRc;
+
+pub trait TextureInfoClone {
+    fn clone_box(&self) -> Box<dyn TextureInfo>;
+}
+
+pub trait TextureInfo: TextureInfoClone {
+    fn get_name(&self) -> &str;
+
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `rand`: Generator of the scene, procedural textures draw their seed from it
+    /// * `textures`: Textures declared before this one by name
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc;
+    fn to_statement(&self) -> Statement;
+
+    /// Names of other textures this one is made of, they have to be declared before it
+    fn get_texture_names(&self) -> Vec<&str> {
+        return Vec::new();
+    }
+
+    /// Checks the texture can be built
+    ///
+    /// returns: String with the reason it is invalid or nothing otherwise
+    fn validate(&self) -> Result<(), String> {
+        return Result::Ok(());
+    }
+}
+
+impl<T> TextureInfoClone for T
+where
+    T: TextureInfo + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn TextureInfo> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn TextureInfo> {
+    fn clone(&self) -> Self {
+        return self.clone_box();
+    }
+}
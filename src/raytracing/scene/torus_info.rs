@@ -0,0 +1,114 @@
+use nalgebra_glm::Vec3;
+
+use crate::hittables::hittable::Hittable;
+use crate::hittables::torus::Torus;
+use crate::materials::material::MaterialRc;
+use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+#[derive(Clone)]
+pub struct TorusInfo {
+    name: String,
+    material_name: String,
+    center: Vec3,
+    axis: Vec3,
+    major_radius: f32,
+    minor_radius: f32,
+}
+
+impl TorusInfo {
+    pub fn new(
+        name: &str,
+        material_name: &str,
+        center: Vec3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            material_name: material_name.to_string(),
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        material_name: &str,
+        center: Vec3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(
+            name,
+            material_name,
+            center,
+            axis,
+            major_radius,
+            minor_radius,
+        ));
+    }
+}
+
+impl HittableInfo for TorusInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn get_material_name(&self) -> &str {
+        return &self.material_name;
+    }
+
+    fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
+        return Torus::boxed(
+            self.center,
+            self.axis,
+            self.major_radius,
+            self.minor_radius,
+            material,
+        );
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.minor_radius > 0.0 && self.minor_radius.is_finite()) {
+            return Result::Err(format!(
+                "minor_radius must be positive, got {}",
+                self.minor_radius
+            ));
+        }
+
+        // A tube reaching the axis would intersect itself
+        if !(self.major_radius > self.minor_radius && self.major_radius.is_finite()) {
+            return Result::Err(format!(
+                "major_radius must be larger than minor_radius, got {}",
+                self.major_radius
+            ));
+        }
+
+        let length = self.axis.magnitude();
+
+        if !(length > 0.0 && length.is_finite()) {
+            return Result::Err("axis must not be zero".to_string());
+        }
+
+        return Result::Ok(());
+    }
+
+    fn is_closed(&self) -> bool {
+        return true;
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("torus"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("center", &self.center)
+            .with_vec3("axis", &self.axis)
+            .with_f32("major_radius", self.major_radius)
+            .with_f32("minor_radius", self.minor_radius);
+    }
+}
@@ -4,6 +4,9 @@ use crate::hittables::hittable::Hittable;
 use crate::hittables::triangle::Triangle;
 use crate::materials::material::MaterialRc;
 use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
+
+const MIN_AREA: f32 = 1e-12;
 
 #[derive(Clone)]
 pub struct TriangleInfo {
@@ -60,4 +63,26 @@ impl HittableInfo for TriangleInfo {
     fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
         return Triangle::boxed(self.point_one, self.point_two, self.point_three, material);
     }
+
+    fn validate(&self) -> Result<(), String> {
+        let area = (self.point_two - self.point_one)
+            .cross(&(self.point_three - self.point_one))
+            .magnitude()
+            * 0.5;
+
+        if !(area > MIN_AREA && area.is_finite()) {
+            return Result::Err(format!("triangle area is {}", area));
+        }
+
+        return Result::Ok(());
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("triangle"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("point_one", &self.point_one)
+            .with_vec3("point_two", &self.point_two)
+            .with_vec3("point_three", &self.point_three);
+    }
 }
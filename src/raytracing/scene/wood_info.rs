@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use crate::misc::rand_gen::RandGenRc;
+use crate::misc::seeded_rand_gen::SeededRandGen;
+use crate::scene::color_info::ColorInfo;
+use crate::scene::statement::Statement;
+use crate::scene::texture_info::TextureInfo;
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::TextureRc;
+use crate::textures::wood::Wood;
+
+#[derive(Clone)]
+pub struct WoodInfo {
+    name: String,
+    light: ColorInfo,
+    dark: ColorInfo,
+    rings: f32,
+    turbulence: f32,
+    seed: u32,
+}
+
+impl WoodInfo {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `name`:
+    /// * `light`: Color or texture of the wood inside rings
+    /// * `dark`: Color or texture of the ring borders
+    /// * `rings`: Number of rings along one world unit of radius
+    /// * `turbulence`: How much the rings are distorted, in ring widths
+    /// * `seed`: Added to the seed drawn from the scene generator
+    pub fn new(
+        name: &str,
+        light: ColorInfo,
+        dark: ColorInfo,
+        rings: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Self {
+        return Self {
+            name: name.to_string(),
+            light,
+            dark,
+            rings,
+            turbulence,
+            seed,
+        };
+    }
+
+    pub fn boxed(
+        name: &str,
+        light: ColorInfo,
+        dark: ColorInfo,
+        rings: f32,
+        turbulence: f32,
+        seed: u32,
+    ) -> Box<Self> {
+        return Box::new(Self::new(name, light, dark, rings, turbulence, seed));
+    }
+}
+
+impl TextureInfo for WoodInfo {
+    fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    fn build(&self, rand: RandGenRc, textures: &HashMap<String, TextureRc>) -> TextureRc {
+        let perlin = Perlin::new(&mut SeededRandGen::derived(
+            &mut **rand.borrow_mut(),
+            self.seed,
+        ));
+
+        return Wood::new_rc(
+            perlin,
+            self.light.build(textures),
+            self.dark.build(textures),
+            self.rings,
+            self.turbulence,
+        );
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("texture", Option::Some("wood"))
+            .with_str("name", &self.name)
+            .with_color("light", &self.light)
+            .with_color("dark", &self.dark)
+            .with_f32("rings", self.rings)
+            .with_f32("turbulence", self.turbulence)
+            .with_u32("seed", self.seed);
+    }
+
+    fn get_texture_names(&self) -> Vec<&str> {
+        return [&self.light, &self.dark]
+            .iter()
+            .filter_map(|x| x.get_texture_name())
+            .collect();
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.rings > 0.0 && self.rings.is_finite()) {
+            return Result::Err(format!("rings must be positive, got {}", self.rings));
+        }
+
+        if !(self.turbulence >= 0.0 && self.turbulence.is_finite()) {
+            return Result::Err(format!(
+                "turbulence must not be negative, got {}",
+                self.turbulence
+            ));
+        }
+
+        return Result::Ok(());
+    }
+}
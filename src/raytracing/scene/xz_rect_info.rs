@@ -4,6 +4,7 @@ use crate::hittables::hittable::Hittable;
 use crate::hittables::xz_rect::XzRect;
 use crate::materials::material::MaterialRc;
 use crate::scene::hittable_info::HittableInfo;
+use crate::scene::statement::Statement;
 
 #[derive(Clone)]
 pub struct XzRectInfo {
@@ -45,4 +46,23 @@ impl HittableInfo for XzRectInfo {
     fn build(&self, material: MaterialRc) -> Box<dyn Hittable> {
         return XzRect::square_boxed(self.position, self.edge_length, material);
     }
+
+    fn validate(&self) -> Result<(), String> {
+        if !(self.edge_length > 0.0 && self.edge_length.is_finite()) {
+            return Result::Err(format!(
+                "edge length must be positive, got {}",
+                self.edge_length
+            ));
+        }
+
+        return Result::Ok(());
+    }
+
+    fn to_statement(&self) -> Statement {
+        return Statement::new("hittable", Option::Some("xz_square"))
+            .with_str("name", &self.name)
+            .with_str("material", &self.material_name)
+            .with_vec3("position", &self.position)
+            .with_f32("edge_length", self.edge_length);
+    }
 }
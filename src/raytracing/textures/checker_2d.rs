@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::texture::{Texture, TextureRc};
+
+/// Checkerboard in surface coordinates
+pub struct Checker2d {
+    even: TextureRc,
+    odd: TextureRc,
+    scale: f32,
+}
+
+impl Checker2d {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `even`: Texture of the square at the origin
+    /// * `odd`: Texture of its neighbours
+    /// * `scale`: Number of squares along one unit of surface coordinates
+    pub fn new(even: TextureRc, odd: TextureRc, scale: f32) -> Self {
+        return Self { even, odd, scale };
+    }
+
+    pub fn new_rc(even: TextureRc, odd: TextureRc, scale: f32) -> TextureRc {
+        return Rc::new(Self::new(even, odd, scale));
+    }
+}
+
+impl Texture for Checker2d {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        let cell = (uv * self.scale).map(|x| x.floor() as i64);
+
+        return if (cell.x + cell.y).rem_euclid(2) == 0 {
+            self.even.value(uv, point)
+        } else {
+            self.odd.value(uv, point)
+        };
+    }
+}
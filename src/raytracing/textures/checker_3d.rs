@@ -0,0 +1,41 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::texture::{Texture, TextureRc};
+
+/// Checkerboard of cubes filling the space, independent of surface coordinates
+pub struct Checker3d {
+    even: TextureRc,
+    odd: TextureRc,
+    size: f32,
+}
+
+impl Checker3d {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `even`: Texture of the cube at the origin
+    /// * `odd`: Texture of its neighbours
+    /// * `size`: Edge length of one cube in world units
+    pub fn new(even: TextureRc, odd: TextureRc, size: f32) -> Self {
+        return Self { even, odd, size };
+    }
+
+    pub fn new_rc(even: TextureRc, odd: TextureRc, size: f32) -> TextureRc {
+        return Rc::new(Self::new(even, odd, size));
+    }
+}
+
+impl Texture for Checker3d {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        let cell = (point / self.size).map(|x| x.floor() as i64);
+
+        return if (cell.x + cell.y + cell.z).rem_euclid(2) == 0 {
+            self.even.value(uv, point)
+        } else {
+            self.odd.value(uv, point)
+        };
+    }
+}
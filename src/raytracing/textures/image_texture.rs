@@ -0,0 +1,82 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use nalgebra_glm::{lerp, Vec2, Vec3};
+
+use crate::image::linear_image::LinearImage;
+use crate::textures::texture::{Texture, TextureRc};
+use crate::textures::texture_filter::TextureFilter;
+use crate::textures::wrap_mode::WrapMode;
+
+/// Image stretched over [0, 1]² of surface coordinates
+pub struct ImageTexture {
+    image: Arc<LinearImage>,
+    wrap_mode: WrapMode,
+    filter: TextureFilter,
+}
+
+impl ImageTexture {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `image`: Image shared by every worker, first row is the top one
+    /// * `wrap_mode`: How the image continues outside of [0, 1]²
+    /// * `filter`: How colors between pixel centers are found
+    pub fn new(image: Arc<LinearImage>, wrap_mode: WrapMode, filter: TextureFilter) -> Self {
+        return Self {
+            image,
+            wrap_mode,
+            filter,
+        };
+    }
+
+    pub fn new_rc(
+        image: Arc<LinearImage>,
+        wrap_mode: WrapMode,
+        filter: TextureFilter,
+    ) -> TextureRc {
+        return Rc::new(Self::new(image, wrap_mode, filter));
+    }
+
+    fn get_pixel(&self, x: i32, y: i32) -> Vec3 {
+        let resolution = self.image.get_resolution();
+
+        return *self.image.get_pixel(
+            self.wrap_mode.apply(x, resolution.x),
+            self.wrap_mode.apply(y, resolution.y),
+        );
+    }
+}
+
+impl Texture for ImageTexture {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        let resolution = self.image.get_resolution();
+        let x = uv.x * resolution.x as f32;
+        let y = (1.0 - uv.y) * resolution.y as f32;
+
+        if self.filter == TextureFilter::Nearest {
+            return self.get_pixel(x.floor() as i32, y.floor() as i32);
+        }
+
+        // Pixel centers are at half coordinates
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let (left, top) = (x.floor(), y.floor());
+        let (tx, ty) = (x - left, y - top);
+        let (left, top) = (left as i32, top as i32);
+
+        let upper = lerp(
+            &self.get_pixel(left, top),
+            &self.get_pixel(left + 1, top),
+            tx,
+        );
+        let lower = lerp(
+            &self.get_pixel(left, top + 1),
+            &self.get_pixel(left + 1, top + 1),
+            tx,
+        );
+
+        return lerp(&upper, &lower, ty);
+    }
+}
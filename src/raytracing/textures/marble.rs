@@ -0,0 +1,70 @@
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::{Texture, TextureRc};
+
+const OCTAVES: u32 = 7;
+
+/// Veins running across the x axis, bent by turbulence
+pub struct Marble {
+    perlin: Perlin,
+    base: TextureRc,
+    vein: TextureRc,
+    scale: f32,
+    turbulence: f32,
+}
+
+impl Marble {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `perlin`:
+    /// * `base`: Texture between the veins
+    /// * `vein`: Texture of the veins
+    /// * `scale`: Number of veins along one world unit of x
+    /// * `turbulence`: How far the veins are bent, in distances between them
+    pub fn new(
+        perlin: Perlin,
+        base: TextureRc,
+        vein: TextureRc,
+        scale: f32,
+        turbulence: f32,
+    ) -> Self {
+        return Self {
+            perlin,
+            base,
+            vein,
+            scale,
+            turbulence,
+        };
+    }
+
+    pub fn new_rc(
+        perlin: Perlin,
+        base: TextureRc,
+        vein: TextureRc,
+        scale: f32,
+        turbulence: f32,
+    ) -> TextureRc {
+        return Rc::new(Self::new(perlin, base, vein, scale, turbulence));
+    }
+}
+
+impl Texture for Marble {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        let scaled = point * self.scale;
+        let phase =
+            2.0 * PI * (scaled.x + self.turbulence * self.perlin.turbulence(&scaled, OCTAVES));
+        // Thin veins where the sine is close to one
+        let t = (0.5 + 0.5 * phase.sin()).powi(4);
+
+        return self
+            .base
+            .value(uv, point)
+            .lerp(&self.vein.value(uv, point), t);
+    }
+}
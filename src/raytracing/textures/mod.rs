@@ -0,0 +1,11 @@
+pub mod checker_2d;
+pub mod checker_3d;
+pub mod image_texture;
+pub mod marble;
+pub mod noise_texture;
+pub mod perlin;
+pub mod solid_color;
+pub mod texture;
+pub mod texture_filter;
+pub mod wood;
+pub mod wrap_mode;
@@ -0,0 +1,58 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::{Texture, TextureRc};
+
+/// Blend of two textures by fractal Perlin noise in world space
+pub struct NoiseTexture {
+    perlin: Perlin,
+    low: TextureRc,
+    high: TextureRc,
+    scale: f32,
+    octaves: u32,
+}
+
+impl NoiseTexture {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `perlin`:
+    /// * `low`: Texture where the noise is lowest
+    /// * `high`: Texture where the noise is highest
+    /// * `scale`: Frequency of the first octave in world units
+    /// * `octaves`: Number of noise layers, 1 for plain Perlin noise
+    pub fn new(perlin: Perlin, low: TextureRc, high: TextureRc, scale: f32, octaves: u32) -> Self {
+        return Self {
+            perlin,
+            low,
+            high,
+            scale,
+            octaves,
+        };
+    }
+
+    pub fn new_rc(
+        perlin: Perlin,
+        low: TextureRc,
+        high: TextureRc,
+        scale: f32,
+        octaves: u32,
+    ) -> TextureRc {
+        return Rc::new(Self::new(perlin, low, high, scale, octaves));
+    }
+}
+
+impl Texture for NoiseTexture {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        let noise = self.perlin.fbm(&(point * self.scale), self.octaves);
+        let t = (0.5 + noise).clamp(0.0, 1.0);
+
+        return self
+            .low
+            .value(uv, point)
+            .lerp(&self.high.value(uv, point), t);
+    }
+}
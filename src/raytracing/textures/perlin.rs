@@ -0,0 +1,118 @@
+use nalgebra_glm::Vec3;
+
+use crate::misc::rand_gen::RandGen;
+
+const POINT_COUNT: usize = 256;
+
+/// Gradient noise over a lattice of random unit vectors
+pub struct Perlin {
+    gradients: Vec<Vec3>,
+    permutations: [Vec<usize>; 3],
+}
+
+impl Perlin {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `rand`: Source of the lattice, seeded one gives the same noise every time
+    pub fn new(rand: &mut dyn RandGen) -> Self {
+        let gradients = (0..POINT_COUNT)
+            .map(|_| rand.unit_sphere().normalize())
+            .collect();
+        let permutations = [
+            Self::permutation(rand),
+            Self::permutation(rand),
+            Self::permutation(rand),
+        ];
+
+        return Self {
+            gradients,
+            permutations,
+        };
+    }
+
+    fn permutation(rand: &mut dyn RandGen) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..POINT_COUNT).collect();
+
+        for i in (1..POINT_COUNT).rev() {
+            let j = ((rand.uniform() * (i + 1) as f32) as usize).min(i);
+
+            permutation.swap(i, j);
+        }
+
+        return permutation;
+    }
+
+    /// Smooth noise with lattice period of one unit
+    ///
+    /// returns: Value from -√3/2 to √3/2, mostly within ±0.5, zero at lattice points
+    pub fn noise(&self, point: &Vec3) -> f32 {
+        let cell = point.map(|x| x.floor());
+        let local = point - cell;
+        // Quintic curve gives continuous second derivative across cells
+        let weight = local.map(|x| x * x * x * (x * (x * 6.0 - 15.0) + 10.0));
+        let mut sum = 0.0;
+
+        for corner in 0..8 {
+            let offset = Vec3::new(
+                (corner & 1) as f32,
+                ((corner >> 1) & 1) as f32,
+                ((corner >> 2) & 1) as f32,
+            );
+            let lattice = (cell + offset).map(|x| (x as i64).rem_euclid(POINT_COUNT as i64));
+            let index = self.permutations[0][lattice.x as usize]
+                ^ self.permutations[1][lattice.y as usize]
+                ^ self.permutations[2][lattice.z as usize];
+            let blend = Vec3::from_fn(|i, _| {
+                if offset[i] == 1.0 {
+                    weight[i]
+                } else {
+                    1.0 - weight[i]
+                }
+            });
+
+            sum += blend.product() * self.gradients[index].dot(&(local - offset));
+        }
+
+        return sum;
+    }
+
+    /// Fractal Brownian motion, sum of octaves with doubling frequency and halving amplitude
+    ///
+    /// returns: Weighted average of octaves, in the same range as `noise` but closer to zero
+    pub fn fbm(&self, point: &Vec3, octaves: u32) -> f32 {
+        let mut sum = 0.0;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut point = *point;
+
+        for _ in 0..octaves {
+            sum += amplitude * self.noise(&point);
+            total += amplitude;
+            amplitude *= 0.5;
+            point *= 2.0;
+        }
+
+        return sum / total;
+    }
+
+    /// Like `fbm` but of absolute values, which gives sharp creases where the noise is zero
+    ///
+    /// returns: Weighted average of absolute octaves, from 0 to √3/2
+    pub fn turbulence(&self, point: &Vec3, octaves: u32) -> f32 {
+        let mut sum = 0.0;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut point = *point;
+
+        for _ in 0..octaves {
+            sum += amplitude * self.noise(&point).abs();
+            total += amplitude;
+            amplitude *= 0.5;
+            point *= 2.0;
+        }
+
+        return sum / total;
+    }
+}
@@ -0,0 +1,25 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::texture::{Texture, TextureRc};
+
+pub struct SolidColor {
+    color: Vec3,
+}
+
+impl SolidColor {
+    pub fn new(color: Vec3) -> Self {
+        return Self { color };
+    }
+
+    pub fn new_rc(color: Vec3) -> TextureRc {
+        return Rc::new(Self::new(color));
+    }
+}
+
+impl Texture for SolidColor {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        return self.color;
+    }
+}
@@ -0,0 +1,18 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+pub type TextureRc = Rc<dyn Texture>;
+
+/// Color varying over surfaces, used for material parameters
+pub trait Texture {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `uv`: Surface coordinates of the hit
+    /// * `point`: Hit point in world space
+    ///
+    /// returns: RGB color
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3;
+}
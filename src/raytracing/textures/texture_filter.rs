@@ -0,0 +1,26 @@
+/// How image texture is sampled between pixel centers
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum TextureFilter {
+    /// Color of the closest pixel
+    Nearest,
+    /// Weighted average of four closest pixels
+    #[default]
+    Bilinear,
+}
+
+impl TextureFilter {
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            TextureFilter::Nearest => "nearest",
+            TextureFilter::Bilinear => "bilinear",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "nearest" => Option::Some(TextureFilter::Nearest),
+            "bilinear" => Option::Some(TextureFilter::Bilinear),
+            _ => Option::None,
+        };
+    }
+}
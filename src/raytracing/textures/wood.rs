@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+use nalgebra_glm::{Vec2, Vec3};
+
+use crate::textures::perlin::Perlin;
+use crate::textures::texture::{Texture, TextureRc};
+
+const OCTAVES: u32 = 4;
+
+/// Growth rings around the z axis, distorted by noise
+pub struct Wood {
+    perlin: Perlin,
+    light: TextureRc,
+    dark: TextureRc,
+    rings: f32,
+    turbulence: f32,
+}
+
+impl Wood {
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `perlin`:
+    /// * `light`: Texture of the wood inside rings
+    /// * `dark`: Texture of the ring borders
+    /// * `rings`: Number of rings along one world unit of radius
+    /// * `turbulence`: How much the rings are distorted, in ring widths
+    pub fn new(
+        perlin: Perlin,
+        light: TextureRc,
+        dark: TextureRc,
+        rings: f32,
+        turbulence: f32,
+    ) -> Self {
+        return Self {
+            perlin,
+            light,
+            dark,
+            rings,
+            turbulence,
+        };
+    }
+
+    pub fn new_rc(
+        perlin: Perlin,
+        light: TextureRc,
+        dark: TextureRc,
+        rings: f32,
+        turbulence: f32,
+    ) -> TextureRc {
+        return Rc::new(Self::new(perlin, light, dark, rings, turbulence));
+    }
+}
+
+impl Texture for Wood {
+    fn value(&self, uv: &Vec2, point: &Vec3) -> Vec3 {
+        // Noise stretched along the axis like wood grain
+        let grain = Vec3::new(point.x, point.y, point.z * 0.1) * self.rings;
+        let radius = point.xy().magnitude() * self.rings
+            + self.turbulence * self.perlin.fbm(&grain, OCTAVES);
+        // Rings darken gradually and end sharply
+        let t = radius.rem_euclid(1.0).powi(3);
+
+        return self
+            .light
+            .value(uv, point)
+            .lerp(&self.dark.value(uv, point), t);
+    }
+}
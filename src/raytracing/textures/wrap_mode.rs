@@ -0,0 +1,54 @@
+/// How texture coordinates outside of [0, 1] are mapped back into the image
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum WrapMode {
+    /// Image is tiled
+    #[default]
+    Repeat,
+    /// Edge pixels are stretched
+    Clamp,
+    /// Image is tiled with every other copy flipped
+    Mirror,
+}
+
+impl WrapMode {
+    /// Maps pixel coordinate to the image of given size
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate`: Pixel index, may be outside of the image
+    /// * `size`: Number of pixels along the axis
+    ///
+    /// returns: Pixel index from 0 to `size - 1`
+    pub fn apply(&self, coordinate: i32, size: i32) -> i32 {
+        return match self {
+            WrapMode::Repeat => coordinate.rem_euclid(size),
+            WrapMode::Clamp => coordinate.clamp(0, size - 1),
+            WrapMode::Mirror => {
+                let period = coordinate.rem_euclid(2 * size);
+
+                if period < size {
+                    period
+                } else {
+                    2 * size - 1 - period
+                }
+            }
+        };
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            WrapMode::Repeat => "repeat",
+            WrapMode::Clamp => "clamp",
+            WrapMode::Mirror => "mirror",
+        };
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "repeat" => Option::Some(WrapMode::Repeat),
+            "clamp" => Option::Some(WrapMode::Clamp),
+            "mirror" => Option::Some(WrapMode::Mirror),
+            _ => Option::None,
+        };
+    }
+}
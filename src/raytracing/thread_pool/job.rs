@@ -1,3 +1,13 @@
+use crate::misc::render_settings::RenderSettings;
+use crate::scene::scene_info::SceneInfo;
+
 pub(super) enum Message {
     Terminate,
 }
+
+/// Scene one worker renders, with the number of samples it should stop at
+pub(super) struct RenderJob {
+    pub(super) scene_info: SceneInfo,
+    pub(super) settings: RenderSettings,
+    pub(super) samples: Option<u64>,
+}
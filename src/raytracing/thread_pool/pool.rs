@@ -1,16 +1,17 @@
 use std::sync::{mpsc, Arc, Mutex};
 
+use crate::misc::render_settings::RenderSettings;
 use crate::misc::scene::Scene;
 use crate::scene::scene_info::SceneInfo;
 use crate::thread_pool::trace_result::TraceResult;
 
-use super::job::Message;
+use super::job::{Message, RenderJob};
 use super::worker::Worker;
 
 pub struct ThreadPool {
     workers: Vec<Worker>,
     command_sender: mpsc::Sender<Message>,
-    job_sender: mpsc::Sender<SceneInfo>,
+    job_sender: mpsc::Sender<RenderJob>,
     result_receiver: mpsc::Receiver<TraceResult>,
 }
 
@@ -52,16 +53,42 @@ impl ThreadPool {
         };
     }
 
-    pub fn execute_scene(&self, scene_info: SceneInfo, count: usize) {
+    /// Splits rendering of the scene between `count` workers
+    ///
+    /// # Arguments
+    ///
+    /// * `scene_info`: Scene every worker builds for itself
+    /// * `settings`: Settings the total sample target of is shared between the workers
+    /// * `count`: Number of workers to render with
+    pub fn execute_scene(&self, scene_info: SceneInfo, settings: RenderSettings, count: usize) {
         if count == 0 {
             return;
         }
 
-        for _ in 0..(count - 1) {
-            self.job_sender.send(scene_info.clone()).unwrap();
-        }
+        for index in 0..count {
+            let samples = settings.get_total_samples().map(|total_samples| {
+                let count = count as u64;
+                let extra = if (index as u64) < total_samples % count {
+                    1
+                } else {
+                    0
+                };
+
+                total_samples / count + extra
+            });
 
-        self.job_sender.send(scene_info).unwrap();
+            if samples == Option::Some(0) {
+                continue;
+            }
+
+            self.job_sender
+                .send(RenderJob {
+                    scene_info: scene_info.clone(),
+                    settings,
+                    samples,
+                })
+                .unwrap();
+        }
     }
 
     pub fn execute<F>(&self, f: F)
@@ -2,14 +2,10 @@ use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
 
-use nalgebra_glm::IVec2;
-use rand::{thread_rng, Rng};
-
 use crate::misc::tracer::Tracer;
-use crate::scene::scene_info::SceneInfo;
 use crate::thread_pool::trace_result::TraceResult;
 
-use super::job::Message;
+use super::job::{Message, RenderJob};
 
 pub(super) struct Worker {
     pub(super) id: usize,
@@ -19,29 +15,46 @@ pub(super) struct Worker {
 impl Worker {
     pub(super) fn new(
         id: usize,
-        job_receiver: Arc<Mutex<mpsc::Receiver<SceneInfo>>>,
+        job_receiver: Arc<Mutex<mpsc::Receiver<RenderJob>>>,
         command_receiver: Arc<Mutex<mpsc::Receiver<Message>>>,
         sender: mpsc::Sender<TraceResult>,
     ) -> Worker {
         let thread = thread::spawn(move || 'stop: loop {
             let job_option = job_receiver.lock().unwrap().try_recv();
 
-            if let Ok(scene_info) = job_option {
+            if let Ok(job) = job_option {
                 println!("Worker {} got a job; executing.", id);
-                let mut tracer = Tracer::new(IVec2::new(800, 600), scene_info.build());
-                let mut iterations: usize = thread_rng().gen_range(1..20);
+                let scene = match job.scene_info.try_build() {
+                    Ok(scene) => scene,
+                    Err(err) => {
+                        println!("Worker {} cannot build the scene. {}", id, err);
+
+                        continue;
+                    }
+                };
+                let batch = job.settings.get_samples_per_batch().max(1);
+                let mut tracer = Tracer::new(job.settings, scene);
+                let mut samples: u64 = 0;
 
                 loop {
                     tracer.trace();
 
-                    iterations += 1;
+                    samples += 1;
 
-                    if iterations % 20 == 0 {
+                    let is_done = job.samples.is_some_and(|x| samples >= x);
+
+                    if samples.is_multiple_of(batch) || is_done {
                         let result = tracer.construct_trace_result();
 
                         sender.send(result).unwrap();
                     }
 
+                    if is_done {
+                        println!("Worker {} finished {} samples.", id, samples);
+
+                        break;
+                    }
+
                     if let Ok(message) = command_receiver.lock().unwrap().try_recv() {
                         match message {
                             Message::Terminate => break 'stop,
@@ -49,6 +62,12 @@ impl Worker {
                     }
                 }
             }
+
+            if let Ok(message) = command_receiver.lock().unwrap().try_recv() {
+                match message {
+                    Message::Terminate => break 'stop,
+                }
+            }
         });
 
         return Worker {